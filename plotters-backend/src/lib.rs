@@ -63,12 +63,16 @@
 */
 use std::error::Error;
 
+mod paint;
 pub mod rasterizer;
 mod style;
 mod text;
 
-pub use style::{BackendColor, BackendStyle};
-pub use text::{text_anchor, BackendTextStyle, FontFamily, FontStyle, FontTransform};
+pub use paint::{BackendPaint, GradientStop, GradientStops, MAX_GRADIENT_STOPS};
+pub use style::{BackendColor, BackendStyle, LineCap, LineJoin};
+pub use text::{
+    text_anchor, BackendTextStyle, FontFamily, FontStyle, FontTransform, OutlineCommand,
+};
 
 use text_anchor::{HPos, VPos};
 
@@ -76,6 +80,29 @@ use text_anchor::{HPos, VPos};
 /// which defines the top-left point as (0, 0).
 pub type BackendCoord = (i32, i32);
 
+/// A coordinate in the pixel-based backend which keeps the sub-pixel position, see
+/// [DrawingBackend::is_subpixel_aware].
+pub type BackendFloatCoord = (f64, f64);
+
+#[inline(always)]
+fn round_coord((x, y): BackendFloatCoord) -> BackendCoord {
+    (x.round() as i32, y.round() as i32)
+}
+
+/// The shape of a marker drawn by [DrawingBackend::draw_marker]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MarkerShape {
+    /// A circle, the size is its radius
+    Circle {
+        /// If the circle should be filled
+        filled: bool,
+    },
+    /// The two diagonals of a square, the size is half the side of the square
+    Cross,
+    /// A filled triangle pointing up, the size is the distance from the center to the vertices
+    Triangle,
+}
+
 /// Describes how pixel positions along an axis map to logical valeus.
 ///
 /// Backends can use this to compute tooltip labels for arbitrary cursor positions (continuous) or
@@ -98,13 +125,66 @@ pub enum Interpolation {
     },
 }
 
+impl Interpolation {
+    /// The value at a pixel position, only known for continuous interpolations.
+    pub fn value_at(&self, pixel: i32) -> Option<f64> {
+        match self {
+            Interpolation::Continuous {
+                backend_range: (p0, p1),
+                value_range: (v0, v1),
+                ..
+            } => {
+                if p0 == p1 {
+                    return Some(*v0);
+                }
+                Some(v0 + (v1 - v0) * f64::from(pixel - p0) / f64::from(p1 - p0))
+            }
+            Interpolation::Discrete { .. } => None,
+        }
+    }
+
+    /// The formatted value at a pixel position. This is the label of the nearest point for
+    /// discrete interpolations, and the value with the precision of one pixel for continuous ones.
+    pub fn label_at(&self, pixel: i32) -> Option<String> {
+        match self {
+            Interpolation::Continuous {
+                backend_range: (p0, p1),
+                value_range: (v0, v1),
+                ..
+            } => {
+                let value = self.value_at(pixel)?;
+                let step = if p0 == p1 {
+                    0.0
+                } else {
+                    ((v1 - v0) / f64::from(p1 - p0)).abs()
+                };
+                let digits = if step > 0.0 {
+                    (-step.log10()).ceil().max(0.0) as usize
+                } else {
+                    0
+                };
+                Some(format!("{:.*}", digits, value))
+            }
+            Interpolation::Discrete { points } => points
+                .iter()
+                .min_by_key(|(p, _)| (p - pixel).abs())
+                .map(|(_, label)| label.clone()),
+        }
+    }
+}
+
 /// Semantic context passed to [`DrawingBackend::begin_context`] so that backends know *what* is
 /// being drawn and can attach metadata such as tooltip information, accessibility labels, or
 /// interactive behaviour.
 ///
 /// Contexts are nestable: a `DataSeries` context may contain multiple `DataPoint` contexts, and an
 /// `Axis` context may contain `Tick` and `Label` children.
+///
+/// More contexts and fields may be added in the future, thus the enum and the data element
+/// variants are non-exhaustive. Use [`ElementContext::data_series`],
+/// [`ElementContext::data_point`] and [`ElementContext::data_line`] to create the latter.
 #[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ElementContext {
     /// The full-chart background fill.
     Background,
@@ -116,6 +196,11 @@ pub enum ElementContext {
         /// tick positions.
         interpolation: Option<Interpolation>,
     },
+    /// The grid lines of the plotting area, drawn with either the bold or the light mesh style.
+    Grid {
+        /// Whether these are the bold grid lines, at the labelled key points
+        bold: bool,
+    },
     /// A single tick mark on an axis.
     Tick {
         /// Which axis this tick belongs to (matches `Axis::axis_id`).
@@ -131,6 +216,7 @@ pub enum ElementContext {
         text: String,
     },
     /// A single rendered data point inside a series.
+    #[non_exhaustive]
     DataPoint {
         /// The backend pixel coordinate of this point.
         coord: BackendCoord,
@@ -138,28 +224,154 @@ pub enum ElementContext {
         x_label: String,
         /// Formatted y-axis value at this point.
         y_label: String,
+        /// Formatted z-axis value at this point, only present for 3D charts.
+        z_label: Option<String>,
+        /// Additional user supplied key/value pairs describing this point, such as a sample
+        /// count or a link to the underlying record. Empty if there's none.
+        metadata: Vec<(String, String)>,
         /// Index of the series this point belongs to (matches `DataSeries::id`).
         series_id: usize,
     },
     /// A path / polyline connecting data points.
+    #[non_exhaustive]
     DataLine {
         /// How to map pixel positions to x-axis values along the line.
         x_interpolation: Interpolation,
         /// How to map pixel positions to y-axis values along the line.
         y_interpolation: Interpolation,
+        /// The z-axis values along the line, only present for 3D charts. Since the z axis has no
+        /// pixel direction of its own, the points are keyed by the pixel y position of the vertex
+        /// and listed in the same order as `y_interpolation`.
+        z_interpolation: Option<Interpolation>,
+        /// The continuous x and y interpolations of the plotting area, present if both axes are
+        /// mapped linearly. Backends use them for the values between the vertices of a path,
+        /// while the vertex labels above keep the formatting of the axes.
+        continuous_interpolation: Option<(Interpolation, Interpolation)>,
         /// Index of the series this line belongs to (matches `DataSeries::id`).
         series_id: usize,
     },
     /// Groups all elements that belong to one logical data series.
+    #[non_exhaustive]
     DataSeries {
         /// A unique identifier for this series within the chart.
         id: usize,
-        /// The colour used for this series.
+        /// The colour used for this series. A fully transparent colour means the colour is not
+        /// known when the series is opened, backends may then take the colour of the first
+        /// primitive drawn inside it.
         color: BackendColor,
         /// Human-readable name / legend label for this series (may be empty if the caller has not
         /// assigned one yet).
         label: String,
+        /// The upper-left and lower-right corners of the plotting area the series is drawn in,
+        /// both included, if it's known. Series sharing the same plotting area belong to the same
+        /// chart.
+        plot_area: Option<(BackendCoord, BackendCoord)>,
     },
+    /// A row of the series legend, containing the legend marker of a series.
+    LegendEntry {
+        /// The series this row describes (matches `DataSeries::id`).
+        series_id: usize,
+        /// The label of the series shown in this row.
+        label: String,
+        /// The upper-left and lower-right corners of the whole row in backend pixels, including
+        /// the label text which may be drawn outside of this context.
+        area: (BackendCoord, BackendCoord),
+    },
+}
+
+impl ElementContext {
+    /// Create a `DataSeries` context without plotting area.
+    pub fn data_series(id: usize, color: BackendColor, label: String) -> Self {
+        ElementContext::DataSeries {
+            id,
+            color,
+            label,
+            plot_area: None,
+        }
+    }
+
+    /// Create a `DataPoint` context without z label and metadata.
+    pub fn data_point(
+        coord: BackendCoord,
+        x_label: String,
+        y_label: String,
+        series_id: usize,
+    ) -> Self {
+        ElementContext::DataPoint {
+            coord,
+            x_label,
+            y_label,
+            z_label: None,
+            metadata: vec![],
+            series_id,
+        }
+    }
+
+    /// Create a `DataLine` context without z and continuous interpolations.
+    pub fn data_line(
+        x_interpolation: Interpolation,
+        y_interpolation: Interpolation,
+        series_id: usize,
+    ) -> Self {
+        ElementContext::DataLine {
+            x_interpolation,
+            y_interpolation,
+            z_interpolation: None,
+            continuous_interpolation: None,
+            series_id,
+        }
+    }
+
+    /// Set the plotting area of a `DataSeries` context. Other contexts are returned unchanged.
+    pub fn with_plot_area(mut self, area: Option<(BackendCoord, BackendCoord)>) -> Self {
+        if let ElementContext::DataSeries { plot_area, .. } = &mut self {
+            *plot_area = area;
+        }
+        self
+    }
+
+    /// Set the z label of a `DataPoint` context. Other contexts are returned unchanged.
+    pub fn with_z_label(mut self, label: Option<String>) -> Self {
+        if let ElementContext::DataPoint { z_label, .. } = &mut self {
+            *z_label = label;
+        }
+        self
+    }
+
+    /// Set the metadata of a `DataPoint` context. Other contexts are returned unchanged.
+    pub fn with_metadata(mut self, pairs: Vec<(String, String)>) -> Self {
+        if let ElementContext::DataPoint { metadata, .. } = &mut self {
+            *metadata = pairs;
+        }
+        self
+    }
+
+    /// Set the z interpolation of a `DataLine` context. Other contexts are returned unchanged.
+    pub fn with_z_interpolation(mut self, interpolation: Option<Interpolation>) -> Self {
+        if let ElementContext::DataLine {
+            z_interpolation, ..
+        } = &mut self
+        {
+            *z_interpolation = interpolation;
+        }
+        self
+    }
+
+    /// Set the continuous x and y interpolations of a `DataLine` context. Other contexts are
+    /// returned unchanged.
+    pub fn with_continuous_interpolation(
+        mut self,
+        interpolation: Option<(Interpolation, Interpolation)>,
+    ) -> Self {
+        if let ElementContext::DataLine {
+            continuous_interpolation,
+            ..
+        } = &mut self
+        {
+            *continuous_interpolation = interpolation;
+        }
+        self
+    }
 }
 
 /// The error produced by a drawing backend.
@@ -206,6 +418,66 @@ pub trait DrawingBackend: Sized {
     /// implementation is a no-op.
     fn end_context(&mut self) {}
 
+    /// Update the metadata of a context that has already been closed.
+    ///
+    /// This is used for information that only becomes known after the elements are drawn, for
+    /// example a series label assigned through the series annotation. `DataSeries` contexts are
+    /// matched by `id`; a fully transparent colour leaves the recorded colour untouched. The
+    /// default implementation is a no-op.
+    fn update_context(&mut self, _ctx: ElementContext) {}
+
+    /// Whether this backend makes use of the semantic contexts.
+    ///
+    /// Plotters only computes the per-element `DataPoint` / `DataLine` contexts, which requires
+    /// formatting every coordinate, when this returns `true`. The default is `false`.
+    fn is_context_aware(&self) -> bool {
+        false
+    }
+
+    /// Whether this backend makes use of the sub-pixel coordinates.
+    ///
+    /// Plotters only computes the fractional positions and calls the `*_f64` drawing methods,
+    /// e.g. [`draw_line_f64`](Self::draw_line_f64), when this returns `true`. The default is
+    /// `false`.
+    fn is_subpixel_aware(&self) -> bool {
+        false
+    }
+
+    /// Whether the built-in [rasterizer] draws the shapes of this backend anti-aliased.
+    ///
+    /// When this returns `true`, the lines, the circles and the polygons drawn with the default
+    /// implementations blend the pixels on their edges by the fraction of the pixel the shape
+    /// covers. The default is `false`, which draws hard edges.
+    fn is_anti_aliased(&self) -> bool {
+        false
+    }
+
+    /// Whether this backend clips the drawing to the regions pushed with
+    /// [`push_clip_rect`](Self::push_clip_rect).
+    ///
+    /// Plotters only relies on the backend to clip the data series to the plotting area when this
+    /// returns `true`, otherwise the coordinates are truncated to the plotting area. The default
+    /// is `false`.
+    fn is_clip_aware(&self) -> bool {
+        false
+    }
+
+    /// Restrict the drawing to a rectangle, including both corners, until the matching
+    /// [`pop_clip`](Self::pop_clip). The nested clip regions are intersected. The default
+    /// implementation ignores the clip region.
+    fn push_clip_rect(
+        &mut self,
+        _upper_left: BackendCoord,
+        _bottom_right: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    /// Remove the clip region pushed last. The default implementation is a no-op.
+    fn pop_clip(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
     /// Get the dimension of the drawing backend in pixels
     fn get_size(&self) -> (u32, u32);
 
@@ -268,24 +540,8 @@ pub trait DrawingBackend: Sized {
             return Ok(());
         }
 
-        if style.stroke_width() == 1 {
-            let mut begin: Option<BackendCoord> = None;
-            for end in path.into_iter() {
-                if let Some(begin) = begin {
-                    let result = self.draw_line(begin, end, style);
-                    #[allow(clippy::question_mark)]
-                    if result.is_err() {
-                        return result;
-                    }
-                }
-                begin = Some(end);
-            }
-        } else {
-            let p: Vec<_> = path.into_iter().collect();
-            let v = rasterizer::polygonize(&p[..], style.stroke_width());
-            return self.fill_polygon(v, &style.color());
-        }
-        Ok(())
+        let p: Vec<_> = path.into_iter().collect();
+        rasterizer::draw_path(self, &p[..], style)
     }
 
     /// Draw a circle on the drawing backend
@@ -313,6 +569,24 @@ pub trait DrawingBackend: Sized {
         rasterizer::fill_polygon(self, &vert_buf[..], style)
     }
 
+    /// Draw a marker of a data point on the drawing backend
+    /// - `center`: The center coordinate of the marker
+    /// - `shape`: The shape of the marker
+    /// - `size`: The size of the marker in pixels, see [MarkerShape]
+    /// - `style`: The style of the marker
+    ///
+    /// The default implementation draws the marker with the other primitives. Backends which
+    /// can reuse a shape drawn many times, e.g. the SVG backend, may override this.
+    fn draw_marker<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        shape: MarkerShape,
+        size: i32,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        rasterizer::draw_marker(self, center, shape, size, style)
+    }
+
     /// Draw a text on the drawing backend
     /// - `text`: The text to draw
     /// - `style`: The text style
@@ -361,6 +635,70 @@ pub trait DrawingBackend: Sized {
         }
     }
 
+    /// Draw a line with sub-pixel coordinates, see [`draw_line`](Self::draw_line)
+    ///
+    /// The default implementation rounds the coordinates to the nearest pixel.
+    fn draw_line_f64<S: BackendStyle>(
+        &mut self,
+        from: BackendFloatCoord,
+        to: BackendFloatCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.draw_line(round_coord(from), round_coord(to), style)
+    }
+
+    /// Draw a path with sub-pixel coordinates, see [`draw_path`](Self::draw_path)
+    ///
+    /// The default implementation rounds the coordinates to the nearest pixel.
+    fn draw_path_f64<S: BackendStyle, I: IntoIterator<Item = BackendFloatCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.draw_path(path.into_iter().map(round_coord), style)
+    }
+
+    /// Draw a circle with sub-pixel coordinates, see [`draw_circle`](Self::draw_circle)
+    ///
+    /// The default implementation rounds the center and the radius to the nearest pixel.
+    fn draw_circle_f64<S: BackendStyle>(
+        &mut self,
+        center: BackendFloatCoord,
+        radius: f64,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.draw_circle(
+            round_coord(center),
+            radius.max(0.0).round() as u32,
+            style,
+            fill,
+        )
+    }
+
+    /// Fill a polygon with sub-pixel coordinates, see [`fill_polygon`](Self::fill_polygon)
+    ///
+    /// The default implementation rounds the coordinates to the nearest pixel.
+    fn fill_polygon_f64<S: BackendStyle, I: IntoIterator<Item = BackendFloatCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.fill_polygon(vert.into_iter().map(round_coord), style)
+    }
+
+    /// Draw a text with a sub-pixel anchor point, see [`draw_text`](Self::draw_text)
+    ///
+    /// The default implementation rounds the anchor point to the nearest pixel.
+    fn draw_text_f64<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendFloatCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.draw_text(text, style, round_coord(pos))
+    }
+
     /// Estimate the size of the horizontal text if rendered on this backend.
     /// This is important because some of the backend may not have font ability.
     /// Thus this allows those backend reports proper value rather than ask the
@@ -425,4 +763,43 @@ pub trait DrawingBackend: Sized {
 
         Ok(())
     }
+
+    /// Blit a bitmap with an alpha channel on to the backend, the pixels are blended with what
+    /// has already been drawn.
+    ///
+    /// - `pos`: The left upper corner of the bitmap to blit
+    /// - `src`: The RGBA pixels of the image, 4 bytes per pixel
+    ///
+    /// The default implementation draws each pixel which isn't fully transparent.
+    fn blit_bitmap_rgba(
+        &mut self,
+        pos: BackendCoord,
+        (iw, ih): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (w, h) = self.get_size();
+
+        for dy in 0..ih {
+            if pos.1 + dy as i32 >= h as i32 {
+                break;
+            }
+            for dx in 0..iw {
+                if pos.0 + dx as i32 >= w as i32 {
+                    break;
+                }
+                let idx = (dx + dy * iw) as usize * 4;
+                let [r, g, b, a] = [src[idx], src[idx + 1], src[idx + 2], src[idx + 3]];
+                if a == 0 {
+                    continue;
+                }
+                let color = BackendColor {
+                    alpha: f64::from(a) / 255.0,
+                    rgb: (r, g, b),
+                };
+                self.draw_pixel((pos.0 + dx as i32, pos.1 + dy as i32), color)?;
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,321 @@
+use crate::{BackendColor, BackendCoord};
+
+/// The maximum number of the color stops of a gradient
+pub const MAX_GRADIENT_STOPS: usize = 8;
+
+/// A color stop of a gradient
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradientStop {
+    /// The position of the stop along the gradient, from 0 to 1
+    pub offset: f64,
+    /// The color at the stop
+    pub color: BackendColor,
+}
+
+/// The color stops of a gradient, sorted by offset.
+///
+/// The stops are stored inline, so that the paints remain `Copy`, thus a gradient has at most
+/// [MAX_GRADIENT_STOPS](constant.MAX_GRADIENT_STOPS.html) stops.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradientStops {
+    stops: [GradientStop; MAX_GRADIENT_STOPS],
+    len: usize,
+}
+
+impl GradientStops {
+    /// Create the stops of a gradient. The offsets are clamped to the range from 0 to 1, the
+    /// stops are sorted by offset and those beyond the maximum number are dropped.
+    pub fn new(stops: &[GradientStop]) -> Self {
+        let transparent = GradientStop {
+            offset: 0.0,
+            color: BackendColor {
+                alpha: 0.0,
+                rgb: (0, 0, 0),
+            },
+        };
+        let mut ret = Self {
+            stops: [transparent; MAX_GRADIENT_STOPS],
+            len: stops.len().min(MAX_GRADIENT_STOPS),
+        };
+        for (dst, src) in ret.stops.iter_mut().zip(stops) {
+            *dst = GradientStop {
+                offset: if src.offset.is_nan() {
+                    0.0
+                } else {
+                    src.offset.clamp(0.0, 1.0)
+                },
+                color: src.color,
+            };
+        }
+        // A stable sort, so that two stops at the same offset make a sharp transition
+        ret.stops[..ret.len].sort_by(|a, b| a.offset.partial_cmp(&b.offset).unwrap());
+        ret
+    }
+
+    /// Get the stops
+    pub fn as_slice(&self) -> &[GradientStop] {
+        &self.stops[..self.len]
+    }
+
+    /// Get the color at a position along the gradient. The colors before the first stop and
+    /// after the last one are padded, and a gradient without stops is transparent.
+    pub fn color_at(&self, t: f64) -> BackendColor {
+        let stops = self.as_slice();
+        let (first, last) = match (stops.first(), stops.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return self.stops[0].color,
+        };
+        if t.is_nan() || t <= first.offset {
+            return first.color;
+        }
+        if t >= last.offset {
+            return last.color;
+        }
+        let idx = stops.iter().position(|s| s.offset > t).unwrap();
+        let (a, b) = (&stops[idx - 1], &stops[idx]);
+        blend(a.color, b.color, (t - a.offset) / (b.offset - a.offset))
+    }
+
+    /// Get the average color along the gradient
+    pub fn mean(&self) -> BackendColor {
+        let stops = self.as_slice();
+        let (first, last) = match (stops.first(), stops.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return self.stops[0].color,
+        };
+        // The integral of each linear piece, including the padding at both ends
+        let mut parts = vec![(first.color, first.offset), (last.color, 1.0 - last.offset)];
+        for (a, b) in stops.iter().zip(stops.iter().skip(1)) {
+            let length = b.offset - a.offset;
+            parts.push((a.color, length / 2.0));
+            parts.push((b.color, length / 2.0));
+        }
+        weighted_mean(&parts)
+    }
+}
+
+/// Blend two colors linearly, with premultiplied alpha
+fn blend(a: BackendColor, b: BackendColor, t: f64) -> BackendColor {
+    weighted_mean(&[(a, 1.0 - t), (b, t)])
+}
+
+/// The average of colors, with premultiplied alpha, the weights sum to 1
+fn weighted_mean(parts: &[(BackendColor, f64)]) -> BackendColor {
+    let alpha: f64 = parts.iter().map(|(c, w)| c.alpha * w).sum();
+    if alpha <= 0.0 {
+        return BackendColor {
+            alpha: 0.0,
+            rgb: parts.first().map_or((0, 0, 0), |(c, _)| c.rgb),
+        };
+    }
+    let channel = |f: fn(&BackendColor) -> u8| {
+        let sum: f64 = parts
+            .iter()
+            .map(|(c, w)| f64::from(f(c)) * c.alpha * w)
+            .sum();
+        (sum / alpha).round().clamp(0.0, 255.0) as u8
+    };
+    BackendColor {
+        alpha,
+        rgb: (
+            channel(|c| c.rgb.0),
+            channel(|c| c.rgb.1),
+            channel(|c| c.rgb.2),
+        ),
+    }
+}
+
+/// The paint which fills a shape.
+///
+/// The geometry of the gradients is relative to the bounding box of the filled shape, where
+/// `(0, 0)` is its upper left corner and `(1, 1)` its bottom right corner. The hatch is in
+/// pixels, thus the hatches of adjacent shapes line up.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BackendPaint {
+    /// A single color
+    Solid(BackendColor),
+    /// The colors vary along the vector from `start` to `end`, and are constant across it
+    LinearGradient {
+        /// The position of offset 0
+        start: (f64, f64),
+        /// The position of offset 1
+        end: (f64, f64),
+        /// The color stops
+        stops: GradientStops,
+    },
+    /// The colors vary with the distance to `center`
+    RadialGradient {
+        /// The position of offset 0
+        center: (f64, f64),
+        /// The distance of offset 1
+        radius: f64,
+        /// The color stops
+        stops: GradientStops,
+    },
+    /// Parallel lines over a background
+    Hatch {
+        /// The color of the lines
+        color: BackendColor,
+        /// The color between the lines
+        background: BackendColor,
+        /// The angle of the lines in degrees, clockwise from horizontal
+        angle: f64,
+        /// The distance in pixels from a line to the next one
+        spacing: f64,
+        /// The width of the lines in pixels
+        width: f64,
+    },
+}
+
+impl BackendPaint {
+    /// Check if the paint is a single color
+    pub fn is_solid(&self) -> bool {
+        matches!(self, BackendPaint::Solid(_))
+    }
+
+    /// Get the average color of the paint, which is used by the backends that can't draw it
+    pub fn mean_color(&self) -> BackendColor {
+        match self {
+            BackendPaint::Solid(color) => *color,
+            BackendPaint::LinearGradient { stops, .. }
+            | BackendPaint::RadialGradient { stops, .. } => stops.mean(),
+            BackendPaint::Hatch {
+                color,
+                background,
+                spacing,
+                width,
+                ..
+            } => {
+                let coverage = if *spacing > 0.0 {
+                    (width / spacing).clamp(0.0, 1.0)
+                } else {
+                    1.0
+                };
+                blend(*background, *color, coverage)
+            }
+        }
+    }
+
+    /// Get the color of a pixel of a shape which bounding box is given by its upper left and
+    /// bottom right corners
+    pub fn color_at(
+        &self,
+        point: BackendCoord,
+        bounds: (BackendCoord, BackendCoord),
+    ) -> BackendColor {
+        let (x, y) = (f64::from(point.0), f64::from(point.1));
+        let ((x0, y0), (x1, y1)) = bounds;
+        let relative = |v: f64, from: i32, to: i32| {
+            if to > from {
+                (v - f64::from(from)) / f64::from(to - from)
+            } else {
+                0.0
+            }
+        };
+        let (u, v) = (relative(x, x0, x1), relative(y, y0, y1));
+        match self {
+            BackendPaint::Solid(color) => *color,
+            BackendPaint::LinearGradient { start, end, stops } => {
+                let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+                let length_square = dx * dx + dy * dy;
+                if length_square == 0.0 {
+                    return stops.color_at(1.0);
+                }
+                stops.color_at(((u - start.0) * dx + (v - start.1) * dy) / length_square)
+            }
+            BackendPaint::RadialGradient {
+                center,
+                radius,
+                stops,
+            } => {
+                let distance = (u - center.0).hypot(v - center.1);
+                if *radius <= 0.0 {
+                    return stops.color_at(1.0);
+                }
+                stops.color_at(distance / radius)
+            }
+            BackendPaint::Hatch {
+                color,
+                background,
+                angle,
+                spacing,
+                width,
+            } => {
+                if spacing.is_nan() || *spacing <= 0.0 {
+                    return *color;
+                }
+                // The distance across the lines, the lines are horizontal before the rotation
+                let (sin, cos) = angle.to_radians().sin_cos();
+                let across = (y * cos - x * sin).rem_euclid(*spacing);
+                if across < *width {
+                    *color
+                } else {
+                    *background
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn gray(level: u8, alpha: f64) -> BackendColor {
+        BackendColor {
+            alpha,
+            rgb: (level, level, level),
+        }
+    }
+
+    fn stop(offset: f64, color: BackendColor) -> GradientStop {
+        GradientStop { offset, color }
+    }
+
+    #[test]
+    fn test_gradient_stops() {
+        let stops = GradientStops::new(&[stop(1.0, gray(200, 1.0)), stop(0.5, gray(100, 1.0))]);
+        assert_eq!(stops.as_slice()[0].offset, 0.5);
+        assert_eq!(stops.color_at(0.0), gray(100, 1.0));
+        assert_eq!(stops.color_at(0.75), gray(150, 1.0));
+        assert_eq!(stops.color_at(2.0), gray(200, 1.0));
+        assert_eq!(stops.mean(), gray(125, 1.0));
+
+        // The color of a transparent stop doesn't bleed into the other one
+        let stops = GradientStops::new(&[stop(0.0, gray(0, 0.0)), stop(1.0, gray(200, 1.0))]);
+        assert_eq!(stops.color_at(0.5), gray(200, 0.5));
+        assert_eq!(GradientStops::new(&[]).color_at(0.5).alpha, 0.0);
+    }
+
+    #[test]
+    fn test_paint_color_at() {
+        let stops = GradientStops::new(&[stop(0.0, gray(0, 1.0)), stop(1.0, gray(100, 1.0))]);
+        let bounds = ((10, 10), (20, 30));
+        let paint = BackendPaint::LinearGradient {
+            start: (0.0, 0.0),
+            end: (0.0, 1.0),
+            stops,
+        };
+        assert_eq!(paint.color_at((15, 20), bounds), gray(50, 1.0));
+        assert_eq!(paint.color_at((10, 30), bounds), gray(100, 1.0));
+
+        let paint = BackendPaint::RadialGradient {
+            center: (0.5, 0.5),
+            radius: 0.5,
+            stops,
+        };
+        assert_eq!(paint.color_at((15, 20), bounds), gray(0, 1.0));
+        assert_eq!(paint.color_at((20, 20), bounds), gray(100, 1.0));
+
+        let paint = BackendPaint::Hatch {
+            color: gray(0, 1.0),
+            background: gray(255, 0.0),
+            angle: 0.0,
+            spacing: 4.0,
+            width: 1.0,
+        };
+        assert_eq!(paint.color_at((3, 8), bounds), gray(0, 1.0));
+        assert_eq!(paint.color_at((3, 9), bounds).alpha, 0.0);
+        assert_eq!(paint.mean_color(), gray(0, 0.25));
+    }
+}
@@ -1,3 +1,4 @@
+use super::{coverage, fill::Filler};
 use crate::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
 
 fn draw_part_a<
@@ -282,6 +283,28 @@ pub fn draw_circle<B: DrawingBackend, S: BackendStyle>(
         return Ok(());
     }
 
+    if b.is_anti_aliased() {
+        let (c, r) = (
+            (f64::from(center.0), f64::from(center.1)),
+            f64::from(radius),
+        );
+        if fill {
+            let bounds = (
+                (center.0 - radius as i32, center.1 - radius as i32),
+                (center.0 + radius as i32, center.1 + radius as i32),
+            );
+            return coverage::fill_ring(b, c, r, None, &Filler::new(style, bounds));
+        }
+        let half_width = f64::from(style.stroke_width()) / 2.0;
+        return coverage::fill_ring(
+            b,
+            c,
+            r + half_width,
+            Some(r - half_width),
+            &Filler::stroke(style),
+        );
+    }
+
     if !fill && style.stroke_width() != 1 {
         let inner_radius = radius - (style.stroke_width() / 2).min(radius);
         radius += style.stroke_width() / 2;
@@ -296,6 +319,15 @@ pub fn draw_circle<B: DrawingBackend, S: BackendStyle>(
     let max = (f64::from(radius) * (1.0 + (2f64).sqrt() / 2.0)).floor() as i32;
 
     let range = min..=max;
+    let r = radius as i32;
+    let filler = if fill {
+        Filler::new(
+            style,
+            ((center.0 - r, center.1 - r), (center.0 + r, center.1 + r)),
+        )
+    } else {
+        Filler::stroke(style)
+    };
 
     let (up, down) = (
         range.start() + center.1 - radius as i32,
@@ -320,21 +352,21 @@ pub fn draw_circle<B: DrawingBackend, S: BackendStyle>(
         let bottom = center.1 + lx.floor() as i32;
 
         if fill {
-            check_result!(b.draw_line((left, y), (right, y), &style.color()));
-            check_result!(b.draw_line((x, top), (x, up - 1), &style.color()));
-            check_result!(b.draw_line((x, down + 1), (x, bottom), &style.color()));
+            check_result!(filler.span(b, (left, y), (right, y)));
+            check_result!(filler.span(b, (x, top), (x, up - 1)));
+            check_result!(filler.span(b, (x, down + 1), (x, bottom)));
         } else {
-            check_result!(b.draw_pixel((left, y), style.color().mix(1.0 - v)));
-            check_result!(b.draw_pixel((right, y), style.color().mix(1.0 - v)));
+            check_result!(filler.pixel(b, (left, y), 1.0 - v));
+            check_result!(filler.pixel(b, (right, y), 1.0 - v));
 
-            check_result!(b.draw_pixel((x, top), style.color().mix(1.0 - v)));
-            check_result!(b.draw_pixel((x, bottom), style.color().mix(1.0 - v)));
+            check_result!(filler.pixel(b, (x, top), 1.0 - v));
+            check_result!(filler.pixel(b, (x, bottom), 1.0 - v));
         }
 
-        check_result!(b.draw_pixel((left - 1, y), style.color().mix(v)));
-        check_result!(b.draw_pixel((right + 1, y), style.color().mix(v)));
-        check_result!(b.draw_pixel((x, top - 1), style.color().mix(v)));
-        check_result!(b.draw_pixel((x, bottom + 1), style.color().mix(v)));
+        check_result!(filler.pixel(b, (left - 1, y), v));
+        check_result!(filler.pixel(b, (right + 1, y), v));
+        check_result!(filler.pixel(b, (x, top - 1), v));
+        check_result!(filler.pixel(b, (x, bottom + 1), v));
     }
 
     Ok(())
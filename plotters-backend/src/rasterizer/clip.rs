@@ -0,0 +1,168 @@
+use crate::{BackendCoord, DrawingBackend};
+
+/// A rectangle, both corners included
+type ClipRect = (BackendCoord, BackendCoord);
+
+/// The region of the backend worth rasterizing: the backend grown by its size on each side,
+/// which keeps the shapes cut at its border exact within the backend, even with wide strokes
+pub(crate) fn raster_rect<B: DrawingBackend>(back: &B) -> ClipRect {
+    let (w, h) = back.get_size();
+    let (w, h) = (
+        w.min(i32::MAX as u32 / 3) as i32,
+        h.min(i32::MAX as u32 / 3) as i32,
+    );
+    ((-w, -h), (2 * w, 2 * h))
+}
+
+fn contains((lo, hi): ClipRect, p: BackendCoord) -> bool {
+    lo.0 <= p.0 && p.0 <= hi.0 && lo.1 <= p.1 && p.1 <= hi.1
+}
+
+/// Whether some vertices are outside the rectangle
+pub(crate) fn exceeds(vertices: &[BackendCoord], rect: ClipRect) -> bool {
+    vertices.iter().any(|p| !contains(rect, *p))
+}
+
+/// Cut a segment to the rectangle, as the parameters of its visible ends, from 0 at `from` to 1
+/// at `to`. Returns `None` if the segment is outside the rectangle.
+fn clip_segment(from: BackendCoord, to: BackendCoord, (lo, hi): ClipRect) -> Option<(f64, f64)> {
+    let (mut t0, mut t1) = (0.0_f64, 1.0_f64);
+    let d = (f64::from(to.0 - from.0), f64::from(to.1 - from.1));
+    // Liang-Barsky: each side of the rectangle limits the parameter range
+    for &(p, q) in &[
+        (-d.0, f64::from(from.0 - lo.0)),
+        (d.0, f64::from(hi.0 - from.0)),
+        (-d.1, f64::from(from.1 - lo.1)),
+        (d.1, f64::from(hi.1 - from.1)),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else if p < 0.0 {
+            t0 = t0.max(q / p);
+        } else {
+            t1 = t1.min(q / p);
+        }
+    }
+    if t0 > t1 {
+        return None;
+    }
+    Some((t0, t1))
+}
+
+fn lerp(from: BackendCoord, to: BackendCoord, t: f64) -> BackendCoord {
+    (
+        (f64::from(from.0) + f64::from(to.0 - from.0) * t).round() as i32,
+        (f64::from(from.1) + f64::from(to.1 - from.1) * t).round() as i32,
+    )
+}
+
+fn distance(from: BackendCoord, to: BackendCoord) -> f64 {
+    f64::from(to.0 - from.0).hypot(f64::from(to.1 - from.1))
+}
+
+/// Cut an open path to the rectangle.
+///
+/// - **returns**: The visible parts of the path, each with the distance along the path at which
+///   it starts, which keeps the dash patterns in place
+pub(crate) fn clip_path(
+    vertices: &[BackendCoord],
+    rect: ClipRect,
+) -> Vec<(f64, Vec<BackendCoord>)> {
+    let mut parts: Vec<(f64, Vec<BackendCoord>)> = vec![];
+    let mut current: Option<(f64, Vec<BackendCoord>)> = None;
+    let mut travelled = 0.0;
+    for segment in vertices.windows(2) {
+        let (from, to) = (segment[0], segment[1]);
+        let length = distance(from, to);
+        match clip_segment(from, to, rect) {
+            Some((t0, t1)) => {
+                let (start, end) = (lerp(from, to, t0), lerp(from, to, t1));
+                let part = current.get_or_insert_with(|| (travelled + length * t0, vec![start]));
+                if part.1.last() != Some(&start) {
+                    part.1.push(start);
+                }
+                if part.1.last() != Some(&end) {
+                    part.1.push(end);
+                }
+                if t1 < 1.0 {
+                    parts.extend(current.take());
+                }
+            }
+            None => parts.extend(current.take()),
+        }
+        travelled += length;
+    }
+    parts.extend(current);
+    parts
+}
+
+/// Cut a polygon to the rectangle, with the Sutherland-Hodgman algorithm. The parts of the
+/// polygon outside the rectangle are replaced by its border.
+pub(crate) fn clip_polygon(vertices: &[BackendCoord], (lo, hi): ClipRect) -> Vec<BackendCoord> {
+    // The signed distance of a point inside each side of the rectangle
+    let sides: [&dyn Fn(BackendCoord) -> i64; 4] = [
+        &|p| i64::from(p.0) - i64::from(lo.0),
+        &|p| i64::from(hi.0) - i64::from(p.0),
+        &|p| i64::from(p.1) - i64::from(lo.1),
+        &|p| i64::from(hi.1) - i64::from(p.1),
+    ];
+    let mut output = vertices.to_vec();
+    for inside in sides.iter() {
+        let input = std::mem::take(&mut output);
+        let last = match input.last() {
+            Some(last) => *last,
+            None => break,
+        };
+        let mut prev = (last, inside(last));
+        for &p in &input {
+            let d = inside(p);
+            if (prev.1 >= 0) != (d >= 0) {
+                let t = prev.1 as f64 / (prev.1 - d) as f64;
+                output.push(lerp(prev.0, p, t));
+            }
+            if d >= 0 {
+                output.push(p);
+            }
+            prev = (p, d);
+        }
+    }
+    output.dedup();
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_clip_path() {
+        let rect = ((0, 0), (10, 10));
+        // The slope of the cut segment is kept
+        assert_eq!(
+            clip_path(&[(5, 5), (25, 15)], rect),
+            vec![(0.0, vec![(5, 5), (10, 8)])]
+        );
+        assert!(clip_path(&[(-5, -5), (-5, 20)], rect).is_empty());
+        let parts = clip_path(&[(-10, 5), (20, 5), (20, 8), (-10, 8)], rect);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0], (10.0, vec![(0, 5), (10, 5)]));
+        assert_eq!(parts[1].1, vec![(10, 8), (0, 8)]);
+        assert!((parts[1].0 - 43.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_clip_polygon() {
+        let rect = ((0, 0), (10, 10));
+        assert_eq!(
+            clip_polygon(&[(2, 2), (8, 2), (5, 8)], rect),
+            vec![(2, 2), (8, 2), (5, 8)]
+        );
+        assert_eq!(
+            clip_polygon(&[(5, -10), (25, 10), (5, 10)], rect),
+            vec![(5, 0), (10, 0), (10, 10), (5, 10)]
+        );
+        assert!(clip_polygon(&[(20, 20), (30, 20), (30, 30)], rect).is_empty());
+    }
+}
@@ -0,0 +1,363 @@
+use super::fill::Filler;
+use crate::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The coverage above which a pixel is drawn as fully covered
+const OPAQUE: f64 = 1.0 - 1.0 / 512.0;
+/// The coverage below which a pixel isn't drawn
+const TRANSPARENT: f64 = 1.0 / 512.0;
+
+/// The signed areas the edges of a shape leave in a rectangle of pixels. Summing a row from the
+/// left gives the fraction of each pixel covered by the shape.
+///
+/// The area coordinates have the pixel `(i, j)` of the rectangle spanning from `(i, j)` to
+/// `(i + 1, j + 1)`, while the backend coordinates are at the centers of the pixels.
+struct Accumulator {
+    origin: BackendCoord,
+    width: usize,
+    height: usize,
+    /// The rows of cells, with two more cells than pixels, which take the areas right of the
+    /// rectangle
+    cells: Vec<f64>,
+}
+
+impl Accumulator {
+    fn new(origin: BackendCoord, (width, height): (usize, usize)) -> Self {
+        Self {
+            origin,
+            width,
+            height,
+            cells: vec![0.0; (width + 2) * height],
+        }
+    }
+
+    fn add_edge(&mut self, from: (f64, f64), to: (f64, f64)) {
+        let to_area = |(x, y): (f64, f64)| {
+            (
+                x - f64::from(self.origin.0) + 0.5,
+                y - f64::from(self.origin.1) + 0.5,
+            )
+        };
+        let (from, to) = (to_area(from), to_area(to));
+        // A horizontal edge doesn't change the coverage
+        if from.1 == to.1 || from.1.is_nan() || to.1.is_nan() {
+            return;
+        }
+        let (sign, (x0, y0), (x1, y1)) = if from.1 < to.1 {
+            (1.0, from, to)
+        } else {
+            (-1.0, to, from)
+        };
+        let dxdy = (x1 - x0) / (y1 - y0);
+        let first_row = y0.max(0.0).floor() as usize;
+        let last_row = y1.min(self.height as f64).ceil() as usize;
+        for row in first_row..last_row {
+            let top = (row as f64).max(y0);
+            let bottom = ((row + 1) as f64).min(y1);
+            if bottom > top {
+                let left = x0 + (top - y0) * dxdy;
+                let right = x0 + (bottom - y0) * dxdy;
+                self.add_row_part(row, left, right, (bottom - top) * sign);
+            }
+        }
+    }
+
+    /// Add the part of an edge within a row, which goes from `a` to `b` horizontally, and
+    /// crosses `height` of the row, negative if it goes up
+    fn add_row_part(&mut self, row: usize, a: f64, b: f64, height: f64) {
+        let (left, right) = (a.min(b), a.max(b));
+        let length = right - left;
+        if length <= f64::EPSILON {
+            self.add_cell_part(row, left, right, height);
+            return;
+        }
+        // Split the part at the pixel boundaries. The parts beyond the rectangle are not split,
+        // their areas all fall into the first cell or after the last one.
+        let width = self.width as f64;
+        let mut add = |from: f64, to: f64| {
+            if to > from {
+                self.add_cell_part(row, from, to, height * (to - from) / length);
+            }
+        };
+        if left < 0.0 {
+            add(left, right.min(0.0));
+        }
+        let first = left.max(0.0).floor();
+        let last = right.min(width).ceil();
+        let mut x = first;
+        while x < last {
+            add(left.max(x), right.min(x + 1.0));
+            x += 1.0;
+        }
+        if right > width {
+            add(left.max(width), right);
+        }
+    }
+
+    /// Add a part of an edge which doesn't cross a pixel boundary
+    fn add_cell_part(&mut self, row: usize, from: f64, to: f64, height: f64) {
+        let x = ((from + to) / 2.0).clamp(0.0, self.width as f64);
+        let col = x.floor();
+        let fraction = x - col;
+        let idx = row * (self.width + 2) + col as usize;
+        // The pixel of the part is covered right of the part, the pixels after it entirely
+        self.cells[idx] += height * (1.0 - fraction);
+        self.cells[idx + 1] += height * fraction;
+    }
+
+    fn draw<B: DrawingBackend>(
+        &self,
+        back: &mut B,
+        filler: &Filler,
+    ) -> Result<(), DrawingErrorKind<B::ErrorType>> {
+        for (row, cells) in self.cells.chunks(self.width + 2).enumerate() {
+            let mut sum = 0.0;
+            check_result!(draw_row(
+                back,
+                filler,
+                (self.origin.0, self.origin.1 + row as i32),
+                cells[..self.width].iter().map(|area| {
+                    sum += area;
+                    // The shapes are filled with the nonzero rule
+                    f64::abs(sum).min(1.0)
+                })
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Draw a row of pixels from their coverage, starting at `from`. The fully covered pixels are
+/// drawn as spans.
+fn draw_row<B: DrawingBackend>(
+    back: &mut B,
+    filler: &Filler,
+    from: BackendCoord,
+    coverage: impl Iterator<Item = f64>,
+) -> Result<(), DrawingErrorKind<B::ErrorType>> {
+    let y = from.1;
+    let mut x = from.0;
+    let mut span_start = None;
+    for value in coverage {
+        if value >= OPAQUE {
+            span_start = span_start.or(Some(x));
+        } else {
+            if let Some(start) = span_start.take() {
+                check_result!(filler.span(back, (start, y), (x - 1, y)));
+            }
+            if value > TRANSPARENT {
+                check_result!(filler.pixel(back, (x, y), value));
+            }
+        }
+        x += 1;
+    }
+    if let Some(start) = span_start {
+        check_result!(filler.span(back, (start, y), (x - 1, y)));
+    }
+    Ok(())
+}
+
+/// The pixels of the backend within a range of coordinates, both ends included
+fn visible_range(from: f64, to: f64, size: u32) -> Option<(i32, i32)> {
+    let first = (from + 0.5).floor().max(0.0);
+    let last = (to + 0.5).floor().min(f64::from(size) - 1.0);
+    if first > last || first.is_nan() || last.is_nan() {
+        return None;
+    }
+    Some((first as i32, last as i32))
+}
+
+/// Fill a polygon, anti-aliased, with the vertices at sub-pixel positions
+pub(crate) fn fill_area<B: DrawingBackend>(
+    back: &mut B,
+    vertices: &[(f64, f64)],
+    filler: &Filler,
+) -> Result<(), DrawingErrorKind<B::ErrorType>> {
+    let (first, rest) = match vertices.split_first() {
+        Some(split) => split,
+        None => return Ok(()),
+    };
+    let (min, max) = rest.iter().fold((*first, *first), |(min, max), p| {
+        (
+            (min.0.min(p.0), min.1.min(p.1)),
+            (max.0.max(p.0), max.1.max(p.1)),
+        )
+    });
+    let size = back.get_size();
+    let (x_range, y_range) = match (
+        visible_range(min.0, max.0, size.0),
+        visible_range(min.1, max.1, size.1),
+    ) {
+        (Some(x_range), Some(y_range)) => (x_range, y_range),
+        _ => return Ok(()),
+    };
+
+    let mut acc = Accumulator::new(
+        (x_range.0, y_range.0),
+        (
+            (x_range.1 - x_range.0 + 1) as usize,
+            (y_range.1 - y_range.0 + 1) as usize,
+        ),
+    );
+    for (from, to) in vertices
+        .iter()
+        .zip(vertices.iter().skip(1).chain(Some(first)))
+    {
+        acc.add_edge(*from, *to);
+    }
+    acc.draw(back, filler)
+}
+
+/// Fill the area of a straight line, anti-aliased, with the ends extended by `extend`
+pub(crate) fn fill_line<B: DrawingBackend>(
+    back: &mut B,
+    from: BackendCoord,
+    to: BackendCoord,
+    width: f64,
+    extend: f64,
+    filler: &Filler,
+) -> Result<(), DrawingErrorKind<B::ErrorType>> {
+    let (from, to) = (
+        (f64::from(from.0), f64::from(from.1)),
+        (f64::from(to.0), f64::from(to.1)),
+    );
+    let length = (to.0 - from.0).hypot(to.1 - from.1);
+    if length < 1e-5 {
+        return Ok(());
+    }
+    let t = ((to.0 - from.0) / length, (to.1 - from.1) / length);
+    let n = (-t.1 * width / 2.0, t.0 * width / 2.0);
+    let from = (from.0 - t.0 * extend, from.1 - t.1 * extend);
+    let to = (to.0 + t.0 * extend, to.1 + t.1 * extend);
+    fill_area(
+        back,
+        &[
+            (from.0 + n.0, from.1 + n.1),
+            (to.0 + n.0, to.1 + n.1),
+            (to.0 - n.0, to.1 - n.1),
+            (from.0 - n.0, from.1 - n.1),
+        ],
+        filler,
+    )
+}
+
+/// Fill a ring, anti-aliased, between the circles of radius `outer` and `inner` around `center`.
+/// Without the inner circle, this fills a disc.
+pub(crate) fn fill_ring<B: DrawingBackend>(
+    back: &mut B,
+    center: (f64, f64),
+    outer: f64,
+    inner: Option<f64>,
+    filler: &Filler,
+) -> Result<(), DrawingErrorKind<B::ErrorType>> {
+    // A pixel is covered as much as its center is within half a pixel of the edge
+    let covered = |radius: f64, distance: f64| (radius + 0.5 - distance).clamp(0.0, 1.0);
+    let reach = outer + 0.5;
+    let size = back.get_size();
+    let rows = match visible_range(center.1 - reach, center.1 + reach, size.1) {
+        Some(rows) => rows,
+        None => return Ok(()),
+    };
+    for y in rows.0..=rows.1 {
+        let dy = f64::from(y) - center.1;
+        let half_width = (reach * reach - dy * dy).max(0.0).sqrt();
+        let (x0, x1) = match visible_range(center.0 - half_width, center.0 + half_width, size.0) {
+            Some(columns) => columns,
+            None => continue,
+        };
+        check_result!(draw_row(
+            back,
+            filler,
+            (x0, y),
+            (x0..=x1).map(|x| {
+                let distance = (f64::from(x) - center.0).hypot(dy);
+                covered(outer, distance) - inner.map_or(0.0, |inner| covered(inner, distance))
+            })
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{BackendColor, DrawingBackend};
+    use std::collections::HashMap;
+
+    /// A backend recording the alpha of each pixel drawn
+    struct Coverage(HashMap<BackendCoord, f64>);
+
+    impl DrawingBackend for Coverage {
+        type ErrorType = std::fmt::Error;
+
+        fn get_size(&self) -> (u32, u32) {
+            (20, 20)
+        }
+
+        fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+            Ok(())
+        }
+
+        fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+            Ok(())
+        }
+
+        fn draw_pixel(
+            &mut self,
+            point: BackendCoord,
+            color: BackendColor,
+        ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+            *self.0.entry(point).or_insert(0.0) += color.alpha;
+            Ok(())
+        }
+    }
+
+    fn draw(op: impl FnOnce(&mut Coverage, &Filler)) -> HashMap<BackendCoord, f64> {
+        let mut back = Coverage(HashMap::new());
+        let color = BackendColor {
+            alpha: 1.0,
+            rgb: (0, 0, 0),
+        };
+        op(&mut back, &Filler::stroke(&color));
+        back.0
+    }
+
+    fn assert_near(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn test_fill_area() {
+        let square = [(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)];
+        let pixels = draw(|b, f| fill_area(b, &square, f).unwrap());
+        // The edges go through the centers of the pixels
+        assert_near(pixels[&(2, 2)], 1.0);
+        assert_near(pixels[&(0, 2)], 0.5);
+        assert_near(pixels[&(4, 4)], 0.25);
+        assert_near(pixels.values().sum(), 16.0);
+
+        // The orientation doesn't matter, the parts out of the backend are dropped
+        let triangle = [(-10.0, -10.0), (10.0, 10.0), (10.0, -10.0)];
+        let pixels = draw(|b, f| fill_area(b, &triangle, f).unwrap());
+        assert_near(pixels[&(5, 5)], 0.5);
+        assert_near(pixels[&(6, 5)], 1.0);
+        assert!(!pixels.contains_key(&(5, 7)));
+        assert_near(pixels.values().sum(), 10.5 * 10.5 / 2.0);
+    }
+
+    #[test]
+    fn test_fill_line_and_ring() {
+        let pixels = draw(|b, f| fill_line(b, (2, 2), (12, 7), 1.0, 0.5, f).unwrap());
+        assert_near(pixels.values().sum(), 125f64.sqrt() + 1.0);
+        assert!(pixels.values().any(|c| *c < 0.9));
+
+        let pixels = draw(|b, f| fill_ring(b, (10.0, 10.0), 5.0, None, f).unwrap());
+        assert_near(pixels[&(10, 10)], 1.0);
+        assert_near(pixels[&(15, 10)], 0.5);
+        let area: f64 = pixels.values().sum();
+        assert!((area - std::f64::consts::PI * 25.0).abs() < 1.0);
+
+        let pixels = draw(|b, f| fill_ring(b, (10.0, 10.0), 5.5, Some(4.5), f).unwrap());
+        assert!(!pixels.contains_key(&(10, 10)));
+        assert_near(pixels[&(15, 10)], 1.0);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{BackendCoord, BackendPaint, BackendStyle, DrawingBackend, DrawingErrorKind};
+
+/// Draws the pixels of a filled shape with the paint of its style
+pub(crate) struct Filler {
+    paint: BackendPaint,
+    /// The bounding box of the shape, which the gradients are relative to
+    bounds: (BackendCoord, BackendCoord),
+}
+
+impl Filler {
+    pub(crate) fn new<S: BackendStyle>(style: &S, bounds: (BackendCoord, BackendCoord)) -> Self {
+        Self {
+            paint: style.paint(),
+            bounds,
+        }
+    }
+
+    /// A filler which draws the color of the style, which is used for the strokes
+    pub(crate) fn stroke<S: BackendStyle>(style: &S) -> Self {
+        Self {
+            paint: BackendPaint::Solid(style.color()),
+            bounds: ((0, 0), (0, 0)),
+        }
+    }
+
+    /// Fill a horizontal or vertical span, including both ends
+    pub(crate) fn span<B: DrawingBackend>(
+        &self,
+        back: &mut B,
+        from: BackendCoord,
+        to: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<B::ErrorType>> {
+        if let BackendPaint::Solid(color) = &self.paint {
+            return back.draw_line(from, to, color);
+        }
+        // Only the pixels within the backend are painted
+        let (w, h) = back.get_size();
+        if from.1 == to.1 {
+            for x in from.0.min(to.0).max(0)..=from.0.max(to.0).min(w as i32 - 1) {
+                check_result!(self.pixel(back, (x, from.1), 1.0));
+            }
+        } else {
+            for y in from.1.min(to.1).max(0)..=from.1.max(to.1).min(h as i32 - 1) {
+                check_result!(self.pixel(back, (from.0, y), 1.0));
+            }
+        }
+        Ok(())
+    }
+
+    /// Fill a pixel partially covered by the shape
+    pub(crate) fn pixel<B: DrawingBackend>(
+        &self,
+        back: &mut B,
+        point: BackendCoord,
+        coverage: f64,
+    ) -> Result<(), DrawingErrorKind<B::ErrorType>> {
+        let color = match &self.paint {
+            BackendPaint::Solid(color) => *color,
+            paint => paint.color_at(point, self.bounds),
+        };
+        back.draw_pixel(point, color.mix(coverage))
+    }
+}
@@ -1,4 +1,5 @@
-use crate::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use super::{coverage, fill::Filler};
+use crate::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind, LineCap};
 
 pub fn draw_line<DB: DrawingBackend, S: BackendStyle>(
     back: &mut DB,
@@ -10,6 +11,21 @@ pub fn draw_line<DB: DrawingBackend, S: BackendStyle>(
         return Ok(());
     }
 
+    if !style.dash_array().is_empty()
+        || (style.stroke_width() > 1 && style.line_cap() != LineCap::Butt)
+    {
+        return super::draw_path(back, &[from, to], style);
+    }
+
+    // The horizontal and vertical 1px lines cover whole pixels, thus they are drawn as below
+    let width = style.stroke_width();
+    if back.is_anti_aliased() && (width != 1 || (from.0 != to.0 && from.1 != to.1)) {
+        // A 1px line covers the pixels at its ends, as the aliased one does
+        let extend = if width == 1 { 0.5 } else { 0.0 };
+        let filler = Filler::stroke(style);
+        return coverage::fill_line(back, from, to, f64::from(width), extend, &filler);
+    }
+
     if style.stroke_width() != 1 {
         // If the line is wider than 1px, then we need to make it a polygon
         let v = (i64::from(to.0 - from.0), i64::from(to.1 - from.1));
@@ -36,14 +52,16 @@ pub fn draw_line<DB: DrawingBackend, S: BackendStyle>(
             trans.swap(0, 1);
         }
 
-        return back.fill_polygon(vertices, style);
+        return back.fill_polygon(vertices, &style.color());
     }
 
+    // Only the pixels within the backend are visited
+    let (w, h) = back.get_size();
     if from.0 == to.0 {
         if from.1 > to.1 {
             std::mem::swap(&mut from, &mut to);
         }
-        for y in from.1..=to.1 {
+        for y in from.1.max(0)..=to.1.min(h as i32 - 1) {
             check_result!(back.draw_pixel((from.0, y), style.color()));
         }
         return Ok(());
@@ -53,7 +71,7 @@ pub fn draw_line<DB: DrawingBackend, S: BackendStyle>(
         if from.0 > to.0 {
             std::mem::swap(&mut from, &mut to);
         }
-        for x in from.0..=to.0 {
+        for x in from.0.max(0)..=to.0.min(w as i32 - 1) {
             check_result!(back.draw_pixel((x, from.1), style.color()));
         }
         return Ok(());
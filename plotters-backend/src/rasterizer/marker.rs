@@ -0,0 +1,33 @@
+use crate::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind, MarkerShape};
+
+/// Draw a marker with the primitives of the backend
+pub fn draw_marker<B: DrawingBackend, S: BackendStyle>(
+    b: &mut B,
+    center: BackendCoord,
+    shape: MarkerShape,
+    size: i32,
+    style: &S,
+) -> Result<(), DrawingErrorKind<B::ErrorType>> {
+    let (x, y) = center;
+    match shape {
+        MarkerShape::Circle { filled } => b.draw_circle(center, size.max(0) as u32, style, filled),
+        MarkerShape::Cross => {
+            let (x0, y0) = (x - size, y - size);
+            let (x1, y1) = (x + size, y + size);
+            b.draw_line((x0, y0), (x1, y1), style)?;
+            b.draw_line((x0, y1), (x1, y0), style)
+        }
+        MarkerShape::Triangle => b.fill_polygon(triangle_vertices(center, size), &style.color()),
+    }
+}
+
+/// The vertices of a triangle marker
+pub fn triangle_vertices(center: BackendCoord, size: i32) -> [BackendCoord; 3] {
+    [-90, -210, -330].map(|deg| {
+        let rad = f64::from(deg) * std::f64::consts::PI / 180.0;
+        (
+            (rad.cos() * f64::from(size) + f64::from(center.0)).ceil() as i32,
+            (rad.sin() * f64::from(size) + f64::from(center.1)).ceil() as i32,
+        )
+    })
+}
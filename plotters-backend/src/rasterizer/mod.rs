@@ -25,6 +25,10 @@ macro_rules! check_result {
     };
 }
 
+mod clip;
+mod coverage;
+mod fill;
+
 mod line;
 pub use line::draw_line;
 
@@ -38,4 +42,7 @@ mod polygon;
 pub use polygon::fill_polygon;
 
 mod path;
-pub use path::polygonize;
+pub use path::{draw_path, polygonize, polygonize_stroke, split_dashes};
+
+mod marker;
+pub use marker::{draw_marker, triangle_vertices};
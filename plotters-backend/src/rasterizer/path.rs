@@ -1,4 +1,7 @@
-use crate::BackendCoord;
+use super::fill::Filler;
+use crate::{
+    BackendColor, BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind, LineCap, LineJoin,
+};
 
 // Compute the tanginal and normal vectors of the given straight line.
 fn get_dir_vector(from: BackendCoord, to: BackendCoord, flag: bool) -> ((f64, f64), (f64, f64)) {
@@ -14,10 +17,58 @@ fn get_dir_vector(from: BackendCoord, to: BackendCoord, flag: bool) -> ((f64, f6
     }
 }
 
+// A vertex of a polygonized stroke, either rounded to the pixels or kept precise for the
+// anti-aliased rasterization.
+pub(crate) trait StrokeVertex: Copy {
+    fn new(x: f64, y: f64) -> Self;
+
+    // The vertex of a straight angle, which the pixel vertices truncate.
+    fn straight(x: f64, y: f64) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl StrokeVertex for BackendCoord {
+    fn new(x: f64, y: f64) -> Self {
+        (x.round() as i32, y.round() as i32)
+    }
+
+    fn straight(x: f64, y: f64) -> Self {
+        (x as i32, y as i32)
+    }
+}
+
+impl StrokeVertex for (f64, f64) {
+    fn new(x: f64, y: f64) -> Self {
+        (x, y)
+    }
+}
+
+// Emit the points of a circular arc around center, from angle `from` to angle `to` (exclusive of
+// both ends), with the segments about 2 pixels long.
+fn push_arc<V: StrokeVertex>(
+    center: (f64, f64),
+    r: f64,
+    from: f64,
+    to: f64,
+    mut op: impl FnMut(V),
+) {
+    let steps = ((to - from).abs() * r / 2.0).ceil().max(2.0) as usize;
+    for i in 1..steps {
+        let a = from + (to - from) * i as f64 / steps as f64;
+        op(V::new(center.0 + r * a.cos(), center.1 + r * a.sin()));
+    }
+}
+
 // Compute the polygonized vertex of the given angle
 // d is the distance between the polygon edge and the actual line.
 // d can be negative, this will emit a vertex on the other side of the line.
-fn compute_polygon_vertex(triple: &[BackendCoord; 3], d: f64, buf: &mut Vec<BackendCoord>) {
+fn compute_polygon_vertex<V: StrokeVertex>(
+    triple: &[BackendCoord; 3],
+    d: f64,
+    join: LineJoin,
+    buf: &mut Vec<V>,
+) {
     buf.clear();
 
     // Compute the tanginal and normal vectors of the given straight line.
@@ -36,7 +87,7 @@ fn compute_polygon_vertex(triple: &[BackendCoord; 3], d: f64, buf: &mut Vec<Back
 
     // Check if 3 points are colinear, up to precision. If so, just emit the point.
     if (a_t.1 * b_t.0 - a_t.0 * b_t.1).abs() <= f64::EPSILON {
-        buf.push((a_p.0 as i32, a_p.1 as i32));
+        buf.push(V::straight(a_p.0, a_p.1));
         return;
     }
 
@@ -68,23 +119,47 @@ fn compute_polygon_vertex(triple: &[BackendCoord; 3], d: f64, buf: &mut Vec<Back
 
     let cross_product = a_t.0 * b_t.1 - a_t.1 * b_t.0;
     if (cross_product < 0.0 && d < 0.0) || (cross_product > 0.0 && d > 0.0) {
-        // Then we are at the outer side of the angle, so we need to consider a cap.
-        let dist_square = (x - triple[1].0 as f64).powi(2) + (y - triple[1].1 as f64).powi(2);
-        // If the point is too far away from the line, we need to cap it.
-        if dist_square > d * d * 16.0 {
-            buf.push((a_p.0.round() as i32, a_p.1.round() as i32));
-            buf.push((b_p.0.round() as i32, b_p.1.round() as i32));
+        // Then we are at the outer side of the angle, so the join shapes the corner.
+        let bevel = match join {
+            LineJoin::Miter { limit } => {
+                let dist_square =
+                    (x - triple[1].0 as f64).powi(2) + (y - triple[1].1 as f64).powi(2);
+                // The miter length is twice the distance, relative to the width which is 2d.
+                dist_square > d * d * limit * limit
+            }
+            LineJoin::Bevel => true,
+            LineJoin::Round => {
+                let center = (f64::from(triple[1].0), f64::from(triple[1].1));
+                let from = (a_p.1 - center.1).atan2(a_p.0 - center.0);
+                let mut to = (b_p.1 - center.1).atan2(b_p.0 - center.0);
+                // Go around the outer side, which is the shorter way
+                if to - from > std::f64::consts::PI {
+                    to -= 2.0 * std::f64::consts::PI;
+                } else if from - to > std::f64::consts::PI {
+                    to += 2.0 * std::f64::consts::PI;
+                }
+                buf.push(V::new(a_p.0, a_p.1));
+                push_arc(center, d.abs(), from, to, |p| buf.push(p));
+                buf.push(V::new(b_p.0, b_p.1));
+                return;
+            }
+        };
+        if bevel {
+            buf.push(V::new(a_p.0, a_p.1));
+            buf.push(V::new(b_p.0, b_p.1));
             return;
         }
     }
 
-    buf.push((x.round() as i32, y.round() as i32));
+    buf.push(V::new(x, y));
 }
 
-fn traverse_vertices<'a>(
+fn traverse_vertices<'a, V: StrokeVertex>(
     mut vertices: impl Iterator<Item = &'a BackendCoord>,
     width: u32,
-    mut op: impl FnMut(BackendCoord),
+    cap: LineCap,
+    join: LineJoin,
+    mut op: impl FnMut(V),
 ) {
     let mut a = vertices.next().unwrap();
     let mut b = vertices.next().unwrap();
@@ -100,9 +175,9 @@ fn traverse_vertices<'a>(
 
     let (_, n) = get_dir_vector(*a, *b, false);
 
-    op((
-        (f64::from(a.0) + n.0 * f64::from(width) / 2.0).round() as i32,
-        (f64::from(a.1) + n.1 * f64::from(width) / 2.0).round() as i32,
+    op(V::new(
+        f64::from(a.0) + n.0 * f64::from(width) / 2.0,
+        f64::from(a.1) + n.1 * f64::from(width) / 2.0,
     ));
 
     let mut recent = [(0, 0), *a, *b];
@@ -115,35 +190,260 @@ fn traverse_vertices<'a>(
         recent.swap(0, 1);
         recent.swap(1, 2);
         recent[2] = *p;
-        compute_polygon_vertex(&recent, f64::from(width) / 2.0, &mut vertex_buf);
+        compute_polygon_vertex(&recent, f64::from(width) / 2.0, join, &mut vertex_buf);
         vertex_buf.iter().cloned().for_each(&mut op);
     }
 
     let b = recent[1];
     let a = recent[2];
 
-    let (_, n) = get_dir_vector(a, b, true);
+    let (t, n) = get_dir_vector(a, b, true);
+    let d = f64::from(width) / 2.0;
+    let a = (f64::from(a.0), f64::from(a.1));
 
-    op((
-        (f64::from(a.0) + n.0 * f64::from(width) / 2.0).round() as i32,
-        (f64::from(a.1) + n.1 * f64::from(width) / 2.0).round() as i32,
-    ));
+    // The cap of the end, the traversal in the other direction starts on the other side
+    match cap {
+        LineCap::Butt => op(V::new(a.0 + n.0 * d, a.1 + n.1 * d)),
+        LineCap::Square => {
+            for side in [d, -d] {
+                op(V::new(
+                    a.0 + n.0 * side - t.0 * d,
+                    a.1 + n.1 * side - t.1 * d,
+                ));
+            }
+        }
+        LineCap::Round => {
+            op(V::new(a.0 + n.0 * d, a.1 + n.1 * d));
+            // The half circle from one side to the other, through the point ahead of the end
+            let steps = (std::f64::consts::PI * d / 2.0).ceil().max(2.0) as usize;
+            for i in 1..steps {
+                let (sin, cos) = (std::f64::consts::PI * i as f64 / steps as f64).sin_cos();
+                op(V::new(
+                    a.0 + d * (n.0 * cos - t.0 * sin),
+                    a.1 + d * (n.1 * cos - t.1 * sin),
+                ));
+            }
+        }
+    }
 }
 
 /// Covert a path with >1px stroke width into polygon.
 pub fn polygonize(vertices: &[BackendCoord], stroke_width: u32) -> Vec<BackendCoord> {
+    polygonize_stroke(
+        vertices,
+        stroke_width,
+        LineCap::default(),
+        LineJoin::default(),
+    )
+}
+
+/// Covert a path with >1px stroke width into polygon, with the given shapes of the ends and the
+/// corners of the stroke.
+pub fn polygonize_stroke(
+    vertices: &[BackendCoord],
+    stroke_width: u32,
+    cap: LineCap,
+    join: LineJoin,
+) -> Vec<BackendCoord> {
+    polygonize_stroke_vertices(vertices, stroke_width, cap, join)
+}
+
+pub(crate) fn polygonize_stroke_vertices<V: StrokeVertex>(
+    vertices: &[BackendCoord],
+    stroke_width: u32,
+    cap: LineCap,
+    join: LineJoin,
+) -> Vec<V> {
     if vertices.len() < 2 {
         return vec![];
     }
 
     let mut ret = vec![];
 
-    traverse_vertices(vertices.iter(), stroke_width, |v| ret.push(v));
-    traverse_vertices(vertices.iter().rev(), stroke_width, |v| ret.push(v));
+    traverse_vertices(vertices.iter(), stroke_width, cap, join, |v| ret.push(v));
+    traverse_vertices(vertices.iter().rev(), stroke_width, cap, join, |v| {
+        ret.push(v)
+    });
 
     ret
 }
 
+/// Split a path into the dashes of a dash pattern, see [BackendStyle::dash_array]
+///
+/// - `vertices`: The vertices of the path
+/// - `dash_array`: The lengths of the dashes and the gaps, alternately. A pattern with an odd
+///   number of lengths is repeated to make it even, as SVG does.
+/// - `dash_offset`: The distance into the pattern at which the path starts
+/// - **returns**: The vertices of each dash. The path is returned as a single dash if the
+///   pattern is empty or invalid, i.e. it has a negative length or only zero lengths.
+pub fn split_dashes(
+    vertices: &[BackendCoord],
+    dash_array: &[f64],
+    dash_offset: f64,
+) -> Vec<Vec<BackendCoord>> {
+    let total: f64 = dash_array.iter().sum();
+    if dash_array.iter().any(|l| !l.is_finite() || *l < 0.0) || total.is_nan() || total <= 0.0 {
+        return vec![vertices.to_vec()];
+    }
+    let pattern: Vec<f64> = if dash_array.len() % 2 == 1 {
+        dash_array.iter().chain(dash_array).copied().collect()
+    } else {
+        dash_array.to_vec()
+    };
+    let total: f64 = pattern.iter().sum();
+
+    // Find where the pattern starts
+    let mut idx = 0;
+    let mut remaining = dash_offset.rem_euclid(total);
+    while remaining >= pattern[idx] {
+        remaining -= pattern[idx];
+        idx = (idx + 1) % pattern.len();
+    }
+    remaining = pattern[idx] - remaining;
+
+    let to_coord = |(x, y): (f64, f64)| (x.round() as i32, y.round() as i32);
+    let mut dashes = vec![];
+    let mut current = vec![];
+    if idx % 2 == 0 {
+        if let Some(&first) = vertices.first() {
+            current.push(first);
+        }
+    }
+    for segment in vertices.windows(2) {
+        let from = (f64::from(segment[0].0), f64::from(segment[0].1));
+        let v = (
+            f64::from(segment[1].0) - from.0,
+            f64::from(segment[1].1) - from.1,
+        );
+        let length = (v.0 * v.0 + v.1 * v.1).sqrt();
+        let mut pos = 0.0;
+        while length - pos > remaining {
+            pos += remaining;
+            let point = to_coord((from.0 + v.0 * pos / length, from.1 + v.1 * pos / length));
+            if current.last() != Some(&point) {
+                current.push(point);
+            }
+            if idx % 2 == 0 {
+                dashes.push(std::mem::take(&mut current));
+            }
+            idx = (idx + 1) % pattern.len();
+            remaining = pattern[idx];
+        }
+        remaining -= length - pos;
+        if idx % 2 == 0 && current.last() != Some(&segment[1]) {
+            current.push(segment[1]);
+        }
+    }
+    if idx % 2 == 0 && current.len() > 1 {
+        dashes.push(current);
+    }
+    dashes.retain(|dash| dash.windows(2).any(|w| w[0] != w[1]));
+    dashes
+}
+
+/// The style of a single dash, which is the style of the dashed stroke without the pattern
+struct Dash {
+    color: BackendColor,
+    stroke_width: u32,
+    line_cap: LineCap,
+    line_join: LineJoin,
+}
+
+impl BackendStyle for Dash {
+    fn color(&self) -> BackendColor {
+        self.color
+    }
+
+    fn stroke_width(&self) -> u32 {
+        self.stroke_width
+    }
+
+    fn line_cap(&self) -> LineCap {
+        self.line_cap
+    }
+
+    fn line_join(&self) -> LineJoin {
+        self.line_join
+    }
+}
+
+/// Draw a path with the dashes, the caps and the joins of the style. The caps and the joins
+/// only apply to strokes wider than 1 pixel.
+pub fn draw_path<DB: DrawingBackend, S: BackendStyle>(
+    back: &mut DB,
+    path: &[BackendCoord],
+    style: &S,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    if style.color().alpha == 0.0 {
+        return Ok(());
+    }
+
+    // The parts far away from the backend are cut, which keeps the dashes along them cheap
+    let rect = super::clip::raster_rect(back);
+    if super::clip::exceeds(path, rect) {
+        for (start, part) in super::clip::clip_path(path, rect) {
+            check_result!(draw_dashed_path(
+                back,
+                &part,
+                style,
+                style.dash_offset() + start
+            ));
+        }
+        return Ok(());
+    }
+    draw_dashed_path(back, path, style, style.dash_offset())
+}
+
+fn draw_dashed_path<DB: DrawingBackend, S: BackendStyle>(
+    back: &mut DB,
+    path: &[BackendCoord],
+    style: &S,
+    dash_offset: f64,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    if style.dash_array().is_empty() {
+        return draw_solid_path(back, path, style);
+    }
+    let dash_style = Dash {
+        color: style.color(),
+        stroke_width: style.stroke_width(),
+        line_cap: style.line_cap(),
+        line_join: style.line_join(),
+    };
+    for dash in split_dashes(path, style.dash_array(), dash_offset) {
+        check_result!(draw_solid_path(back, &dash, &dash_style));
+    }
+    Ok(())
+}
+
+fn draw_solid_path<DB: DrawingBackend, S: BackendStyle>(
+    back: &mut DB,
+    path: &[BackendCoord],
+    style: &S,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    if style.stroke_width() == 1 {
+        for segment in path.windows(2) {
+            check_result!(back.draw_line(segment[0], segment[1], style));
+        }
+        Ok(())
+    } else if back.is_anti_aliased() {
+        let v = polygonize_stroke_vertices(
+            path,
+            style.stroke_width(),
+            style.line_cap(),
+            style.line_join(),
+        );
+        super::coverage::fill_area(back, &v, &Filler::stroke(style))
+    } else {
+        let v = polygonize_stroke(
+            path,
+            style.stroke_width(),
+            style.line_cap(),
+            style.line_join(),
+        );
+        back.fill_polygon(v, &style.color())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -152,8 +452,8 @@ mod test {
     #[test]
     fn test_no_inf_in_compute_polygon_vertex() {
         let path = [(335, 386), (338, 326), (340, 286)];
-        let mut buf = Vec::new();
-        compute_polygon_vertex(&path, 2.0, buf.as_mut());
+        let mut buf: Vec<BackendCoord> = Vec::new();
+        compute_polygon_vertex(&path, 2.0, LineJoin::default(), buf.as_mut());
         assert!(!buf.is_empty());
         let nani32 = f64::INFINITY as i32;
         assert!(!buf.iter().any(|&v| v.0 == nani32 || v.1 == nani32));
@@ -163,10 +463,64 @@ mod test {
     #[test]
     fn standard_corner() {
         let path = [(10, 10), (20, 10), (20, 20)];
-        let mut buf = Vec::new();
-        compute_polygon_vertex(&path, 2.0, buf.as_mut());
+        let mut buf: Vec<BackendCoord> = Vec::new();
+        compute_polygon_vertex(&path, 2.0, LineJoin::default(), buf.as_mut());
         assert!(!buf.is_empty());
         let buf2 = vec![(18, 12)];
         assert_eq!(buf, buf2);
     }
+
+    #[test]
+    fn test_split_dashes() {
+        let path = [(0, 0), (10, 0), (10, 10)];
+        assert_eq!(
+            split_dashes(&path, &[4.0, 2.0], 0.0),
+            vec![
+                vec![(0, 0), (4, 0)],
+                vec![(6, 0), (10, 0)],
+                vec![(10, 2), (10, 6)],
+                vec![(10, 8), (10, 10)],
+            ]
+        );
+        // The dash at the corner continues on the next segment
+        assert_eq!(
+            split_dashes(&path, &[3.0], 1.0),
+            vec![
+                vec![(0, 0), (2, 0)],
+                vec![(5, 0), (8, 0)],
+                vec![(10, 1), (10, 4)],
+                vec![(10, 7), (10, 10)],
+            ]
+        );
+        assert_eq!(split_dashes(&path, &[0.0, 0.0], 0.0), vec![path.to_vec()]);
+    }
+
+    #[test]
+    fn test_polygonize_caps_and_joins() {
+        let path = [(10, 10), (20, 10), (20, 20)];
+        let butt = polygonize(&path, 4);
+        assert_eq!(
+            butt,
+            vec![(10, 12), (18, 12), (18, 20), (22, 20), (22, 8), (10, 8)]
+        );
+        // The square caps extend the ends, the bevel cuts the outer corner
+        let square = polygonize_stroke(&path, 4, LineCap::Square, LineJoin::Bevel);
+        assert_eq!(
+            square,
+            vec![
+                (10, 12),
+                (18, 12),
+                (18, 22),
+                (22, 22),
+                (22, 20),
+                (22, 10),
+                (20, 8),
+                (8, 8),
+                (8, 12)
+            ]
+        );
+        let round = polygonize_stroke(&path, 4, LineCap::Round, LineJoin::Round);
+        assert!(round.len() > butt.len());
+        assert!(round.contains(&(8, 10)) && round.contains(&(20, 22)));
+    }
 }
@@ -1,3 +1,4 @@
+use super::{clip, fill::Filler};
 use crate::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
 
 use std::cmp::{Ord, Ordering, PartialOrd};
@@ -69,34 +70,60 @@ impl Ord for Edge {
     }
 }
 
+/// The ranges of the coordinates of the vertices
+fn spans(vertices: &[BackendCoord]) -> Option<((i32, i32), (i32, i32))> {
+    vertices
+        .iter()
+        .fold(None, |res: Option<((i32, i32), (i32, i32))>, (x, y)| {
+            Some(
+                res.map(|((min_x, max_x), (min_y, max_y))| {
+                    (
+                        (min_x.min(*x), max_x.max(*x)),
+                        (min_y.min(*y), max_y.max(*y)),
+                    )
+                })
+                .unwrap_or(((*x, *x), (*y, *y))),
+            )
+        })
+}
+
 pub fn fill_polygon<DB: DrawingBackend, S: BackendStyle>(
     back: &mut DB,
     vertices: &[BackendCoord],
     style: &S,
 ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-    if let Some((x_span, y_span)) =
-        vertices
-            .iter()
-            .fold(None, |res: Option<((i32, i32), (i32, i32))>, (x, y)| {
-                Some(
-                    res.map(|((min_x, max_x), (min_y, max_y))| {
-                        (
-                            (min_x.min(*x), max_x.max(*x)),
-                            (min_y.min(*y), max_y.max(*y)),
-                        )
-                    })
-                    .unwrap_or(((*x, *x), (*y, *y))),
-                )
-            })
-    {
+    if let Some((x_span, y_span)) = spans(vertices) {
         // First of all, let's handle the case that all the points is in a same vertical or
         // horizontal line
         if x_span.0 == x_span.1 || y_span.0 == y_span.1 {
             return back.draw_line((x_span.0, y_span.0), (x_span.1, y_span.1), style);
         }
 
+        // The paint spans the whole polygon, while only its part around the backend is swept
+        let filler = Filler::new(style, ((x_span.0, y_span.0), (x_span.1, y_span.1)));
+        let rect = clip::raster_rect(back);
+        let clipped;
+        let vertices = if clip::exceeds(vertices, rect) {
+            clipped = clip::clip_polygon(vertices, rect);
+            &clipped[..]
+        } else {
+            vertices
+        };
+        let (x_span, y_span) = match spans(vertices) {
+            Some(spans) => spans,
+            None => return Ok(()),
+        };
+
         let horizontal_sweep = x_span.1 - x_span.0 > y_span.1 - y_span.0;
 
+        if back.is_anti_aliased() {
+            let vertices: Vec<_> = vertices
+                .iter()
+                .map(|(x, y)| (f64::from(*x), f64::from(*y)))
+                .collect();
+            return super::coverage::fill_area(back, &vertices, &filler);
+        }
+
         let mut edges: Vec<_> = vertices
             .iter()
             .zip(vertices.iter().skip(1))
@@ -201,32 +228,36 @@ pub fn fill_polygon<DB: DrawingBackend, S: BackendStyle>(
                         }
 
                         if horizontal_sweep {
-                            check_result!(back.draw_line(
+                            check_result!(filler.span(
+                                back,
                                 (sweep_line, from.ceil() as i32),
                                 (sweep_line, to.floor() as i32),
-                                &style.color(),
                             ));
-                            check_result!(back.draw_pixel(
+                            check_result!(filler.pixel(
+                                back,
                                 (sweep_line, from.floor() as i32),
-                                style.color().mix(from.ceil() - from),
+                                from.ceil() - from,
                             ));
-                            check_result!(back.draw_pixel(
+                            check_result!(filler.pixel(
+                                back,
                                 (sweep_line, to.ceil() as i32),
-                                style.color().mix(to - to.floor()),
+                                to - to.floor(),
                             ));
                         } else {
-                            check_result!(back.draw_line(
+                            check_result!(filler.span(
+                                back,
                                 (from.ceil() as i32, sweep_line),
                                 (to.floor() as i32, sweep_line),
-                                &style.color(),
                             ));
-                            check_result!(back.draw_pixel(
+                            check_result!(filler.pixel(
+                                back,
                                 (from.floor() as i32, sweep_line),
-                                style.color().mix(from.ceil() - from),
+                                from.ceil() - from,
                             ));
-                            check_result!(back.draw_pixel(
+                            check_result!(filler.pixel(
+                                back,
                                 (to.ceil() as i32, sweep_line),
-                                style.color().mix(to.floor() - to),
+                                to.floor() - to,
                             ));
                         }
 
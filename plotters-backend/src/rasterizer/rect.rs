@@ -1,3 +1,4 @@
+use super::fill::Filler;
 use crate::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
 
 pub fn draw_rect<B: DrawingBackend, S: BackendStyle>(
@@ -21,13 +22,24 @@ pub fn draw_rect<B: DrawingBackend, S: BackendStyle>(
         ),
     );
 
-    if fill {
+    // The rows and the columns of the fills outside the backend are skipped
+    let (w, h) = b.get_size();
+    let (columns, rows) = (
+        upper_left.0.max(0)..=bottom_right.0.min(w as i32 - 1),
+        upper_left.1.max(0)..=bottom_right.1.min(h as i32 - 1),
+    );
+    if fill && !style.paint().is_solid() {
+        let filler = Filler::new(style, (upper_left, bottom_right));
+        for y in rows {
+            check_result!(filler.span(b, (upper_left.0, y), (bottom_right.0, y)));
+        }
+    } else if fill {
         if bottom_right.0 - upper_left.0 < bottom_right.1 - upper_left.1 {
-            for x in upper_left.0..=bottom_right.0 {
+            for x in columns {
                 check_result!(b.draw_line((x, upper_left.1), (x, bottom_right.1), style));
             }
         } else {
-            for y in upper_left.1..=bottom_right.1 {
+            for y in rows {
                 check_result!(b.draw_line((upper_left.0, y), (bottom_right.0, y), style));
             }
         }
@@ -1,3 +1,5 @@
+use crate::BackendPaint;
+
 /// The color type that is used by all the backend
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct BackendColor {
@@ -15,6 +17,46 @@ impl BackendColor {
     }
 }
 
+/// The shape at the ends of an open stroke
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineCap {
+    /// The stroke ends exactly at the end points, this is the default
+    Butt,
+    /// The stroke ends with a half circle around the end points
+    Round,
+    /// The stroke extends beyond the end points by half of its width
+    Square,
+}
+
+// `#[default]` on enum variants isn't available at the MSRV
+#[allow(clippy::derivable_impls)]
+impl Default for LineCap {
+    fn default() -> Self {
+        LineCap::Butt
+    }
+}
+
+/// The shape at the corners of a stroke
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineJoin {
+    /// The outer edges are extended until they meet. When the ratio of the length of the miter
+    /// to the stroke width exceeds `limit`, the corner is beveled instead.
+    Miter {
+        /// The miter limit, which is 4 by default
+        limit: f64,
+    },
+    /// The corner is rounded with a circular arc
+    Round,
+    /// The corner is cut off at the end of the outer edges
+    Bevel,
+}
+
+impl Default for LineJoin {
+    fn default() -> Self {
+        LineJoin::Miter { limit: 4.0 }
+    }
+}
+
 /// The style data for the backend drawing API
 pub trait BackendStyle {
     /// Get the color of current style
@@ -24,6 +66,35 @@ pub trait BackendStyle {
     fn stroke_width(&self) -> u32 {
         1
     }
+
+    /// Get the dash pattern of current style, which are the lengths in pixels of the dashes and
+    /// the gaps between them, alternately. An empty pattern, the default, draws a solid stroke.
+    fn dash_array(&self) -> &[f64] {
+        &[]
+    }
+
+    /// Get the distance into the dash pattern at which the stroke starts
+    fn dash_offset(&self) -> f64 {
+        0.0
+    }
+
+    /// Get the shape at the ends of the open strokes of current style
+    fn line_cap(&self) -> LineCap {
+        LineCap::default()
+    }
+
+    /// Get the shape at the corners of the strokes of current style
+    fn line_join(&self) -> LineJoin {
+        LineJoin::default()
+    }
+
+    /// Get the paint which fills the shapes of current style, the strokes are drawn with
+    /// [color](#tymethod.color). Backends which don't support a paint draw it with its
+    /// [mean color](enum.BackendPaint.html#method.mean_color), which is what the styles with
+    /// a paint should return as their color.
+    fn paint(&self) -> BackendPaint {
+        BackendPaint::Solid(self.color())
+    }
 }
 
 impl BackendStyle for BackendColor {
@@ -176,6 +176,21 @@ impl<'a> From<&'a str> for FontStyle {
     }
 }
 
+/// A command of the outline of a text, in pixels with the y axis pointing down
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutlineCommand {
+    /// Start a new contour at the point
+    MoveTo(f64, f64),
+    /// A line to the point
+    LineTo(f64, f64),
+    /// A quadratic Bézier curve with a control point, to the point
+    QuadTo((f64, f64), (f64, f64)),
+    /// A cubic Bézier curve with two control points, to the point
+    CubicTo((f64, f64), (f64, f64), (f64, f64)),
+    /// Close the contour
+    Close,
+}
+
 /// The trait that abstracts a style of a text.
 ///
 /// This is used because the the backend crate have no knowledge about how
@@ -228,4 +243,17 @@ pub trait BackendTextStyle {
         pos: BackendCoord,
         draw: DrawFunc,
     ) -> Result<Result<(), E>, Self::FontError>;
+
+    /// Trace the outlines of the glyphs of the text, placed as [draw](BackendTextStyle::draw)
+    /// places them. The transform of the text isn't applied.
+    ///
+    /// Returns `false` if the font implementation can't provide the outlines, the default.
+    fn outline<F: FnMut(OutlineCommand)>(
+        &self,
+        _text: &str,
+        _pos: BackendCoord,
+        _sink: F,
+    ) -> Result<bool, Self::FontError> {
+        Ok(false)
+    }
 }
@@ -1,10 +1,11 @@
 use plotters_backend::{
-    BackendColor, BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind,
+    BackendColor, BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind, ElementContext,
 };
 use std::marker::PhantomData;
 
 use crate::bitmap_pixel::{PixelFormat, RGBPixel};
 use crate::error::BitMapBackendError;
+use crate::hit_test::{HitRegionFormat, HitTestIndex};
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
 mod image_encoding_support {
@@ -18,7 +19,7 @@ use image_encoding_support::*;
 
 mod target;
 
-use target::{Buffer, Target};
+use target::{Buffer, HitIndex, Target};
 
 /// The backend that drawing a bitmap
 ///
@@ -40,6 +41,17 @@ pub struct BitMapBackend<'a, P: PixelFormat = RGBPixel> {
     buffer: Buffer<'a>,
     /// Flag indicates if the bitmap has been saved
     saved: bool,
+    /// The index recording the data elements drawn, if hit-testing is enabled
+    hit_index: Option<HitIndex<'a>>,
+    /// The file the hit regions are written to on present
+    #[cfg(not(target_arch = "wasm32"))]
+    hit_regions_file: Option<(&'a std::path::Path, HitRegionFormat)>,
+    /// Flag indicates if the data series are clipped to their plotting area
+    clipping: bool,
+    /// The clip regions, each intersected with the previous one, both corners included
+    clips: Vec<(BackendCoord, BackendCoord)>,
+    /// Flag indicates if the shapes are drawn anti-aliased
+    anti_aliased: bool,
     _phantomdata: PhantomData<P>,
 }
 
@@ -57,6 +69,12 @@ impl<'a> BitMapBackend<'a, RGBPixel> {
             size: (w, h),
             buffer: Buffer::Owned(vec![0; Self::PIXEL_SIZE * (w * h) as usize]),
             saved: false,
+            hit_index: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            hit_regions_file: None,
+            clipping: false,
+            clips: vec![],
+            anti_aliased: false,
             _phantomdata: PhantomData,
         }
     }
@@ -85,6 +103,12 @@ impl<'a> BitMapBackend<'a, RGBPixel> {
             size: (w, h),
             buffer: Buffer::Owned(vec![0; Self::PIXEL_SIZE * (w * h) as usize]),
             saved: false,
+            hit_index: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            hit_regions_file: None,
+            clipping: false,
+            clips: vec![],
+            anti_aliased: false,
             _phantomdata: PhantomData,
         })
     }
@@ -128,10 +152,116 @@ impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
             size: (w, h),
             buffer: Buffer::Borrowed(buf),
             saved: false,
+            hit_index: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            hit_regions_file: None,
+            clipping: false,
+            clips: vec![],
+            anti_aliased: false,
             _phantomdata: PhantomData,
         })
     }
 
+    /// Record the data elements drawn into a hit-test index
+    ///
+    /// The index is cleared, then filled with the data points and lines of the charts drawn on
+    /// this backend and their pixel areas. Once the drawing is done, the index finds the element
+    /// under a pixel, e.g. the mouse cursor, see [HitTestIndex::hit_test].
+    ///
+    /// - `index`: The index to record into
+    /// - **returns**: The backend recording into the index
+    pub fn with_hit_testing(mut self, index: &'a mut HitTestIndex) -> Self {
+        index.clear();
+        self.hit_index = Some(HitIndex::Borrowed(index));
+        self
+    }
+
+    /// Write the regions covered by the data elements to a file next to the image
+    ///
+    /// The file is written every time the backend is presented, either as an HTML image map
+    /// giving the image the hover labels of the SVG tooltips, or as JSON, see
+    /// [HitTestIndex::regions]. This can be combined with
+    /// [with_hit_testing](BitMapBackend::with_hit_testing), which should then be called first.
+    ///
+    /// - `path`: The path of the file to write
+    /// - `format`: The format of the file
+    /// - **returns**: The backend writing the file
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_hit_regions_file<T: AsRef<std::path::Path> + ?Sized>(
+        mut self,
+        path: &'a T,
+        format: HitRegionFormat,
+    ) -> Self {
+        if self.hit_index.is_none() {
+            self.hit_index = Some(HitIndex::Owned(Box::default()));
+        }
+        self.hit_regions_file = Some((path.as_ref(), format));
+        self
+    }
+
+    /// Draw the lines, the circles and the polygons anti-aliased
+    ///
+    /// The pixels on the edges of the shapes are blended by the fraction of the pixel the shape
+    /// covers, which looks smoother than the default hard edges, at the cost of a slower drawing.
+    ///
+    /// - **returns**: The backend drawing anti-aliased
+    pub fn with_anti_aliasing(mut self) -> Self {
+        self.anti_aliased = true;
+        self
+    }
+
+    /// Clip the data series to their plotting area
+    ///
+    /// The lines leaving the plotting area keep their slopes up to its edges and the shapes
+    /// crossing them are cut, rather than having their coordinates truncated to the plotting area.
+    ///
+    /// - **returns**: The backend clipping the data series
+    pub fn with_clipping(mut self) -> Self {
+        self.clipping = true;
+        self
+    }
+
+    /// The hit-test index, if hit-testing is enabled
+    #[inline(always)]
+    fn hit_index(&mut self) -> Option<&mut HitTestIndex> {
+        self.hit_index.as_mut().map(HitIndex::borrow_index)
+    }
+
+    /// Write the hit regions file, if requested
+    #[cfg(not(target_arch = "wasm32"))]
+    fn write_hit_regions(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        if let (Some((path, format)), Some(index)) = (&self.hit_regions_file, &mut self.hit_index) {
+            std::fs::write(path, index.borrow_index().write_regions(format))
+                .map_err(|e| DrawingErrorKind::DrawingError(BitMapBackendError::IOError(e)))?;
+        }
+        Ok(())
+    }
+
+    /// Expand the area of the data element being drawn, if hit-testing is enabled
+    #[inline(always)]
+    fn track(&mut self, a: BackendCoord, b: BackendCoord) {
+        if let Some(index) = self.hit_index() {
+            if index.is_tracking() {
+                index.track(a, b);
+            }
+        }
+    }
+
+    /// Intersect a rectangle, both corners included, with the clip region. Returns `None` if
+    /// nothing is left.
+    #[inline(always)]
+    fn clip_rect(&self, a: BackendCoord, b: BackendCoord) -> Option<(BackendCoord, BackendCoord)> {
+        let (mut lo, mut hi) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
+        if let Some((clip_lo, clip_hi)) = self.clips.last() {
+            lo = (lo.0.max(clip_lo.0), lo.1.max(clip_lo.1));
+            hi = (hi.0.min(clip_hi.0), hi.1.min(clip_hi.1));
+        }
+        if lo.0 > hi.0 || lo.1 > hi.1 {
+            return None;
+        }
+        Some((lo, hi))
+    }
+
     #[inline(always)]
     pub(crate) fn get_raw_pixel_buffer(&mut self) -> &mut [u8] {
         self.buffer.borrow_buffer()
@@ -144,6 +274,7 @@ impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
     /// - **returns**: The split backends that can be rendered in parallel
     pub fn split(&mut self, area_size: &[u32]) -> Vec<BitMapBackend<'_, P>> {
         let (w, h) = self.get_size();
+        let anti_aliased = self.anti_aliased;
         let buf = self.get_raw_pixel_buffer();
 
         let base_addr = &mut buf[0] as *mut u8;
@@ -167,7 +298,9 @@ impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
                         ((end - begin) * w) as usize * Self::PIXEL_SIZE,
                     )
                 };
-                Self::with_buffer_and_format(actual_buf, (w, end - begin)).unwrap()
+                let mut back = Self::with_buffer_and_format(actual_buf, (w, end - begin)).unwrap();
+                back.anti_aliased = anti_aliased;
+                back
             })
             .collect()
     }
@@ -185,13 +318,64 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         Ok(())
     }
 
+    fn is_context_aware(&self) -> bool {
+        self.hit_index.is_some()
+    }
+
+    fn begin_context(&mut self, ctx: ElementContext) {
+        if let Some(index) = self.hit_index() {
+            index.begin_context(&ctx);
+        }
+    }
+
+    fn end_context(&mut self) {
+        if let Some(index) = self.hit_index() {
+            index.end_context();
+        }
+    }
+
+    fn update_context(&mut self, ctx: ElementContext) {
+        if let Some(index) = self.hit_index() {
+            index.update_context(&ctx);
+        }
+    }
+
+    fn is_anti_aliased(&self) -> bool {
+        self.anti_aliased
+    }
+
+    fn is_clip_aware(&self) -> bool {
+        self.clipping
+    }
+
+    fn push_clip_rect(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        // An empty region is kept as an inverted rectangle, which clips everything
+        let clip = self
+            .clip_rect(upper_left, bottom_right)
+            .unwrap_or(((0, 0), (-1, -1)));
+        self.clips.push(clip);
+        Ok(())
+    }
+
+    fn pop_clip(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.clips.pop();
+        Ok(())
+    }
+
     #[cfg(any(target_arch = "wasm32", not(feature = "image")))]
     fn present(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        #[cfg(not(target_arch = "wasm32"))]
+        self.write_hit_regions()?;
         Ok(())
     }
 
     #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
     fn present(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        self.write_hit_regions()?;
         if !P::can_be_saved() {
             return Ok(());
         }
@@ -235,10 +419,16 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         {
             return Ok(());
         }
+        if let Some((lo, hi)) = self.clips.last() {
+            if point.0 < lo.0 || point.1 < lo.1 || point.0 > hi.0 || point.1 > hi.1 {
+                return Ok(());
+            }
+        }
 
         let alpha = color.alpha;
         let rgb = color.rgb;
 
+        self.track(point, point);
         P::draw_pixel(self, point, rgb, alpha);
 
         Ok(())
@@ -253,7 +443,15 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         let alpha = style.color().alpha;
         let (r, g, b) = style.color().rgb;
 
-        if (from.0 == to.0 || from.1 == to.1) && style.stroke_width() == 1 {
+        if (from.0 == to.0 || from.1 == to.1)
+            && style.stroke_width() == 1
+            && style.dash_array().is_empty()
+        {
+            let (from, to) = match self.clip_rect(from, to) {
+                Some(visible) => visible,
+                None => return Ok(()),
+            };
+            self.track(from, to);
             if alpha >= 1.0 {
                 if from.1 == to.1 {
                     P::fill_rect_fast(self, from, (to.0 + 1, to.1 + 1), r, g, b);
@@ -278,7 +476,25 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
         let alpha = style.color().alpha;
         let (r, g, b) = style.color().rgb;
-        if fill {
+        if fill && style.paint().is_solid() {
+            // The bottom right corner is excluded from the fill
+            let (upper_left, bottom_right) = match self.clips.last() {
+                Some((lo, hi)) => (
+                    (
+                        upper_left.0.min(bottom_right.0).max(lo.0),
+                        upper_left.1.min(bottom_right.1).max(lo.1),
+                    ),
+                    (
+                        upper_left.0.max(bottom_right.0).min(hi.0 + 1),
+                        upper_left.1.max(bottom_right.1).min(hi.1 + 1),
+                    ),
+                ),
+                None => (upper_left, bottom_right),
+            };
+            if upper_left.0 >= bottom_right.0 || upper_left.1 >= bottom_right.1 {
+                return Ok(());
+            }
+            self.track(upper_left, bottom_right);
             if alpha >= 1.0 {
                 P::fill_rect_fast(self, upper_left, bottom_right, r, g, b);
             } else {
@@ -289,6 +505,34 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         plotters_backend::rasterizer::draw_rect(self, upper_left, bottom_right, style, fill)
     }
 
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.color().alpha == 0.0 {
+            return Ok(());
+        }
+        let path: Vec<_> = path.into_iter().collect();
+        if let Some(index) = self.hit_index() {
+            index.track_shape(&path);
+        }
+
+        plotters_backend::rasterizer::draw_path(self, &path[..], style)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let vert: Vec<_> = vert.into_iter().collect();
+        if let Some(index) = self.hit_index() {
+            index.track_shape(&vert);
+        }
+        plotters_backend::rasterizer::fill_polygon(self, &vert[..], style)
+    }
+
     fn blit_bitmap(
         &mut self,
         pos: BackendCoord,
@@ -301,10 +545,20 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         let (x1, y1) = (x0 + sw as i32, y0 + sh as i32);
 
         let (x0, y0, x1, y1) = (x0.max(0), y0.max(0), x1.min(dw as i32), y1.min(dh as i32));
-
-        if x0 == x1 || y0 == y1 {
+        let (x0, y0, x1, y1) = match self.clips.last() {
+            Some((lo, hi)) => (
+                x0.max(lo.0),
+                y0.max(lo.1),
+                x1.min(hi.0 + 1),
+                y1.min(hi.1 + 1),
+            ),
+            None => (x0, y0, x1, y1),
+        };
+
+        if x0 >= x1 || y0 >= y1 {
             return Ok(());
         }
+        self.track((x0, y0), (x1 - 1, y1 - 1));
 
         let mut chunk_size = (x1 - x0) as usize;
         let mut num_chunks = (y1 - y0) as usize;
@@ -315,8 +569,7 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
 
         let mut dst = &mut self.get_raw_pixel_buffer()[dst_start..];
 
-        let src_start =
-            Self::PIXEL_SIZE * ((sh as i32 + y0 - y1) * sw as i32 + (sw as i32 + x0 - x1)) as usize;
+        let src_start = Self::PIXEL_SIZE * ((y0 - pos.1) * sw as i32 + (x0 - pos.0)) as usize;
         let mut src = &src[src_start..];
 
         if src_gap == 0 && dst_gap == 0 {
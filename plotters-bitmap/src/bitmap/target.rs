@@ -1,5 +1,6 @@
 #[cfg(all(feature = "gif", not(target_arch = "wasm32"), feature = "image"))]
 use crate::gif_support;
+use crate::hit_test::HitTestIndex;
 use std::marker::PhantomData;
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
 use std::path::Path;
@@ -28,3 +29,18 @@ impl<'a> Buffer<'a> {
         }
     }
 }
+
+pub(super) enum HitIndex<'a> {
+    Owned(Box<HitTestIndex>),
+    Borrowed(&'a mut HitTestIndex),
+}
+
+impl<'a> HitIndex<'a> {
+    #[inline(always)]
+    pub(super) fn borrow_index(&mut self) -> &mut HitTestIndex {
+        match self {
+            HitIndex::Owned(index) => index,
+            HitIndex::Borrowed(index) => index,
+        }
+    }
+}
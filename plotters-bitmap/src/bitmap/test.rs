@@ -347,6 +347,169 @@ fn test_draw_simple_lines() {
     assert_eq!(nz_count, 6 * 1000 * 3);
 }
 
+#[test]
+fn test_draw_dashed_lines() {
+    use plotters::prelude::*;
+    let mut buffer = vec![0; 20 * 20 * 3];
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut buffer, (20, 20));
+        let style = ShapeStyle::from(WHITE).dash(&[4.0, 2.0]);
+        back.draw_line((0, 5), (19, 5), &style).unwrap();
+        let style = ShapeStyle::from(WHITE)
+            .stroke_width(3)
+            .dash(&[4.0, 2.0])
+            .dash_offset(4.0);
+        back.draw_line((0, 15), (19, 15), &style).unwrap();
+    }
+
+    let lit = |x: usize, y: usize| buffer[(y * 20 + x) * 3] != 0;
+    assert!(lit(2, 5) && lit(8, 5) && lit(14, 5));
+    assert!(!lit(5, 5) && !lit(11, 5) && !lit(17, 5));
+    // The offset shifts the pattern to start with the gap
+    assert!(!lit(1, 15) && lit(4, 15) && lit(4, 14) && !lit(7, 15));
+}
+
+#[test]
+fn test_draw_paints() {
+    use plotters::prelude::*;
+    let mut buffer = vec![0; 20 * 20 * 3];
+
+    {
+        let root = BitMapBackend::with_buffer(&mut buffer, (20, 20)).into_drawing_area();
+        let fade =
+            WHITE
+                .filled()
+                .linear_gradient((0.0, 0.0), (1.0, 0.0), &[(0.0, BLACK), (1.0, WHITE)]);
+        root.draw(&Rectangle::new([(0, 0), (10, 9)], fade)).unwrap();
+        let hatch = WHITE.filled().hatch(0.0, 4.0, 1.0);
+        root.draw(&Polygon::new(
+            vec![(0, 10), (19, 10), (19, 19), (0, 19)],
+            hatch,
+        ))
+        .unwrap();
+    }
+
+    let red = |x: usize, y: usize| buffer[(y * 20 + x) * 3];
+    assert_eq!((red(0, 5), red(5, 5), red(10, 5)), (0, 128, 255));
+    assert_eq!((red(5, 12), red(5, 13), red(5, 16)), (255, 0, 255));
+}
+
+#[test]
+fn test_draw_anti_aliased() {
+    use plotters::prelude::*;
+    let mut buffer = vec![0; 20 * 20 * 3];
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut buffer, (20, 20)).with_anti_aliasing();
+        back.draw_circle((5, 5), 3, &WHITE, true).unwrap();
+        back.fill_polygon(vec![(10, 10), (18, 10), (18, 18), (10, 18)], &WHITE)
+            .unwrap();
+        back.draw_line((0, 19), (19, 19), &WHITE).unwrap();
+        back.draw_line((10, 1), (18, 6), &WHITE).unwrap();
+    }
+
+    let red = |x: usize, y: usize| buffer[(y * 20 + x) * 3];
+    // The edges are blended, through the centers of the pixels
+    assert_eq!((red(5, 5), red(8, 5), red(5, 9)), (255, 127, 0));
+    assert_eq!((red(14, 14), red(10, 14), red(10, 10)), (255, 127, 63));
+    // The horizontal lines stay sharp, the slanted ones don't
+    assert!((0..20).all(|x| red(x, 19) == 255));
+    let line: Vec<_> = (0..20)
+        .flat_map(|x| (0..9).map(move |y| (x, y)))
+        .map(|(x, y)| red(x, y))
+        .filter(|v| *v > 0)
+        .collect();
+    assert!(line.iter().any(|v| *v < 255));
+}
+
+#[test]
+fn test_clip_rect() {
+    use plotters::prelude::*;
+    let mut buffer = vec![0; 20 * 20 * 3];
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut buffer, (20, 20));
+        back.push_clip_rect((5, 5), (14, 14)).unwrap();
+        // The nested region is the intersection, thus nothing is drawn in it
+        back.push_clip_rect((0, 0), (4, 4)).unwrap();
+        back.draw_rect((0, 0), (19, 19), &WHITE, true).unwrap();
+        back.pop_clip().unwrap();
+        back.draw_line((0, 10), (19, 10), &WHITE).unwrap();
+        back.draw_rect((12, 0), (19, 19), &WHITE, true).unwrap();
+        back.draw_circle((5, 5), 3, &WHITE, true).unwrap();
+        back.pop_clip().unwrap();
+        back.draw_pixel((0, 0), WHITE.to_backend_color()).unwrap();
+    }
+
+    let lit = |x: usize, y: usize| buffer[(y * 20 + x) * 3] != 0;
+    assert!(lit(5, 10) && lit(14, 10) && !lit(4, 10) && !lit(15, 10));
+    assert!(lit(12, 5) && lit(14, 14) && !lit(12, 4) && !lit(15, 14));
+    assert!(lit(6, 6) && !lit(4, 4) && !lit(5, 2));
+    assert!(lit(0, 0) && !lit(1, 1));
+}
+
+#[test]
+fn test_chart_clips_series() {
+    use plotters::prelude::*;
+    let mut buffer = vec![0; 40 * 40 * 3];
+
+    {
+        let root = BitMapBackend::with_buffer(&mut buffer, (40, 40))
+            .with_clipping()
+            .into_drawing_area();
+        let area = root.margin(10, 10, 10, 10);
+        let mut chart = ChartBuilder::on(&area)
+            .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+            .unwrap();
+        chart
+            .draw_series(LineSeries::new(vec![(-1.0, 0.5), (2.0, 0.5)], &WHITE))
+            .unwrap();
+        chart
+            .draw_series(std::iter::once(Rectangle::new(
+                [(0.5, -1.0), (2.0, 2.0)],
+                WHITE.filled(),
+            )))
+            .unwrap();
+    }
+
+    // Everything stops at the edge of the plotting area, which covers the pixels 10 to 29
+    let lit = |x: usize, y: usize| buffer[(y * 40 + x) * 3] != 0;
+    assert!(lit(10, 20) && lit(29, 20) && !lit(9, 20) && !lit(30, 20));
+    assert!(lit(25, 10) && lit(25, 29) && !lit(25, 9) && !lit(25, 30));
+}
+
+#[test]
+fn test_chart_clips_far_points() {
+    use plotters::prelude::*;
+    let mut buffer = vec![0; 40 * 40 * 3];
+
+    {
+        let root = BitMapBackend::with_buffer(&mut buffer, (40, 40))
+            .with_clipping()
+            .into_drawing_area();
+        let area = root.margin(10, 10, 10, 10);
+        let mut chart = ChartBuilder::on(&area)
+            .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+            .unwrap();
+        chart
+            .draw_series(LineSeries::new(vec![(0.0, 0.0), (4.0, 1.0)], &WHITE))
+            .unwrap();
+        // The dashes far away from the bitmap aren't rasterized
+        chart
+            .draw_series(LineSeries::new(
+                vec![(0.0, 0.5), (1e6, 0.5)],
+                ShapeStyle::from(WHITE).stroke_width(3).dash(&[2.0, 2.0]),
+            ))
+            .unwrap();
+    }
+
+    // The line keeps its slope up to the edge of the plotting area, where it reaches y = 24.25
+    let lit = |x: usize, y: usize| buffer[(y * 40 + x) * 3] != 0;
+    assert!(lit(29, 24) && !lit(29, 20) && !lit(30, 24));
+    assert!(lit(10, 19) && !lit(13, 19) && lit(28, 19) && !lit(30, 19));
+}
+
 #[cfg(test)]
 #[test]
 fn test_bitmap_blit() {
@@ -387,6 +550,178 @@ fn test_bitmap_blit() {
     }
 }
 
+#[test]
+fn test_bitmap_rgba_pixel_format() {
+    use crate::{bitmap_pixel::RGBAPixel, BitMapBackend};
+    use plotters::prelude::*;
+
+    let mut buffer = vec![0; 4 * 4 * 4];
+    {
+        let mut back =
+            BitMapBackend::<RGBAPixel>::with_buffer_and_format(&mut buffer, (4, 4)).unwrap();
+        back.draw_rect((0, 0), (3, 1), &BLUE, true).unwrap();
+        back.draw_pixel((1, 1), RED.mix(0.5).to_backend_color())
+            .unwrap();
+        back.draw_pixel((1, 2), RED.mix(0.5).to_backend_color())
+            .unwrap();
+    }
+
+    // Over an opaque pixel the colors are mixed, over a transparent one the color is kept
+    assert_eq!(&buffer[4..8], &[0, 0, 255, 255]);
+    assert_eq!(&buffer[20..24], &[128, 0, 128, 255]);
+    assert_eq!(&buffer[36..40], &[255, 0, 0, 128]);
+    assert_eq!(&buffer[60..64], &[0, 0, 0, 0]);
+
+    // Blitting the overlay keeps the background where it's transparent
+    let mut target = vec![255; 4 * 4 * 3];
+    {
+        let mut back = BitMapBackend::with_buffer(&mut target, (4, 4));
+        back.blit_bitmap_rgba((0, 0), (4, 4), &buffer).unwrap();
+    }
+    assert_eq!(&target[3..6], &[0, 0, 255]);
+    assert_eq!(&target[27..30], &[255, 128, 128]);
+    assert_eq!(&target[45..48], &[255, 255, 255]);
+}
+
+#[test]
+fn test_hit_testing() {
+    use crate::{BitMapBackend, HitTestIndex};
+    use plotters::prelude::*;
+
+    let mut buffer = vec![0; 201 * 201 * 3];
+    let mut index = HitTestIndex::new();
+    {
+        let root = BitMapBackend::with_buffer(&mut buffer, (201, 201))
+            .with_hit_testing(&mut index)
+            .into_drawing_area();
+        let mut chart = ChartBuilder::on(&root)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .unwrap();
+        chart.configure_mesh().draw().unwrap();
+        chart
+            .draw_series(LineSeries::new(vec![(0.0, 0.0), (10.0, 10.0)], &RED))
+            .unwrap()
+            .label("Line");
+        chart
+            .draw_series(std::iter::once(Circle::new((8.0, 2.0), 5, BLUE.filled())))
+            .unwrap()
+            .label("Point");
+    }
+
+    // On the drawn area of the point
+    let hit = index.hit_test((163, 163)).unwrap();
+    assert_eq!(hit.series_id, 1);
+    assert_eq!(hit.label, "Point");
+    assert_eq!((hit.x.as_str(), hit.y.as_str()), ("8.0", "2.0"));
+    assert_eq!(hit.distance, 0.0);
+
+    // Next to the line, the value is interpolated along it
+    let hit = index.hit_test((96, 96)).unwrap();
+    assert_eq!(hit.label, "Line");
+    assert_eq!(hit.coord, (100, 100));
+    assert_eq!((hit.x.as_str(), hit.y.as_str()), ("5.00", "5.00"));
+    assert!((hit.distance - 32f64.sqrt()).abs() < 1e-9);
+
+    assert!(index.hit_test_within((96, 96), 5.0).is_none());
+}
+
+#[test]
+fn test_hit_testing_bars() {
+    use crate::{BitMapBackend, HitShape, HitTestIndex};
+    use plotters::prelude::*;
+
+    let mut buffer = vec![0; 201 * 201 * 3];
+    let mut index = HitTestIndex::new();
+    {
+        let root = BitMapBackend::with_buffer(&mut buffer, (201, 201))
+            .with_hit_testing(&mut index)
+            .into_drawing_area();
+        let mut chart = ChartBuilder::on(&root)
+            .build_cartesian_2d(0..10i32, 0..10i32)
+            .unwrap();
+        chart
+            .draw_series_with_tooltips(
+                (1..4).map(|x| Rectangle::new([(x * 2, 0), (x * 2 + 1, x * 2)], BLUE.filled())),
+                &BLUE,
+                "Bars",
+            )
+            .unwrap();
+    }
+
+    // Inside the second bar, which spans x = 80..100 and y = 120..200
+    let hit = index.hit_test((90, 160)).unwrap();
+    assert_eq!(hit.label, "Bars");
+    assert_eq!(hit.distance, 0.0);
+    assert_eq!(hit.coord, (90, 160));
+    assert_eq!((hit.x.as_str(), hit.y.as_str()), ("4", "0"));
+    let hit = index.hit_test((98, 125)).unwrap();
+    assert_eq!((hit.x.as_str(), hit.y.as_str()), ("5", "4"));
+
+    // Next to a bar, the distance is measured to its drawn area
+    let hit = index.hit_test((110, 160)).unwrap();
+    assert_eq!(hit.coord.1, 160);
+    assert!(hit.distance > 0.0 && hit.distance <= 10.0);
+
+    assert_eq!(
+        index
+            .regions()
+            .iter()
+            .filter(|r| matches!(r.shape, HitShape::Rect(_)))
+            .count(),
+        3
+    );
+}
+
+#[test]
+fn test_hit_regions_file() {
+    use crate::{BitMapBackend, HitRegionFormat, HitTestIndex};
+    use plotters::prelude::*;
+
+    let dir = std::path::Path::new("target/test/bitmap");
+    std::fs::create_dir_all(dir).unwrap();
+    let map_path = dir.join("test_hit_regions.html");
+
+    let mut buffer = vec![0; 201 * 201 * 3];
+    let mut index = HitTestIndex::new();
+    {
+        let root = BitMapBackend::with_buffer(&mut buffer, (201, 201))
+            .with_hit_testing(&mut index)
+            .with_hit_regions_file(&map_path, HitRegionFormat::HtmlMap("chart".to_string()))
+            .into_drawing_area();
+        let mut chart = ChartBuilder::on(&root)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .unwrap();
+        chart.configure_mesh().draw().unwrap();
+        chart
+            .draw_series(LineSeries::new(vec![(0.0, 0.0), (10.0, 10.0)], &RED))
+            .unwrap()
+            .label("A & B");
+        chart
+            .draw_series(std::iter::once(Circle::new((8.0, 2.0), 5, BLUE.filled())))
+            .unwrap()
+            .label("Point");
+        root.present().unwrap();
+    }
+
+    let map = std::fs::read_to_string(&map_path).unwrap();
+    assert!(map.starts_with("<map name=\"chart\">\n"));
+    assert!(map.contains(
+        r#"<area shape="rect" coords="155,155,165,165" title="Point&#10;x: 8.0&#10;y: 2.0""#
+    ));
+    assert!(map.contains(
+        r#"<area shape="circle" coords="0,200,5" title="A &amp; B&#10;x: 0.0&#10;y: 0.0""#
+    ));
+    // The band of the line comes after the more specific regions
+    let band = map.find(r#"<area shape="poly""#).unwrap();
+    assert!(band > map.find(r#"shape="rect""#).unwrap());
+    assert!(map[band..].contains(r#"title="A &amp; B""#));
+
+    let json = index.to_json();
+    assert!(json.starts_with(
+        r#"[{"series_id":0,"label":"A & B","title":"A & B\nx: 0.0\ny: 0.0","shape":"circle","coords":[0,200,5]}"#
+    ));
+}
+
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
 #[cfg(test)]
 mod test {
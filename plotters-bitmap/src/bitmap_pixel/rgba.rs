@@ -0,0 +1,113 @@
+use super::PixelFormat;
+use crate::BitMapBackend;
+use plotters_backend::DrawingBackend;
+
+/// The marker type that indicates we are currently using a RGBA8888 pixel format, with a
+/// straight (not premultiplied) alpha channel.
+///
+/// This is meant for transparent overlays which are blitted on to other backends, the shapes
+/// drawn on it are composited over the existing pixels, including their alpha.
+pub struct RGBAPixel;
+
+impl PixelFormat for RGBAPixel {
+    const PIXEL_SIZE: usize = 4;
+    const EFFECTIVE_PIXEL_SIZE: usize = 4;
+
+    #[inline(always)]
+    fn byte_at(r: u8, g: u8, b: u8, _a: u64, idx: usize) -> u8 {
+        match idx {
+            0 => r,
+            1 => g,
+            2 => b,
+            _ => 0xff,
+        }
+    }
+
+    #[inline(always)]
+    fn decode_pixel(data: &[u8]) -> (u8, u8, u8, u64) {
+        (data[0], data[1], data[2], u64::from(data[3]))
+    }
+
+    fn blend_rect_fast(
+        target: &mut BitMapBackend<'_, Self>,
+        upper_left: (i32, i32),
+        bottom_right: (i32, i32),
+        r: u8,
+        g: u8,
+        b: u8,
+        a: f64,
+    ) {
+        let (w, h) = target.get_size();
+        let (x0, y0) = (
+            upper_left.0.min(bottom_right.0).max(0),
+            upper_left.1.min(bottom_right.1).max(0),
+        );
+        let (x1, y1) = (
+            upper_left.0.max(bottom_right.0).min(w as i32),
+            upper_left.1.max(bottom_right.1).min(h as i32),
+        );
+        for y in y0..y1 {
+            for x in x0..x1 {
+                Self::draw_pixel(target, (x, y), (r, g, b), a);
+            }
+        }
+    }
+
+    fn fill_rect_fast(
+        target: &mut BitMapBackend<'_, Self>,
+        upper_left: (i32, i32),
+        bottom_right: (i32, i32),
+        r: u8,
+        g: u8,
+        b: u8,
+    ) {
+        let (w, h) = target.get_size();
+        let (x0, y0) = (
+            upper_left.0.min(bottom_right.0).max(0),
+            upper_left.1.min(bottom_right.1).max(0),
+        );
+        let (x1, y1) = (
+            upper_left.0.max(bottom_right.0).min(w as i32 - 1),
+            upper_left.1.max(bottom_right.1).min(h as i32 - 1),
+        );
+        if x0 > x1 || y0 > y1 {
+            return;
+        }
+        let dst = target.get_raw_pixel_buffer();
+        for y in y0..=y1 {
+            let start = (y as usize * w as usize + x0 as usize) * Self::PIXEL_SIZE;
+            let end = (y as usize * w as usize + x1 as usize + 1) * Self::PIXEL_SIZE;
+            for pixel in dst[start..end].chunks_exact_mut(Self::PIXEL_SIZE) {
+                pixel.copy_from_slice(&[r, g, b, 0xff]);
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn draw_pixel(
+        target: &mut BitMapBackend<'_, Self>,
+        point: (i32, i32),
+        (r, g, b): (u8, u8, u8),
+        alpha: f64,
+    ) {
+        let (w, h) = target.get_size();
+        if point.0 < 0 || point.1 < 0 || point.0 >= w as i32 || point.1 >= h as i32 {
+            return;
+        }
+        let alpha = alpha.clamp(0.0, 1.0);
+        if alpha == 0.0 {
+            return;
+        }
+        let base = (point.1 as usize * w as usize + point.0 as usize) * Self::PIXEL_SIZE;
+        let pixel = &mut target.get_raw_pixel_buffer()[base..base + Self::PIXEL_SIZE];
+
+        // The source-over operator on straight alpha
+        let dst_alpha = f64::from(pixel[3]) / 255.0 * (1.0 - alpha);
+        let out_alpha = alpha + dst_alpha;
+        for (channel, src) in pixel.iter_mut().zip([r, g, b]) {
+            let value = (f64::from(src) * alpha + f64::from(*channel) * dst_alpha) / out_alpha;
+            *channel = value.round() as u8;
+        }
+        pixel[3] = (out_alpha * 255.0).round() as u8;
+    }
+}
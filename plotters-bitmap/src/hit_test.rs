@@ -0,0 +1,607 @@
+use plotters_backend::{rasterizer::polygonize, BackendCoord, ElementContext, Interpolation};
+use std::fmt::Write;
+
+/// A rectangle in backend pixels, given by its upper-left and bottom-right corners
+pub type PixelBounds = (BackendCoord, BackendCoord);
+
+/// The data element found by [HitTestIndex::hit_test]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hit {
+    /// The id of the series the element belongs to
+    pub series_id: usize,
+    /// The label of the series, empty if it has none
+    pub label: String,
+    /// The formatted x value at the hit location
+    pub x: String,
+    /// The formatted y value at the hit location
+    pub y: String,
+    /// The formatted z value, only present for 3D charts
+    pub z: Option<String>,
+    /// The location on the element closest to the queried pixel: the nominal coordinate of a data
+    /// point, the closest point of a line or the closest pixel of an area such as a bar
+    pub coord: BackendCoord,
+    /// The distance in pixels between the queried pixel and the element, zero when the pixel is
+    /// on the drawn area of a data point
+    pub distance: f64,
+}
+
+#[derive(Clone, Debug)]
+enum Shape {
+    Point {
+        coord: BackendCoord,
+        x: String,
+        y: String,
+        z: Option<String>,
+    },
+    Line {
+        vertices: Vec<BackendCoord>,
+        /// The formatted x, y and z values of every vertex, for lines of discrete values
+        labels: Option<Vec<(String, String, Option<String>)>>,
+        /// The mapping of the pixels to the values, for the values between the vertices
+        x: Interpolation,
+        y: Interpolation,
+        /// Whether the element is hit anywhere on its drawn area rather than along its vertices,
+        /// e.g. bars and boxplots
+        area: bool,
+    },
+}
+
+/// The shape of a [HitRegion], in backend pixels
+#[derive(Clone, Debug, PartialEq)]
+pub enum HitShape {
+    /// A rectangle given by its upper-left and bottom-right corners
+    Rect(PixelBounds),
+    /// A circle given by its center and radius
+    Circle(BackendCoord, u32),
+    /// A polygon given by its vertices
+    Polygon(Vec<BackendCoord>),
+}
+
+/// A region of the image covering a data element, with the text describing it
+#[derive(Clone, Debug, PartialEq)]
+pub struct HitRegion {
+    /// The id of the series the element belongs to
+    pub series_id: usize,
+    /// The label of the series, empty if it has none
+    pub label: String,
+    /// The text describing the element: the series label and the values, one per line
+    pub title: String,
+    /// The area of the element
+    pub shape: HitShape,
+}
+
+/// The format of the hit regions written next to a bitmap, see
+/// [with_hit_regions_file](crate::BitMapBackend::with_hit_regions_file)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HitRegionFormat {
+    /// An HTML `<map>` with the given name, whose `<area>` elements carry the description of the
+    /// elements as `title`, for use with `<img usemap="#name">`
+    HtmlMap(String),
+    /// A JSON array of regions, see [HitTestIndex::to_json]
+    Json,
+}
+
+/// The radius of the regions of the vertices of a line
+const VERTEX_RADIUS: u32 = 5;
+/// The width of the band around a line covered by its region
+const LINE_BAND_WIDTH: u32 = 9;
+/// How far the drawn area of a line may extend beyond its vertices before it is considered as an
+/// area, like the box of a boxplot
+const AREA_THRESHOLD: i32 = LINE_BAND_WIDTH as i32;
+
+/// A data element recorded while drawing
+#[derive(Clone, Debug)]
+struct Element {
+    /// The index of the series in `HitTestIndex::series`
+    series: usize,
+    shape: Shape,
+    /// The area covered by the pixels drawn for the element
+    bounds: Option<PixelBounds>,
+}
+
+/// A retained index of the data elements drawn on a bitmap, for finding the element under a
+/// pixel
+///
+/// The index is built from the data point and data line contexts reported by the chart while a
+/// [BitMapBackend](crate::BitMapBackend) draws, see
+/// [with_hit_testing](crate::BitMapBackend::with_hit_testing). It gives native applications the
+/// same hover information the tooltips of the SVG backend show.
+#[derive(Clone, Debug, Default)]
+pub struct HitTestIndex {
+    /// The id and the label of every series
+    series: Vec<(usize, String)>,
+    elements: Vec<Element>,
+    /// One entry per open context, `None` for the contexts which aren't data elements
+    open: Vec<Option<OpenElement>>,
+}
+
+#[derive(Clone, Debug)]
+struct OpenElement {
+    ctx: ElementContext,
+    series: usize,
+    bounds: Option<PixelBounds>,
+    /// The vertices of the first path or polygon drawn
+    shape: Vec<BackendCoord>,
+}
+
+impl HitTestIndex {
+    /// Create an empty index
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remove all the recorded elements
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Check if no data element has been recorded
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Find the data element nearest to a pixel
+    ///
+    /// - `coord`: The pixel to query
+    /// - **returns**: The nearest element, or `None` if the index is empty
+    pub fn hit_test(&self, coord: BackendCoord) -> Option<Hit> {
+        self.hit_test_within(coord, f64::INFINITY)
+    }
+
+    /// Find the data element nearest to a pixel, ignoring the elements which are too far away
+    ///
+    /// - `coord`: The pixel to query
+    /// - `max_distance`: The largest distance in pixels of the element to the pixel
+    /// - **returns**: The nearest element, or `None` if there's no element close enough
+    pub fn hit_test_within(&self, coord: BackendCoord, max_distance: f64) -> Option<Hit> {
+        let mut best: Option<(f64, f64, &Element, BackendCoord)> = None;
+        for element in self.elements.iter() {
+            let Some((distance, tie_break, location)) = element.distance(coord) else {
+                continue;
+            };
+            if distance > max_distance {
+                continue;
+            }
+            let better = match best {
+                Some((d, t, ..)) => (distance, tie_break) < (d, t),
+                None => true,
+            };
+            if better {
+                best = Some((distance, tie_break, element, location));
+            }
+        }
+        let (distance, _, element, location) = best?;
+        let (series_id, label) = self.series[element.series].clone();
+        let (x, y, z) = match &element.shape {
+            Shape::Point { x, y, z, .. } => (x.clone(), y.clone(), z.clone()),
+            Shape::Line {
+                vertices,
+                labels,
+                x,
+                y,
+                area,
+            } => {
+                let nearest = (0..vertices.len())
+                    .min_by_key(|idx| squared_distance(vertices[*idx], location));
+                // Areas and vertices show the labels formatted by the axes, the locations between
+                // the vertices of a continuous line are evaluated
+                let continuous = matches!(x, Interpolation::Continuous { .. })
+                    && !*area
+                    && !matches!(nearest, Some(idx) if vertices[idx] == location);
+                match (labels, nearest) {
+                    (Some(labels), Some(nearest)) if !continuous => labels[nearest].clone(),
+                    _ => (
+                        x.label_at(location.0).unwrap_or_default(),
+                        y.label_at(location.1).unwrap_or_default(),
+                        None,
+                    ),
+                }
+            }
+        };
+        Some(Hit {
+            series_id,
+            label,
+            x,
+            y,
+            z,
+            coord: location,
+            distance,
+        })
+    }
+
+    /// The regions of the image covering the data elements, for image maps and the like
+    ///
+    /// Data points are covered by their drawn area, the vertices of lines by small circles and
+    /// lines by a band along them, or by their drawn area for bars and boxplots. The regions of the points and vertices come first, since they
+    /// are more specific than the bands of the lines they may overlap.
+    pub fn regions(&self) -> Vec<HitRegion> {
+        let mut regions = vec![];
+        let mut bands = vec![];
+        for element in self.elements.iter() {
+            let (series_id, label) = &self.series[element.series];
+            let region = |title: String, shape: HitShape| HitRegion {
+                series_id: *series_id,
+                label: label.clone(),
+                title,
+                shape,
+            };
+            match &element.shape {
+                Shape::Point { coord, x, y, z } => {
+                    let shape = match element.bounds {
+                        Some(bounds) => HitShape::Rect(bounds),
+                        None => HitShape::Circle(*coord, VERTEX_RADIUS),
+                    };
+                    regions.push(region(describe(label, x, y, z.as_deref()), shape));
+                }
+                Shape::Line {
+                    vertices,
+                    labels,
+                    x,
+                    y,
+                    area,
+                } => {
+                    for (idx, vertex) in vertices.iter().enumerate() {
+                        let title = match labels {
+                            Some(labels) => {
+                                let (xl, yl, zl) = &labels[idx];
+                                describe(label, xl, yl, zl.as_deref())
+                            }
+                            None => describe(
+                                label,
+                                &x.label_at(vertex.0).unwrap_or_default(),
+                                &y.label_at(vertex.1).unwrap_or_default(),
+                                None,
+                            ),
+                        };
+                        regions.push(region(title, HitShape::Circle(*vertex, VERTEX_RADIUS)));
+                    }
+                    let title = if label.is_empty() {
+                        format!("Series {}", series_id + 1)
+                    } else {
+                        label.clone()
+                    };
+                    match element.bounds {
+                        Some(bounds) if *area => bands.push(region(title, HitShape::Rect(bounds))),
+                        _ if vertices.len() >= 2 => {
+                            let band = polygonize(vertices, LINE_BAND_WIDTH);
+                            bands.push(region(title, HitShape::Polygon(band)));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        regions.extend(bands);
+        regions
+    }
+
+    /// Write the regions as an HTML `<map>`, see [regions](HitTestIndex::regions)
+    ///
+    /// - `name`: The name of the map, which the image refers to with `usemap="#name"`
+    pub fn to_html_map(&self, name: &str) -> String {
+        let mut html = format!("<map name=\"{}\">\n", escape_html(name));
+        for region in self.regions() {
+            let (shape, coords) = region_coords(&region.shape);
+            let coords: Vec<_> = coords.iter().map(|c| c.to_string()).collect();
+            let title = escape_html(&region.title);
+            let _ = writeln!(
+                html,
+                "<area shape=\"{}\" coords=\"{}\" title=\"{}\" alt=\"{}\">",
+                shape,
+                coords.join(","),
+                title,
+                title
+            );
+        }
+        html.push_str("</map>\n");
+        html
+    }
+
+    /// Write the regions as a JSON array, see [regions](HitTestIndex::regions)
+    ///
+    /// Each region is an object with the fields `series_id`, `label`, `title`, `shape` (`"rect"`,
+    /// `"circle"` or `"poly"`) and `coords`, which are listed like in an HTML `<area>`.
+    pub fn to_json(&self) -> String {
+        let regions: Vec<_> = self
+            .regions()
+            .iter()
+            .map(|region| {
+                let (shape, coords) = region_coords(&region.shape);
+                let coords: Vec<_> = coords.iter().map(|c| c.to_string()).collect();
+                format!(
+                    "{{\"series_id\":{},\"label\":\"{}\",\"title\":\"{}\",\"shape\":\"{}\",\"coords\":[{}]}}",
+                    region.series_id,
+                    escape_json(&region.label),
+                    escape_json(&region.title),
+                    shape,
+                    coords.join(",")
+                )
+            })
+            .collect();
+        format!("[{}]\n", regions.join(",\n"))
+    }
+
+    /// Write the regions in the given format
+    pub fn write_regions(&self, format: &HitRegionFormat) -> String {
+        match format {
+            HitRegionFormat::HtmlMap(name) => self.to_html_map(name),
+            HitRegionFormat::Json => self.to_json(),
+        }
+    }
+
+    pub(crate) fn begin_context(&mut self, ctx: &ElementContext) {
+        // Only data points and lines are tracked
+        let open = match ctx {
+            ElementContext::DataSeries { id, label, .. } => {
+                self.series.push((*id, label.clone()));
+                None
+            }
+            ElementContext::DataPoint { series_id, .. }
+            | ElementContext::DataLine { series_id, .. } => Some(OpenElement {
+                ctx: ctx.clone(),
+                series: self.series_index(*series_id),
+                bounds: None,
+                shape: vec![],
+            }),
+            _ => None,
+        };
+        self.open.push(open);
+    }
+
+    pub(crate) fn end_context(&mut self) {
+        let Some(Some(open)) = self.open.pop() else {
+            return;
+        };
+        let shape = match open.ctx {
+            ElementContext::DataPoint {
+                coord,
+                x_label,
+                y_label,
+                z_label,
+                ..
+            } => Shape::Point {
+                coord,
+                x: x_label,
+                y: y_label,
+                z: z_label,
+            },
+            ElementContext::DataLine {
+                x_interpolation,
+                y_interpolation,
+                z_interpolation,
+                continuous_interpolation,
+                ..
+            } => {
+                let (vertices, labels) = match (&x_interpolation, &y_interpolation) {
+                    (
+                        Interpolation::Discrete { points: xpts },
+                        Interpolation::Discrete { points: ypts },
+                    ) => {
+                        // The z points are listed in the same order as the y ones
+                        let zpts = match &z_interpolation {
+                            Some(Interpolation::Discrete { points }) => &points[..],
+                            _ => &[],
+                        };
+                        let vertices = xpts.iter().zip(ypts.iter());
+                        let labels = vertices
+                            .clone()
+                            .enumerate()
+                            .map(|(idx, ((_, xl), (_, yl)))| {
+                                let zl = zpts.get(idx).map(|(_, zl)| zl.clone());
+                                (xl.clone(), yl.clone(), zl)
+                            })
+                            .collect();
+                        let vertices = vertices.map(|((x, _), (y, _))| (*x, *y)).collect();
+                        (vertices, Some(labels))
+                    }
+                    _ => (open.shape.clone(), None),
+                };
+                // Elements drawn without a path, like bars, or covering much more than their
+                // vertices, like boxplots, are hit anywhere on their drawn area
+                let area = match open.bounds {
+                    Some(bounds) => open.shape.is_empty() || extends_beyond(bounds, &vertices),
+                    None => false,
+                };
+                let (x, y) = continuous_interpolation.unwrap_or((x_interpolation, y_interpolation));
+                Shape::Line {
+                    vertices,
+                    labels,
+                    x,
+                    y,
+                    area,
+                }
+            }
+            _ => return,
+        };
+        self.elements.push(Element {
+            series: open.series,
+            shape,
+            bounds: open.bounds,
+        });
+    }
+
+    pub(crate) fn update_context(&mut self, ctx: &ElementContext) {
+        if let ElementContext::DataSeries { id, label, .. } = ctx {
+            if let Some(series) = self.series.iter_mut().rev().find(|(sid, _)| sid == id) {
+                series.1 = label.clone();
+            }
+        }
+    }
+
+    /// Check if an element is being drawn, the draw calls only need to be tracked then
+    pub(crate) fn is_tracking(&self) -> bool {
+        matches!(self.open.last(), Some(Some(_)))
+    }
+
+    /// Expand the area of the element being drawn
+    pub(crate) fn track(&mut self, a: BackendCoord, b: BackendCoord) {
+        if let Some(Some(open)) = self.open.last_mut() {
+            let (lo, hi) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
+            open.bounds = Some(match open.bounds {
+                Some((l, h)) => (
+                    (l.0.min(lo.0), l.1.min(lo.1)),
+                    (h.0.max(hi.0), h.1.max(hi.1)),
+                ),
+                None => (lo, hi),
+            });
+        }
+    }
+
+    /// Record the vertices of a path or a polygon drawn for the element
+    pub(crate) fn track_shape(&mut self, points: &[BackendCoord]) {
+        if let Some(Some(open)) = self.open.last_mut() {
+            if open.shape.is_empty() {
+                open.shape.extend_from_slice(points);
+            }
+        }
+    }
+
+    /// The series an element belongs to, which is the latest series with the id or, if there's
+    /// none, a new unlabeled series
+    fn series_index(&mut self, series_id: usize) -> usize {
+        match self.series.iter().rposition(|(id, _)| *id == series_id) {
+            Some(idx) => idx,
+            None => {
+                self.series.push((series_id, String::new()));
+                self.series.len() - 1
+            }
+        }
+    }
+}
+
+/// The text describing a data element, as shown by a tooltip
+fn describe(label: &str, x: &str, y: &str, z: Option<&str>) -> String {
+    let mut lines = vec![];
+    if !label.is_empty() {
+        lines.push(label.to_string());
+    }
+    lines.push(format!("x: {}", x));
+    lines.push(format!("y: {}", y));
+    if let Some(z) = z {
+        lines.push(format!("z: {}", z));
+    }
+    lines.join("\n")
+}
+
+/// The shape name and the coordinates of a region, like in an HTML `<area>`
+fn region_coords(shape: &HitShape) -> (&'static str, Vec<i32>) {
+    match shape {
+        HitShape::Rect(((x0, y0), (x1, y1))) => ("rect", vec![*x0, *y0, *x1, *y1]),
+        HitShape::Circle((x, y), r) => ("circle", vec![*x, *y, *r as i32]),
+        HitShape::Polygon(vertices) => (
+            "poly",
+            vertices.iter().flat_map(|(x, y)| vec![*x, *y]).collect(),
+        ),
+    }
+}
+
+fn escape_html(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '\n' => escaped.push_str("&#10;"),
+            other => escaped.push(other),
+        }
+    }
+    escaped
+}
+
+fn escape_json(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            other => escaped.push(other),
+        }
+    }
+    escaped
+}
+
+/// Check if the drawn area of an element extends beyond its vertices
+fn extends_beyond(((x0, y0), (x1, y1)): PixelBounds, vertices: &[BackendCoord]) -> bool {
+    let Some(min_x) = vertices.iter().map(|v| v.0).min() else {
+        return true;
+    };
+    let max_x = vertices.iter().map(|v| v.0).max().unwrap_or(min_x);
+    let min_y = vertices.iter().map(|v| v.1).min().unwrap_or(0);
+    let max_y = vertices.iter().map(|v| v.1).max().unwrap_or(min_y);
+    x0 < min_x - AREA_THRESHOLD
+        || x1 > max_x + AREA_THRESHOLD
+        || y0 < min_y - AREA_THRESHOLD
+        || y1 > max_y + AREA_THRESHOLD
+}
+
+fn squared_distance(a: BackendCoord, b: BackendCoord) -> i64 {
+    let (dx, dy) = (i64::from(a.0 - b.0), i64::from(a.1 - b.1));
+    dx * dx + dy * dy
+}
+
+/// The closest point to `p` on the segment from `a` to `b`
+fn project(p: BackendCoord, a: BackendCoord, b: BackendCoord) -> (f64, f64) {
+    let (ax, ay, bx, by) = (
+        f64::from(a.0),
+        f64::from(a.1),
+        f64::from(b.0),
+        f64::from(b.1),
+    );
+    let (dx, dy) = (bx - ax, by - ay);
+    let len2 = dx * dx + dy * dy;
+    if len2 == 0.0 {
+        return (ax, ay);
+    }
+    let t = ((f64::from(p.0) - ax) * dx + (f64::from(p.1) - ay) * dy) / len2;
+    let t = t.clamp(0.0, 1.0);
+    (ax + t * dx, ay + t * dy)
+}
+
+impl Element {
+    /// The distance of the element to a pixel, a tie-breaking distance used when several
+    /// elements are at the same distance, and the location on the element closest to the pixel
+    fn distance(&self, p: BackendCoord) -> Option<(f64, f64, BackendCoord)> {
+        let to = |(x, y): (f64, f64)| (x - f64::from(p.0)).hypot(y - f64::from(p.1));
+        match &self.shape {
+            Shape::Point { coord, .. } => {
+                let center = to((f64::from(coord.0), f64::from(coord.1)));
+                let distance = match self.bounds {
+                    Some(((x0, y0), (x1, y1))) => {
+                        let x = p.0.clamp(x0, x1);
+                        let y = p.1.clamp(y0, y1);
+                        to((f64::from(x), f64::from(y)))
+                    }
+                    None => center,
+                };
+                Some((distance, center, *coord))
+            }
+            Shape::Line { area: true, .. } => {
+                let ((x0, y0), (x1, y1)) = self.bounds?;
+                let location = (p.0.clamp(x0, x1), p.1.clamp(y0, y1));
+                let distance = to((f64::from(location.0), f64::from(location.1)));
+                // Overlapping areas are told apart by the distance to their center
+                let center = to((f64::from(x0 + x1) / 2.0, f64::from(y0 + y1) / 2.0));
+                Some((distance, center, location))
+            }
+            Shape::Line { vertices, .. } => {
+                let closest = match vertices.len() {
+                    0 => return None,
+                    1 => (f64::from(vertices[0].0), f64::from(vertices[0].1)),
+                    _ => vertices
+                        .windows(2)
+                        .map(|seg| project(p, seg[0], seg[1]))
+                        .min_by(|a, b| to(*a).total_cmp(&to(*b)))?,
+                };
+                let distance = to(closest);
+                let location = (closest.0.round() as i32, closest.1.round() as i32);
+                Some((distance, distance, location))
+            }
+        }
+    }
+}
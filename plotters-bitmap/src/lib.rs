@@ -17,6 +17,9 @@ mod bitmap;
 pub use bitmap::BitMapBackend;
 pub use error::BitMapBackendError;
 
+mod hit_test;
+pub use hit_test::{Hit, HitRegion, HitRegionFormat, HitShape, HitTestIndex, PixelBounds};
+
 /*pub mod bitmap_pixel {
     pub use super::bitmap::{BGRXPixel, RGBPixel};
 }*/
@@ -5,6 +5,9 @@
 
    See the documentation for [SVGBackend](struct.SVGBackend.html) for more details.
 */
+mod path;
 mod svg;
+mod tooltip;
 
-pub use svg::SVGBackend;
+pub use svg::{SVGBackend, TooltipMode};
+pub use tooltip::{tooltip_script, tooltip_stylesheet};
@@ -0,0 +1,220 @@
+/*!
+The compact path data written by the optimized output of the SVG backend
+*/
+
+use std::fmt::Write as _;
+
+/// The `d` attribute of a `<path>` element, made of subpaths with relative commands.
+///
+/// Coordinates are rounded to a fixed number of decimals and the current point is tracked in
+/// rounded units, thus the relative deltas don't accumulate any rounding error.
+pub(crate) struct PathData {
+    d: String,
+    /// The number of decimals kept
+    precision: usize,
+    /// The size of a unit, `10^precision`
+    scale: f64,
+    /// The current point, in units
+    pos: (i64, i64),
+    /// The start of the current subpath, in units
+    start: (i64, i64),
+}
+
+impl PathData {
+    pub(crate) fn new(precision: usize) -> Self {
+        Self {
+            d: String::new(),
+            precision,
+            scale: 10f64.powi(precision as i32),
+            pos: (0, 0),
+            start: (0, 0),
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.d.is_empty()
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        &self.d
+    }
+
+    fn to_units(&self, (x, y): (f64, f64)) -> (i64, i64) {
+        (
+            (x * self.scale).round() as i64,
+            (y * self.scale).round() as i64,
+        )
+    }
+
+    /// Append a command followed by its arguments, the separator is left out where the sign of
+    /// the number already separates it from the previous one.
+    fn command(&mut self, cmd: char, args: &[i64]) {
+        self.d.push(cmd);
+        for (idx, &value) in args.iter().enumerate() {
+            if idx > 0 && value >= 0 {
+                self.d.push(' ');
+            }
+            self.push_number(value);
+        }
+    }
+
+    fn push_number(&mut self, value: i64) {
+        if self.precision == 0 {
+            let _ = write!(self.d, "{}", value);
+            return;
+        }
+        if value < 0 {
+            self.d.push('-');
+        }
+        let unit = self.scale as u64;
+        let (int, frac) = (value.unsigned_abs() / unit, value.unsigned_abs() % unit);
+        if frac == 0 {
+            let _ = write!(self.d, "{}", int);
+            return;
+        }
+        let mut frac = format!("{:0width$}", frac, width = self.precision);
+        while frac.ends_with('0') {
+            frac.pop();
+        }
+        // The leading zero isn't needed, e.g. `.5`
+        if int != 0 {
+            let _ = write!(self.d, "{}", int);
+        }
+        self.d.push('.');
+        self.d.push_str(&frac);
+    }
+
+    /// Start a new subpath, the first one is absolute and the others relative to the end of the
+    /// previous one
+    pub(crate) fn move_to(&mut self, to: (f64, f64)) {
+        let to = self.to_units(to);
+        if self.d.is_empty() {
+            self.command('M', &[to.0, to.1]);
+        } else {
+            self.command('m', &[to.0 - self.pos.0, to.1 - self.pos.1]);
+        }
+        self.pos = to;
+        self.start = to;
+    }
+
+    pub(crate) fn line_to(&mut self, to: (f64, f64)) {
+        let to = self.to_units(to);
+        let (dx, dy) = (to.0 - self.pos.0, to.1 - self.pos.1);
+        match (dx, dy) {
+            (0, 0) => return,
+            (dx, 0) => self.command('h', &[dx]),
+            (0, dy) => self.command('v', &[dy]),
+            (dx, dy) => self.command('l', &[dx, dy]),
+        }
+        self.pos = to;
+    }
+
+    pub(crate) fn quad_to(&mut self, ctrl: (f64, f64), to: (f64, f64)) {
+        let (ctrl, to) = (self.to_units(ctrl), self.to_units(to));
+        let (x, y) = self.pos;
+        self.command('q', &[ctrl.0 - x, ctrl.1 - y, to.0 - x, to.1 - y]);
+        self.pos = to;
+    }
+
+    pub(crate) fn cubic_to(&mut self, ctrl1: (f64, f64), ctrl2: (f64, f64), to: (f64, f64)) {
+        let (ctrl1, ctrl2) = (self.to_units(ctrl1), self.to_units(ctrl2));
+        let to = self.to_units(to);
+        let (x, y) = self.pos;
+        let args = [
+            ctrl1.0 - x,
+            ctrl1.1 - y,
+            ctrl2.0 - x,
+            ctrl2.1 - y,
+            to.0 - x,
+            to.1 - y,
+        ];
+        self.command('c', &args);
+        self.pos = to;
+    }
+
+    pub(crate) fn close(&mut self) {
+        self.d.push('z');
+        self.pos = self.start;
+    }
+
+    /// Add an open polyline
+    pub(crate) fn polyline<I: IntoIterator<Item = (f64, f64)>>(&mut self, points: I) {
+        let mut points = points.into_iter();
+        if let Some(first) = points.next() {
+            self.move_to(first);
+            points.for_each(|pt| self.line_to(pt));
+        }
+    }
+
+    /// Add a closed polygon. With `clockwise`, the vertices are reversed if needed, so that
+    /// overlapping polygons of the same path are all filled under the nonzero rule.
+    pub(crate) fn polygon(&mut self, points: &[(f64, f64)], clockwise: bool) {
+        if points.is_empty() {
+            return;
+        }
+        // The shoelace formula, positive for clockwise polygons with the y axis pointing down
+        let area: f64 = points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(a, b)| a.0 * b.1 - b.0 * a.1)
+            .sum();
+        self.move_to(points[0]);
+        if clockwise && area < 0.0 {
+            points[1..].iter().rev().for_each(|&pt| self.line_to(pt));
+        } else {
+            points[1..].iter().for_each(|&pt| self.line_to(pt));
+        }
+        self.close();
+    }
+
+    /// Add a clockwise rectangle
+    pub(crate) fn rect(&mut self, upper_left: (f64, f64), bottom_right: (f64, f64)) {
+        self.move_to(upper_left);
+        self.line_to((bottom_right.0, upper_left.1));
+        self.line_to(bottom_right);
+        self.line_to((upper_left.0, bottom_right.1));
+        self.close();
+    }
+
+    /// Add a clockwise circle made of two arcs
+    pub(crate) fn circle(&mut self, center: (f64, f64), radius: f64) {
+        self.move_to((center.0 - radius, center.1));
+        let r = self.to_units((radius, radius)).0;
+        self.command('a', &[r, r, 0, 1, 1, 2 * r, 0]);
+        self.command('a', &[r, r, 0, 1, 1, -2 * r, 0]);
+        self.d.push('z');
+        self.pos = self.start;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_relative_commands() {
+        let mut d = PathData::new(0);
+        d.polyline([(10.0, 10.0), (20.0, 10.0), (20.0, 5.0), (25.0, 8.0)]);
+        d.polyline([(30.0, 10.0), (30.0, 10.0), (30.0, 12.0)]);
+        assert_eq!(d.as_str(), "M10 10h10v-5l5 3m5 2v2");
+
+        let mut d = PathData::new(2);
+        d.polyline([(0.125, 1.0), (1.5, -0.25)]);
+        assert_eq!(d.as_str(), "M.13 1l1.37-1.25");
+
+        let mut d = PathData::new(1);
+        d.move_to((1.0, 1.0));
+        d.quad_to((2.0, 0.5), (3.0, 1.0));
+        d.cubic_to((3.0, 2.0), (2.5, 3.0), (1.0, 3.0));
+        d.close();
+        assert_eq!(d.as_str(), "M1 1q1-.5 2 0c0 1-.5 2-2 2z");
+    }
+
+    #[test]
+    fn test_polygon_orientation() {
+        let mut d = PathData::new(0);
+        d.polygon(&[(0.0, 0.0), (0.0, 10.0), (10.0, 10.0)], true);
+        d.rect((0.0, 0.0), (4.0, 3.0));
+        assert_eq!(d.as_str(), "M0 0l10 10h-10zm0 0h4v3h-4z");
+    }
+}
@@ -3,28 +3,57 @@ The SVG image drawing backend
 */
 
 use plotters_backend::{
+    rasterizer,
     text_anchor::{HPos, VPos},
-    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
-    ElementContext, FontStyle, FontTransform,
+    BackendColor, BackendCoord, BackendFloatCoord, BackendPaint, BackendStyle, BackendTextStyle,
+    DrawingBackend, DrawingErrorKind, ElementContext, FontStyle, FontTransform, GradientStop,
+    Interpolation, LineCap, LineJoin, MarkerShape, OutlineCommand,
 };
 
-use std::fmt::{format, Write as _};
+use crate::path::PathData;
+use crate::tooltip::{tooltip_script, tooltip_stylesheet};
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
 use std::fs::File;
 #[allow(unused_imports)]
 use std::io::Cursor;
 use std::io::{BufWriter, Error, Write};
 use std::path::Path;
 
-use crate::svg;
+/// The id of the definition of a paint. It's derived from the paint, so the documents inlined in
+/// the same page only share the definitions of identical paints.
+fn paint_id(paint: &BackendPaint) -> String {
+    // FNV-1a, whose hashes don't change across the builds unlike the ones of the std hashers
+    let hash = format!("{:?}", paint)
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+    format!("plotters-paint-{:016x}", hash)
+}
 
 struct Rgb(u8, u8, u8);
 fn make_svg_color(color: BackendColor) -> Rgb {
     Rgb(color.rgb.0, color.rgb.1, color.rgb.2)
 }
 
+/// The fill of a shape, either a color or the id of a paint defined in `<defs>`
+enum Fill {
+    Color(Rgb),
+    Paint(String),
+}
+
+/// The size from which the buffer of a streamed document is written out
+const STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
 enum Target<'a> {
     File(String, &'a Path),
     Buffer(&'a mut String),
+    /// The buffer holds the part of the document which isn't written yet
+    Stream(String, Box<dyn Write + 'a>),
+    /// The document is written into the bytes at `present()`
+    Bytes(String, &'a mut Vec<u8>),
 }
 
 impl Target<'_> {
@@ -32,6 +61,8 @@ impl Target<'_> {
         match self {
             Target::File(ref mut buf, _) => buf,
             Target::Buffer(buf) => buf,
+            Target::Stream(ref mut buf, _) => buf,
+            Target::Bytes(ref mut buf, _) => buf,
         }
     }
 }
@@ -43,13 +74,21 @@ enum SVGTag {
     Line,
     Polygon,
     Polyline,
+    Path,
     Rectangle,
     Text,
     #[allow(dead_code)]
     Image,
     Group,
+    Defs,
+    Use,
     Style,
     Script,
+    LinearGradient,
+    RadialGradient,
+    Stop,
+    Pattern,
+    ClipPath,
 }
 
 impl SVGTag {
@@ -59,17 +98,167 @@ impl SVGTag {
             SVGTag::Circle => "circle",
             SVGTag::Line => "line",
             SVGTag::Polyline => "polyline",
+            SVGTag::Path => "path",
             SVGTag::Rectangle => "rect",
             SVGTag::Text => "text",
             SVGTag::Image => "image",
             SVGTag::Polygon => "polygon",
             SVGTag::Group => "g",
+            SVGTag::Defs => "defs",
+            SVGTag::Use => "use",
             SVGTag::Style => "style",
             SVGTag::Script => "script",
+            SVGTag::LinearGradient => "linearGradient",
+            SVGTag::RadialGradient => "radialGradient",
+            SVGTag::Stop => "stop",
+            SVGTag::Pattern => "pattern",
+            SVGTag::ClipPath => "clipPath",
+        }
+    }
+}
+
+/// The class of the bold or light grid lines
+fn grid_class(bold: bool) -> &'static str {
+    if bold {
+        "plotters-grid-bold"
+    } else {
+        "plotters-grid-light"
+    }
+}
+
+/// Write a complete document, compressed with gzip if requested
+fn write_document<W: Write>(mut out: W, document: &str, gzip: bool) -> std::io::Result<()> {
+    if gzip {
+        #[cfg(feature = "gzip")]
+        {
+            let mut encoder = flate2::write::GzEncoder::new(out, flate2::Compression::default());
+            encoder.write_all(document.as_bytes())?;
+            return encoder.finish()?.flush();
+        }
+        #[cfg(not(feature = "gzip"))]
+        return Err(Error::new(
+            std::io::ErrorKind::Unsupported,
+            "writing .svgz documents needs the gzip feature of plotters-svg",
+        ));
+    }
+    out.write_all(document.as_bytes())?;
+    out.flush()
+}
+
+/// The presentation of an optimized `<path>`, consecutive primitives with the same style are
+/// merged into a single path
+#[derive(Clone, PartialEq)]
+enum PathStyle {
+    Fill((u8, u8, u8)),
+    /// The stroke colour, width and other properties
+    Stroke((u8, u8, u8), u32, StrokeDetails),
+}
+
+/// The properties of a stroke besides its colour and width, those which are the SVG defaults are
+/// left out
+#[derive(Clone, Default, PartialEq)]
+struct StrokeDetails {
+    dash_array: Vec<f64>,
+    dash_offset: f64,
+    line_cap: Option<&'static str>,
+    line_join: Option<&'static str>,
+    miter_limit: Option<f64>,
+}
+
+impl StrokeDetails {
+    fn of<S: BackendStyle>(style: &S) -> Self {
+        let line_cap = match style.line_cap() {
+            LineCap::Butt => None,
+            LineCap::Round => Some("round"),
+            LineCap::Square => Some("square"),
+        };
+        let (line_join, miter_limit) = match style.line_join() {
+            LineJoin::Miter { limit } => (None, Some(limit).filter(|limit| *limit != 4.0)),
+            LineJoin::Round => (Some("round"), None),
+            LineJoin::Bevel => (Some("bevel"), None),
+        };
+        Self {
+            dash_array: style.dash_array().to_vec(),
+            dash_offset: style.dash_offset(),
+            line_cap,
+            line_join,
+            miter_limit,
+        }
+    }
+
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn write_attrs(&self, aw: &mut AttrWriter<'_, Init>) {
+        if !self.dash_array.is_empty() {
+            aw.write_key("stroke-dasharray")
+                .write_value(FormatEscapedIter(
+                    self.dash_array
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, length)| (if idx > 0 { " " } else { "" }, *length)),
+                ));
+            if self.dash_offset != 0.0 {
+                aw.write_key("stroke-dashoffset")
+                    .write_value(self.dash_offset);
+            }
+        }
+        if let Some(line_cap) = self.line_cap {
+            aw.write_key("stroke-linecap").write_value(line_cap);
+        }
+        if let Some(line_join) = self.line_join {
+            aw.write_key("stroke-linejoin").write_value(line_join);
+        }
+        if let Some(miter_limit) = self.miter_limit {
+            aw.write_key("stroke-miterlimit").write_value(miter_limit);
         }
     }
 }
 
+fn to_f64(coord: BackendCoord) -> (f64, f64) {
+    (coord.0 as f64, coord.1 as f64)
+}
+
+fn to_i32(coord: BackendFloatCoord) -> BackendCoord {
+    (coord.0.round() as i32, coord.1.round() as i32)
+}
+
+/// The bounds `(x0, y0, x1, y1)` of the points, grown by `margin`
+fn path_bounds<I: IntoIterator<Item = BackendFloatCoord>>(points: I, margin: f64) -> [f64; 4] {
+    points.into_iter().fold(
+        [
+            f64::INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NEG_INFINITY,
+        ],
+        |b, (x, y)| {
+            [
+                b[0].min(x - margin),
+                b[1].min(y - margin),
+                b[2].max(x + margin),
+                b[3].max(y + margin),
+            ]
+        },
+    )
+}
+
+/// The maximum number of translucent primitives merged into a single path
+const MAX_TRANSLUCENT_MERGE: usize = 256;
+
+/// The optimized path which primitives are being merged into
+struct PendingPath {
+    style: PathStyle,
+    alpha: f64,
+    data: PathData,
+    /// The semantic classes of the merged primitives
+    classes: String,
+    /// The bounds of the merged primitives of a translucent path. Those may only be merged when
+    /// they don't overlap, as overlapping translucent elements blend with each other.
+    bounds: Vec<[f64; 4]>,
+}
+
 /// Tracks the bounding box of all drawing operations within a context
 #[derive(Clone, Debug, Default)]
 struct BBoxTracker {
@@ -81,6 +270,10 @@ struct BBoxTracker {
     /// Set to true when fill_polygon or a filled draw_rect is called
     /// inside this context, indicating the shape has interior area
     has_fill: bool,
+    /// The colour of the first primitive drawn inside this context
+    color: Option<BackendColor>,
+    /// The vertices of the first path or polygon drawn inside this context
+    shape: Vec<BackendCoord>,
 }
 impl BBoxTracker {
     fn expand(&mut self, x: i32, y: i32) {
@@ -106,6 +299,38 @@ impl BBoxTracker {
     }
 }
 
+/// A series `<g>` tag already written to the document
+struct SeriesTag {
+    /// The series id reported by the chart
+    id: usize,
+    /// The buffer offset of the opening tag
+    start: usize,
+    /// The buffer offset after the closing tag, `None` while the series is drawn
+    end: Option<usize>,
+    /// The accessible name of the series, which prefixes the titles of its elements
+    name: String,
+}
+
+/// How the interactive tooltips of the [SVGBackend] pick the data they show
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TooltipMode {
+    /// Show the data point or the line vertex under the cursor, this is the default
+    Nearest,
+    /// Show a vertical crosshair following the cursor inside the plotting area, and list the value
+    /// of every series at the nearest x position. The tooltip template isn't used in this mode.
+    SharedX,
+}
+
+/// How the `width` and `height` of the document are declared
+enum SizeAttrs {
+    /// The size of the backend in pixels
+    Pixels,
+    /// No size, the document is scaled to its container
+    Omitted,
+    /// Percentages of the size of the container
+    Percent(f64, f64),
+}
+
 /// The SVG image drawing backend
 pub struct SVGBackend<'a> {
     target: Target<'a>,
@@ -115,11 +340,74 @@ pub struct SVGBackend<'a> {
     /// When true, `begin_context` / `end_context` emit `<g>` wrappers with
     /// data attributes that the injected tooltip script can use.
     interactive: bool,
+    /// When false, the tooltip CSS and JavaScript are expected to be included by the host page
+    inline_tooltip_assets: bool,
     /// Stack of active element contexts (mirrors `begin_context` / `end_context` nesting ).
     context_stack: Vec<ElementContext>,
     /// Stack of bounding-box trackers, one per active interactive context.
     /// Pushed in `begin_context`, poopped in `end_context`
     bbox_stack: Vec<BBoxTracker>,
+    /// Every emitted series, so that metadata known only after drawing can be patched into it.
+    /// The index in this list is the key of the series, which is unique within the document even
+    /// when it contains several charts.
+    series_tags: Vec<SeriesTag>,
+    /// When true, the contexts are turned into ARIA roles, labels and `<title>` elements
+    accessible: bool,
+    /// The buffer offset of the `<svg>` tag
+    svg_start: usize,
+    /// The document title, taken from the first label context (the chart caption)
+    doc_title: Option<String>,
+    /// The tooltip text template, the default layout of the script is used if not set.
+    tooltip_template: Option<String>,
+    /// Display names of the axes in the tooltip, keyed by axis id.
+    tooltip_axis_names: Vec<(String, String)>,
+    /// How the tooltips pick the data they show.
+    tooltip_mode: TooltipMode,
+    /// Units of the axes in the tooltip, keyed by axis id. Entries set explicitly take precedence
+    /// over the units reported by the axis contexts.
+    tooltip_axis_units: Vec<(String, String)>,
+    /// The number of series tags already written out by a streamed document
+    flushed_series: usize,
+    /// Attributes of series which were written out before the attributes were known, keyed by
+    /// series key, with escaped values. They are emitted at the end of the document for the tooltip script.
+    late_series_attrs: Vec<(usize, String, String)>,
+    /// The first error of a streamed document, reported by `present()`
+    stream_error: Option<Error>,
+    /// When true, the shapes are written as compact `<path>` elements
+    optimized_paths: bool,
+    /// The number of decimals of the coordinates of the optimized paths
+    path_precision: usize,
+    /// The number of decimals of the sub-pixel coordinates, `None` if they're rounded to pixels
+    subpixel_precision: Option<usize>,
+    /// The optimized path which primitives are merged into, with its style and opacity
+    pending_path: Option<PendingPath>,
+    /// When true, each distinct marker is defined once in `<defs>` and drawn with `<use>`
+    reuse_markers: bool,
+    /// The ids of the markers already defined
+    marker_ids: HashSet<String>,
+    /// The ids of the paints already defined
+    paint_ids: HashSet<String>,
+    /// When true, the data series are clipped to their plotting area with clip paths
+    clipping: bool,
+    /// The rectangles of the clip paths already defined, in order
+    clip_rects: Vec<(BackendCoord, BackendCoord)>,
+    /// The depth of the tag stack at each open clip group, including the group itself
+    clip_depths: Vec<usize>,
+    /// When true, the texts are written as the outlines of their glyphs
+    text_outlines: bool,
+    /// When true, the document is compressed with gzip when it's written
+    gzip: bool,
+    /// When true, the shapes get semantic classes derived from their element contexts
+    css_classes: bool,
+    /// The rules of the presentation attributes moved into a `<style>` block
+    stylesheet: Option<StyleSheet>,
+    /// How the size of the document is declared
+    size_attrs: SizeAttrs,
+    /// The `preserveAspectRatio` of the document, the default of the viewers if not set
+    preserve_aspect_ratio: Option<String>,
+    /// The quality of the bitmaps embedded as JPEG, `None` to embed them as PNG
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    jpeg_quality: Option<u8>,
 }
 
 trait FormatEscaped {
@@ -174,6 +462,15 @@ impl FormatEscaped for Rgb {
     }
 }
 
+impl FormatEscaped for Fill {
+    fn format_escaped(buf: &mut String, fill: Fill) {
+        match fill {
+            Fill::Color(rgb) => FormatEscaped::format_escaped(buf, rgb),
+            Fill::Paint(id) => FormatEscaped::format_escaped(buf, ("url(#", id.as_str(), ')')),
+        }
+    }
+}
+
 impl<T: FormatEscaped> FormatEscaped for Option<T> {
     fn format_escaped(buf: &mut String, opt: Option<T>) {
         match opt {
@@ -195,6 +492,8 @@ impl FormatEscaped for char {
             '&' => buf.push_str("&amp;"),
             '"' => buf.push_str("&quot;"),
             '\'' => buf.push_str("&apos;"),
+            // Keep line breaks in attribute values, which would be normalized to spaces otherwise
+            '\n' => buf.push_str("&#10;"),
             other => buf.push(other),
         };
     }
@@ -210,12 +509,66 @@ impl<I: IntoIterator<Item: FormatEscaped>> FormatEscaped for FormatEscapedIter<I
     }
 }
 
+/// The presentation attributes which are moved into the stylesheet, with the unit their values
+/// need as CSS properties
+const PRESENTATION_ATTRS: [(&str, &str); 14] = [
+    ("fill", ""),
+    ("stroke", ""),
+    ("stroke-width", "px"),
+    ("stroke-dasharray", ""),
+    ("stroke-dashoffset", "px"),
+    ("stroke-linecap", ""),
+    ("stroke-linejoin", ""),
+    ("stroke-miterlimit", ""),
+    ("opacity", ""),
+    ("font-family", ""),
+    ("font-size", "px"),
+    ("font-weight", ""),
+    ("font-style", ""),
+    ("text-anchor", ""),
+];
+
+/// The rules of the `<style>` block, one per distinct set of presentation attributes
+#[derive(Default)]
+struct StyleSheet {
+    /// The index of each rule, keyed by its declarations
+    ids: HashMap<String, usize>,
+    rules: Vec<String>,
+}
+
+impl StyleSheet {
+    /// Get the class of the rule with the given declarations
+    fn class_of(&mut self, declarations: String) -> String {
+        let id = match self.ids.get(&declarations) {
+            Some(&id) => id,
+            None => {
+                self.rules.push(declarations.clone());
+                self.ids.insert(declarations, self.rules.len() - 1);
+                self.rules.len() - 1
+            }
+        };
+        format!("plotters-s{}", id)
+    }
+}
+
+/// The classes of a shape, and its CSS declarations if the presentation attributes are moved
+/// into the stylesheet
+struct ShapeClass<'a> {
+    /// The semantic classes of the shape
+    classes: String,
+    declarations: Option<(String, &'a mut StyleSheet)>,
+}
+
 enum Value {}
 enum Init {}
 struct AttrWriter<'a, State> {
     buf: &'a mut String,
     tag: SVGTag,
     tag_stack: &'a mut Vec<SVGTag>,
+    /// The class of a shape tag
+    shape: Option<ShapeClass<'a>>,
+    /// The unit of a value written as a CSS declaration, `None` for an attribute value
+    css_unit: Option<&'static str>,
     state: std::marker::PhantomData<State>,
 }
 
@@ -233,26 +586,77 @@ impl<'a> AttrWriter<'a, Init> {
             buf,
             tag,
             tag_stack,
+            shape: None,
+            css_unit: None,
             state: Default::default(),
         }
     }
 
     fn write_key<'s>(&'s mut self, key: &str) -> AttrWriter<'s, Value> {
+        let unit = PRESENTATION_ATTRS
+            .iter()
+            .find(|(attr, _)| *attr == key)
+            .map(|(_, unit)| *unit);
+        if let (
+            Some(unit),
+            Some(ShapeClass {
+                declarations: Some((declarations, _)),
+                ..
+            }),
+        ) = (unit, self.shape.as_mut())
+        {
+            declarations.push_str(key);
+            declarations.push(':');
+            return AttrWriter {
+                buf: declarations,
+                tag: self.tag.clone(),
+                tag_stack: self.tag_stack,
+                shape: None,
+                css_unit: Some(unit),
+                state: Default::default(),
+            };
+        }
         self.buf.push(' ');
         self.buf.push_str(key);
         AttrWriter {
             buf: self.buf,
             tag: self.tag.clone(),
             tag_stack: self.tag_stack,
+            shape: None,
+            css_unit: None,
             state: Default::default(),
         }
     }
 
-    fn close(self) {
+    /// Write the class of a shape, including the class of its rule in the stylesheet
+    fn write_shape_class(&mut self) {
+        let Some(ShapeClass {
+            mut classes,
+            declarations,
+        }) = self.shape.take()
+        else {
+            return;
+        };
+        if let Some((declarations, stylesheet)) = declarations {
+            if !declarations.is_empty() {
+                if !classes.is_empty() {
+                    classes.push(' ');
+                }
+                classes.push_str(&stylesheet.class_of(declarations));
+            }
+        }
+        if !classes.is_empty() {
+            self.write_key("class").write_value(classes.as_str());
+        }
+    }
+
+    fn close(mut self) {
+        self.write_shape_class();
         self.buf.push_str("/>\n");
     }
 
-    fn finish_without_closing(self) {
+    fn finish_without_closing(mut self) {
+        self.write_shape_class();
         self.tag_stack.push(self.tag);
         self.buf.push_str(">\n");
     }
@@ -260,10 +664,23 @@ impl<'a> AttrWriter<'a, Init> {
 
 impl<'a> AttrWriter<'a, Value> {
     fn write_value(self, value: impl FormatEscaped) {
+        if let Some(unit) = self.css_unit {
+            FormatEscaped::format_escaped(self.buf, value);
+            self.buf.push_str(unit);
+            self.buf.push(';');
+            return;
+        }
         self.buf.push_str("=\"");
         FormatEscaped::format_escaped(self.buf, value);
         self.buf.push('"');
     }
+
+    /// Write a value which has already been escaped
+    fn write_escaped_value(self, value: &str) {
+        self.buf.push_str("=\"");
+        self.buf.push_str(value);
+        self.buf.push('"');
+    }
 }
 
 impl<'a> SVGBackend<'a> {
@@ -274,1227 +691,3750 @@ impl<'a> SVGBackend<'a> {
     }
 
     fn close_tag(&mut self) -> bool {
+        self.flush_path();
         if let Some(tag) = self.tag_stack.pop() {
             let buf = self.target.get_mut();
             buf.push_str("</");
             buf.push_str(tag.to_tag_name());
             buf.push_str(">\n");
+            if buf.len() >= STREAM_CHUNK_SIZE {
+                self.flush_stream();
+            }
             return true;
         }
         false
     }
 
-    /// Opens a tag and provides facilities for writing attrs and closing the tag
-    fn open_tag(&mut self, tag: SVGTag) -> AttrWriter<'_, Init> {
-        AttrWriter::open_tag(self.target.get_mut(), tag, &mut self.tag_stack)
-    }
-
-    fn init_svg_file(&mut self, size: (u32, u32)) {
-        let mut attrwriter = self.open_tag(SVGTag::Svg);
-        attrwriter.write_key("width").write_value(size.0);
-        attrwriter.write_key("height").write_value(size.1);
-        attrwriter
-            .write_key("viewBox")
-            .write_value(("0 0 ", size.0, ' ', size.1));
-        attrwriter
-            .write_key("xmlns")
-            .write_value("http://www.w3.org/2000/svg");
-        attrwriter.finish_without_closing();
-    }
-
-    /// Create a new SVG drawing backend
-    pub fn new<T: AsRef<Path> + ?Sized>(path: &'a T, size: (u32, u32)) -> Self {
-        let mut ret = Self {
-            target: Target::File(String::default(), path.as_ref()),
-            size,
-            tag_stack: vec![],
-            saved: false,
-            interactive: false,
-            context_stack: vec![],
-            bbox_stack: vec![],
+    /// Add a primitive with the given bounds to the optimized output. The primitive is merged
+    /// into the pending path if it has the same style and opacity, and either is opaque or doesn't
+    /// overlap the primitives of the path, so that the result renders the same.
+    fn add_to_path(
+        &mut self,
+        style: PathStyle,
+        alpha: f64,
+        bounds: [f64; 4],
+        build: impl FnOnce(&mut PathData),
+    ) {
+        let overlaps = |b: &[f64; 4]| {
+            b[0] < bounds[2] && bounds[0] < b[2] && b[1] < bounds[3] && bounds[1] < b[3]
         };
-
-        ret.init_svg_file(size);
-        ret
-    }
-
-    /// Create a new SVG drawing backend and store the document into a String buffer
-    pub fn with_string(buf: &'a mut String, size: (u32, u32)) -> Self {
-        let mut ret = Self {
-            target: Target::Buffer(buf),
-            size,
-            tag_stack: vec![],
-            saved: false,
-            interactive: false,
-            context_stack: vec![],
-            bbox_stack: vec![],
+        let classes = self.semantic_classes();
+        let same_style = |pending: &PendingPath| {
+            pending.style == style && pending.alpha == alpha && pending.classes == classes
         };
+        match self.pending_path.as_mut() {
+            Some(pending) if same_style(pending) && alpha >= 1.0 => {
+                build(&mut pending.data);
+            }
+            Some(pending)
+                if same_style(pending)
+                    && pending.bounds.len() < MAX_TRANSLUCENT_MERGE
+                    && !pending.bounds.iter().any(overlaps) =>
+            {
+                build(&mut pending.data);
+                pending.bounds.push(bounds);
+            }
+            _ => {
+                self.flush_path();
+                let precision = self
+                    .path_precision
+                    .max(self.subpixel_precision.unwrap_or(0));
+                let mut data = PathData::new(precision);
+                build(&mut data);
+                self.pending_path = Some(PendingPath {
+                    style,
+                    alpha,
+                    data,
+                    classes,
+                    bounds: if alpha < 1.0 { vec![bounds] } else { vec![] },
+                });
+            }
+        }
+    }
 
-        ret.init_svg_file(size);
+    /// Write the definition of the paint of a fill into a `<defs>` element, unless it has already
+    /// been defined. Returns the id of the definition, or `None` for a solid paint.
+    fn define_paint(&mut self, style: &impl BackendStyle) -> Option<String> {
+        let paint = style.paint();
+        if paint.is_solid() {
+            return None;
+        }
+        let id = paint_id(&paint);
+        if !self.paint_ids.insert(id.clone()) {
+            return Some(id);
+        }
 
-        ret
+        self.open_tag(SVGTag::Defs).finish_without_closing();
+        match paint {
+            BackendPaint::Solid(_) => unreachable!(),
+            BackendPaint::LinearGradient { start, end, stops } => {
+                let mut aw = self.open_tag(SVGTag::LinearGradient);
+                aw.write_key("id").write_value(id.as_str());
+                aw.write_key("x1").write_value(start.0);
+                aw.write_key("y1").write_value(start.1);
+                aw.write_key("x2").write_value(end.0);
+                aw.write_key("y2").write_value(end.1);
+                aw.finish_without_closing();
+                self.write_gradient_stops(stops.as_slice());
+            }
+            BackendPaint::RadialGradient {
+                center,
+                radius,
+                stops,
+            } => {
+                let mut aw = self.open_tag(SVGTag::RadialGradient);
+                aw.write_key("id").write_value(id.as_str());
+                aw.write_key("cx").write_value(center.0);
+                aw.write_key("cy").write_value(center.1);
+                aw.write_key("r").write_value(radius);
+                aw.finish_without_closing();
+                self.write_gradient_stops(stops.as_slice());
+            }
+            BackendPaint::Hatch {
+                color,
+                background,
+                angle,
+                spacing,
+                width,
+            } => {
+                // A tile of a horizontal line, rotated
+                let mut aw = self.open_tag(SVGTag::Pattern);
+                aw.write_key("id").write_value(id.as_str());
+                aw.write_key("patternUnits").write_value("userSpaceOnUse");
+                aw.write_key("width").write_value(spacing);
+                aw.write_key("height").write_value(spacing);
+                if angle != 0.0 {
+                    aw.write_key("patternTransform")
+                        .write_value(("rotate(", angle, ')'));
+                }
+                aw.finish_without_closing();
+                for (color, height) in [(background, spacing), (color, width)] {
+                    if color.alpha == 0.0 {
+                        continue;
+                    }
+                    let mut aw = self.open_tag(SVGTag::Rectangle);
+                    aw.write_key("width").write_value(spacing);
+                    aw.write_key("height").write_value(height);
+                    aw.write_key("opacity").write_value(color.alpha);
+                    aw.write_key("fill").write_value(make_svg_color(color));
+                    aw.close();
+                }
+            }
+        }
+        self.close_tag(); // </linearGradient>, </radialGradient> or </pattern>
+        self.close_tag(); // </defs>
+        Some(id)
     }
 
-    /// Enable interactive tooltips
-    ///
-    /// When enabled, `begin_context` / `end_context` calls emit `<g>` wrapper elements with
-    /// `data-*` attributes. A `<style>` and `<script>` block are injected at `present()` time to
-    /// drive mouse-hover tooltips.
-    pub fn with_tooltips(mut self) -> Self {
-        self.interactive = true;
-        self
+    fn write_gradient_stops(&mut self, stops: &[GradientStop]) {
+        for stop in stops {
+            let mut aw = self.open_tag(SVGTag::Stop);
+            aw.write_key("offset").write_value(stop.offset);
+            aw.write_key("stop-color")
+                .write_value(make_svg_color(stop.color));
+            aw.write_key("stop-opacity").write_value(stop.color.alpha);
+            aw.close();
+        }
     }
 
-    /// Expand the current bounding-box tracker (if any) with a Coordinate.
-    fn track_coord(&mut self, coord: BackendCoord) {
-        if let Some(bb) = self.bbox_stack.last_mut() {
-            bb.expand_coord(coord);
+    /// Write the definition of a marker at the origin into a `<defs>` element, unless it has
+    /// already been defined. Returns the id of the definition.
+    fn define_marker(
+        &mut self,
+        shape: MarkerShape,
+        size: i32,
+        style: &impl BackendStyle,
+    ) -> String {
+        let color = style.color();
+        let width = style.stroke_width();
+        let (r, g, b) = color.rgb;
+        let name = match shape {
+            MarkerShape::Circle { filled: true } => "disc",
+            MarkerShape::Circle { filled: false } => "circle",
+            MarkerShape::Cross => "cross",
+            MarkerShape::Triangle => "triangle",
+        };
+        let id = format!(
+            "plotters-marker-{}-{}-{:02X}{:02X}{:02X}-{}-{}",
+            name, size, r, g, b, color.alpha, width
+        );
+        if !self.marker_ids.insert(id.clone()) {
+            return id;
         }
-    }
 
-    /// Expand the current bounding-box tracker with a rectangle.
-    fn track_rect(&mut self, a: BackendCoord, b: BackendCoord) {
-        if let Some(bb) = self.bbox_stack.last_mut() {
-            bb.expand_rect(a, b);
+        self.open_tag(SVGTag::Defs).finish_without_closing();
+        let svg_color = make_svg_color(color);
+        match shape {
+            MarkerShape::Circle { filled } => {
+                let (fill, stroke) = if filled {
+                    (Some(svg_color), None)
+                } else {
+                    (None, Some(svg_color))
+                };
+                let mut aw = self.open_tag(SVGTag::Circle);
+                aw.write_key("id").write_value(id.as_str());
+                aw.write_key("r").write_value(size.max(0));
+                aw.write_key("opacity").write_value(color.alpha);
+                aw.write_key("fill").write_value(fill);
+                aw.write_key("stroke").write_value(stroke);
+                aw.write_key("stroke-width").write_value(width);
+                aw.close();
+            }
+            MarkerShape::Cross => {
+                let mut aw = self.open_tag(SVGTag::Group);
+                aw.write_key("id").write_value(id.as_str());
+                aw.write_key("opacity").write_value(color.alpha);
+                aw.write_key("stroke").write_value(svg_color);
+                aw.write_key("stroke-width").write_value(width);
+                aw.finish_without_closing();
+                for (y0, y1) in [(-size, size), (size, -size)] {
+                    let mut aw = self.open_tag(SVGTag::Line);
+                    aw.write_key("x1").write_value(-size);
+                    aw.write_key("y1").write_value(y0);
+                    aw.write_key("x2").write_value(size);
+                    aw.write_key("y2").write_value(y1);
+                    aw.close();
+                }
+                self.close_tag();
+            }
+            MarkerShape::Triangle => {
+                let points = rasterizer::triangle_vertices((0, 0), size);
+                let mut aw = self.open_tag(SVGTag::Polygon);
+                aw.write_key("id").write_value(id.as_str());
+                aw.write_key("opacity").write_value(color.alpha);
+                aw.write_key("fill").write_value(svg_color);
+                aw.write_key("points").write_value(FormatEscapedIter(
+                    points.iter().map(|c| (c.0, ',', c.1, ' ')),
+                ));
+                aw.close();
+            }
         }
+        self.close_tag(); // </defs>
+        id
     }
 
-    /// Inject the CSS and JavaScript needed for interactive tooltips.
-    ///
-    /// Called once from `present()` when `interactive` is enabled.
-    fn inject_tooltip_assets(&mut self) {
-        // --- CSS ---
-        let aw = self.open_tag(SVGTag::Style);
-        aw.finish_without_closing();
-        let buf = self.target.get_mut();
-        buf.push_str("<![CDATA[");
-        buf.push_str(
-            r#"
-.plotters-tooltip {
-    pointer-events: none;
-    opacity: 0;
-    transition: opacity 0.15s;
-}
-.plotters-tooltip.plotters-tt-visible {
-    opacity: 1;
-}
-.plotters-tooltip rect {
-    fill: #222;
-    rx: 4;
-    ry: 4;
-}
-.plotters-tooltip text {
-    fill: #fff;
-    font-family: sans-serif;
-    font-size: 12px;
-}
-.plotters-tt-series {
-    font-weight: bold;
-}
-.plotters-dp-hover {
-    pointer-events: all;
-    cursor: crosshair;
-}
-rect.plotters-dp-hover,
-rect.plotters-dl-hover {
-    pointer-events: fill;
-}
-.plotters-dp:hover .plotters-dp-ring, .plotters-dp-hover:hover ~ .plotters-dp-ring, circle:hover ~ .plotters-dp-ring {
-    opacity: 1;
-}
-.plotters-dp > circle {
-    pointer-events: none;
-}
-.plotters-dp-ring {
-    pointer-events: none;
-    opacity: 0;
-    transition: opacity 0.12s;
-}
-.plotters-dl-hover {
-    pointer-events: stroke;
-    cursor: crosshair;
-}
-"#,
-        );
-        buf.push_str("]]>");
-        self.close_tag(); // </style>
+    /// Write a text as the outlines of its glyphs, laid out as the default implementation of
+    /// `draw_text` does. Returns false if the font can't provide the outlines.
+    fn draw_text_outline<S: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &S,
+        pos: BackendFloatCoord,
+    ) -> Result<bool, DrawingErrorKind<Error>> {
+        let font_error = |e| DrawingErrorKind::FontError(Box::new(e));
+        let ((min_x, min_y), (max_x, max_y)) = style.layout_box(text).map_err(font_error)?;
+        let (width, height) = (max_x - min_x, max_y - min_y);
+        let dx = match style.anchor().h_pos {
+            HPos::Left => 0,
+            HPos::Right => -width,
+            HPos::Center => -width / 2,
+        };
+        let dy = match style.anchor().v_pos {
+            VPos::Top => 0,
+            VPos::Center => -height / 2,
+            VPos::Bottom => -height,
+        };
+        let origin = (pos.0 + f64::from(dx - min_x), pos.1 + f64::from(dy - min_y));
+
+        // The glyphs are small, a precision of a hundredth of a pixel keeps their curves smooth
+        let mut d = PathData::new(self.path_precision.max(2));
+        let supported = style
+            .outline(text, (0, 0), |cmd| {
+                let at = |(x, y): (f64, f64)| (origin.0 + x, origin.1 + y);
+                match cmd {
+                    OutlineCommand::MoveTo(x, y) => d.move_to(at((x, y))),
+                    OutlineCommand::LineTo(x, y) => d.line_to(at((x, y))),
+                    OutlineCommand::QuadTo(ctrl, to) => d.quad_to(at(ctrl), at(to)),
+                    OutlineCommand::CubicTo(ctrl1, ctrl2, to) => {
+                        d.cubic_to(at(ctrl1), at(ctrl2), at(to))
+                    }
+                    OutlineCommand::Close => d.close(),
+                }
+            })
+            .map_err(font_error)?;
+        if !supported {
+            return Ok(false);
+        }
+        if d.is_empty() {
+            return Ok(true);
+        }
 
-        // --- Tooltip container ( hidden by default ) ---
-        let buf = self.target.get_mut();
-        buf.push_str(
-            r#"<g class="plotters-tooltip">
-<rect x="0" y="0" width="1" height="1"/>
-<text x="0" y="0">
-<tspan class="plotters-tt-series" x="0" dy="0"></tspan>
-<tspan class="plotters-tt-value" x="0" dy="1.3em"></tspan>
-</text>
-</g>
-"#,
-        );
+        let color = style.color();
+        let mut aw = self.open_text_tag(SVGTag::Path);
+        aw.write_key("aria-label").write_value(text);
+        aw.write_key("opacity").write_value(color.alpha);
+        aw.write_key("fill").write_value(make_svg_color(color));
+        let degrees = match style.transform() {
+            FontTransform::None => None,
+            FontTransform::Rotate90 => Some(90),
+            FontTransform::Rotate180 => Some(180),
+            FontTransform::Rotate270 => Some(270),
+        };
+        if let Some(degrees) = degrees {
+            aw.write_key("transform")
+                .write_value(("rotate(", degrees, ", ", pos.0, ", ", pos.1, ')'));
+        }
+        aw.write_key("d").write_value(d.as_str());
+        aw.close();
+        Ok(true)
+    }
 
-        // --- JavaScript ---
-        let aw = self.open_tag(SVGTag::Script);
-        aw.finish_without_closing();
-        let buf = self.target.get_mut();
-        buf.push_str("<![CDATA[");
-        buf.push_str(
-            r#"
-(function() {
-  var svg = document.currentScript.closest("svg");
-  if (!svg) return;
-  let tt = svg.querySelector(".plotters-tooltip");
-  if (!tt) return;
-  let ttRect = tt.querySelector("rect");
-  let ttSeries = tt.querySelector(".plotters-tt-series");
-  let ttValue = tt.querySelector(".plotters-tt-value");
-  let pad = 8;
-
-  function show(evt) {
-    let el = evt.currentTarget;
-    let xl = el.getAttribute("data-xl") || "";
-    let yl = el.getAttribute("data-yl") || "";
-    let sl = el.getAttribute("data-sl") || "";
-
-    // Populate tspans
-    ttSeries.textContent = sl;
-    ttSeries.style.display = sl ? "" : "none";
-    ttValue.textContent = "x: " + xl + " y: " + yl;
-
-    // Position everything at origin to measure
-    tt.classList.add("plotters-tt-visible");
-    ttSeries.setAttribute("x", 0);
-    ttValue.setAttribute("x", 0);
-    let bbox = tt.querySelector("text").getBBox();
-
-    let px = parseFloat(el.getAttribute("cx") || el.getAttribute("data-cx") || 0);
-    let py = parseFloat(el.getAttribute("cy") || el.getAttribute("data-cy") || 0);
-
-    let tw = bbox.width + pad*2;
-    let th = bbox.height + pad*2;
-    let tx = px + 12;
-    let ty = py - th - 4;
-    let svgW = svg.viewBox.baseVal ? svg.viewBox.baseVal.width : svg.width.baseVal.value;
-    let svgH = svg.viewBox.baseVal ? svg.viewBox.baseVal.height : svg.height.baseVal.value;
-    if (tx + tw > svgW) tx = px - tw - 12;
-    if (ty < 0) ty = py + 16;
-    if (ty + th > svgH) ty = svgH - th -2;
-
-    ttRect.setAttribute("x", tx);
-    ttRect.setAttribute("y", ty);
-    ttRect.setAttribute("width", tw);
-    ttRect.setAttribute("height", th);
-
-    // Align tspans inside the box
-    let textY = ty + pad + (bbox.height * 0.35);
-    ttSeries.setAttribute("x", tx + pad);
-    ttValue.setAttribute("x", tx + pad);
-    tt.querySelector("text").setAttribute("x", tx + pad);
-    tt.querySelector("text").setAttribute("y", textY);
-  }
-
-  function hide() {
-    tt.classList.remove("plotters-tt-visible");
-  }
-
-  svg.querySelectorAll(".plotters-dp-hover").forEach(function(el) {
-    el.addEventListener("mouseenter", show);
-    el.addEventListener("mouseleave", hide);
-  });
-
-  /* --- Continous line tooltips via mousemove --- */
-  function lineMove(evt) {
-    let el = evt.target;
-    let ptsStr = el.getAttribute("data-pts");
-    if (!ptsStr) return;
-    let sl = el.getAttribute("data-sl") || "";
-
-    // Parse vertices: "x,y,xl,yl;..."
-    let verts = ptsStr.split(";").map(function(s) {
-      let p = s.split(",");
-      return { x: +p[0], y: +p[1], xl: p[2], yl: p[3] };
-    });
-
-    // Get cursor position in SVG coordinates
-    let pt = svg.createSVGPoint();
-    pt.x = evt.clientX;
-    pt.y = evt.clientY;
-    let svgPt = pt.matrixTransform(svg.getScreenCTM().inverse());
-
-    // Find nearest vertex by x-distance
-    let best = verts[0], bestDist = Math.abs(svgPt.x - verts[0].x);
-    for (var i = 1; i < verts.length; i++) {
-      let d = Math.abs(svgPt.x - verts[i].x);
-      if (d < bestDist) { bestDist = d; best = verts[i]; }
-    }
-
-    // Show tooltip at that vertex
-    ttSeries.textContent = sl;
-    ttSeries.style.display = sl ? "" : "none";
-    ttValue.textContent = "x: " + best.xl + " y: " + best.yl;
-
-    tt.classList.add("plotters-tt-visible");
-    ttSeries.setAttribute("x", 0);
-    ttValue.setAttribute("x", 0);
-    let bbox = tt.querySelector("text").getBBox();
-
-    let tw = bbox.width + pad * 2;
-    let th = bbox.height + pad * 2;
-    let tx = best.x + 12;
-    let ty = best.y - th - 4;
-    let svgW = svg.viewBox.baseVal ? svg.viewBox.baseVal.width : svg.width.baseVal.value;
-    let svgH = svg.viewBox.baseVal ? svg.viewBox.baseVal.height : svg.height.baseVal.value;
-    if (tx + tw > svgW) tx = best.x - tw - 12;
-    if (ty < 0) ty = best.y + 16;
-    if (ty + th > svgH) ty = svgH - th - 2;
-
-    ttRect.setAttribute("x", tx);
-    ttRect.setAttribute("y", ty);
-    ttRect.setAttribute("width", tw);
-    ttRect.setAttribute("height", th);
-
-    let textY = ty + pad + (bbox.height * 0.35);
-    ttSeries.setAttribute("x", tx + pad);
-    ttValue.setAttribute("x", tx + pad);
-    tt.querySelector("text").setAttribute("x", tx + pad);
-    tt.querySelector("text").setAttribute("y", textY);
-  }
-
-  svg.querySelectorAll(".plotters-dl-hover").forEach(function(el) {
-    el.addEventListener("mousemove", lineMove);
-    el.addEventListener("mouseleave", hide);
-  });
-})();
-//# sourceURL=svg-tooltip.js
-"#,
-        );
-        buf.push_str("]]>");
-        self.close_tag(); // </script>
+    /// Round a sub-pixel coordinate to the precision of the document, whole pixels unless
+    /// [with_subpixel_precision](SVGBackend::with_subpixel_precision) is set
+    fn snap(&self, (x, y): BackendFloatCoord) -> BackendFloatCoord {
+        (self.snap_value(x), self.snap_value(y))
     }
-}
 
-impl<'a> DrawingBackend for SVGBackend<'a> {
-    type ErrorType = Error;
+    fn snap_value(&self, value: f64) -> f64 {
+        let scale = 10f64.powi(self.subpixel_precision.unwrap_or(0) as i32);
+        (value * scale).round() / scale
+    }
 
-    fn get_size(&self) -> (u32, u32) {
-        self.size
+    /// Draw a line, the coordinates are rounded with [snap](SVGBackend::snap), as for the other
+    /// shapes written below
+    fn write_line<S: BackendStyle>(
+        &mut self,
+        from: BackendFloatCoord,
+        to: BackendFloatCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        if style.color().alpha == 0.0 {
+            return Ok(());
+        }
+        let (from, to) = (self.snap(from), self.snap(to));
+        if self.optimized_paths {
+            let width = style.stroke_width();
+            let bounds = path_bounds([from, to], width as f64 / 2.0);
+            let path_style = PathStyle::Stroke(style.color().rgb, width, StrokeDetails::of(style));
+            self.add_to_path(path_style, style.color().alpha, bounds, |d| {
+                d.polyline([from, to])
+            });
+        } else {
+            let mut attrwriter = self.open_shape_tag(SVGTag::Line);
+            attrwriter
+                .write_key("opacity")
+                .write_value(style.color().alpha);
+            attrwriter
+                .write_key("stroke")
+                .write_value(make_svg_color(style.color()));
+            attrwriter
+                .write_key("stroke-width")
+                .write_value(style.stroke_width());
+            StrokeDetails::of(style).write_attrs(&mut attrwriter);
+            attrwriter.write_key("x1").write_value(from.0);
+            attrwriter.write_key("y1").write_value(from.1);
+            attrwriter.write_key("x2").write_value(to.0);
+            attrwriter.write_key("y2").write_value(to.1);
+            attrwriter.close();
+        }
+        self.track_rect(to_i32(from), to_i32(to));
+        self.track_color(style.color());
+        Ok(())
     }
 
-    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+    /// Draw an open polyline
+    fn write_path<S: BackendStyle, I: IntoIterator<Item = BackendFloatCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        if style.color().alpha == 0.0 {
+            return Ok(());
+        }
+        let path_points: Vec<_> = path.into_iter().map(|pt| self.snap(pt)).collect();
+        if self.optimized_paths {
+            let width = style.stroke_width();
+            // The miter joins may reach further than half the width
+            let bounds = path_bounds(path_points.iter().copied(), width as f64 * 2.0);
+            let path_style = PathStyle::Stroke(style.color().rgb, width, StrokeDetails::of(style));
+            self.add_to_path(path_style, style.color().alpha, bounds, |d| {
+                d.polyline(path_points.iter().copied())
+            });
+        } else {
+            let mut attrwriter = self.open_shape_tag(SVGTag::Polyline);
+            attrwriter.write_key("fill").write_value("none");
+            attrwriter
+                .write_key("opacity")
+                .write_value(style.color().alpha);
+            attrwriter
+                .write_key("stroke")
+                .write_value(make_svg_color(style.color()));
+            attrwriter
+                .write_key("stroke-width")
+                .write_value(style.stroke_width());
+            StrokeDetails::of(style).write_attrs(&mut attrwriter);
+            attrwriter
+                .write_key("points")
+                .write_value(FormatEscapedIter(
+                    path_points.iter().map(|c| (c.0, ',', c.1, ' ')),
+                ));
+            attrwriter.close();
+        }
+        let pixels: Vec<_> = path_points.iter().copied().map(to_i32).collect();
+        for &pt in &pixels {
+            self.track_coord(pt);
+        }
+        self.track_shape(&pixels);
+        self.track_color(style.color());
         Ok(())
     }
 
-    fn begin_context(&mut self, ctx: ElementContext) {
-        if !self.interactive {
-            self.context_stack.push(ctx);
-            return;
+    /// Draw a filled polygon
+    fn write_polygon<S: BackendStyle, I: IntoIterator<Item = BackendFloatCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        if style.color().alpha == 0.0 {
+            return Ok(());
         }
-        // Open a <g> with data attributes derived from the context
-        let mut aw = self.open_tag(SVGTag::Group);
-        match &ctx {
-            ElementContext::Background => {
-                aw.write_key("class").write_value("plotters-bg");
+        let poly_points: Vec<_> = path.into_iter().map(|pt| self.snap(pt)).collect();
+        // The gradients are relative to the bounding box of each shape, thus they aren't merged
+        let paint_id = self.define_paint(style);
+        if self.optimized_paths && paint_id.is_none() {
+            let bounds = path_bounds(poly_points.iter().copied(), 0.0);
+            let path_style = PathStyle::Fill(style.color().rgb);
+            self.add_to_path(path_style, style.color().alpha, bounds, |d| {
+                d.polygon(&poly_points, true)
+            });
+        } else {
+            let (opacity, fill) = match paint_id {
+                Some(id) => (1.0, Fill::Paint(id)),
+                None => (
+                    style.color().alpha,
+                    Fill::Color(make_svg_color(style.color())),
+                ),
+            };
+            let mut attrwriter = self.open_shape_tag(SVGTag::Polygon);
+            attrwriter.write_key("opacity").write_value(opacity);
+            attrwriter.write_key("fill").write_value(fill);
+            attrwriter
+                .write_key("points")
+                .write_value(FormatEscapedIter(
+                    poly_points.iter().map(|c| (c.0, ',', c.1, ' ')),
+                ));
+            attrwriter.close();
+        }
+        let pixels: Vec<_> = poly_points.iter().copied().map(to_i32).collect();
+        if let Some(bb) = self.bbox_stack.last_mut() {
+            for &pt in &pixels {
+                bb.expand_coord(pt);
             }
-            ElementContext::Axis { axis_id, .. } => {
-                aw.write_key("class").write_value("plotters-axis");
-                aw.write_key("data-axis").write_value(axis_id.as_str());
+            bb.has_fill = true;
+        }
+        self.track_shape(&pixels);
+        self.track_color(style.color());
+
+        Ok(())
+    }
+
+    /// Draw a circle
+    fn write_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendFloatCoord,
+        radius: f64,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        let center = self.snap(center);
+        let radius = self.snap_value(radius.max(0.0));
+        if style.color().alpha == 0.0 {
+            return Ok(());
+        }
+        let paint_id = if fill { self.define_paint(style) } else { None };
+        if self.optimized_paths && paint_id.is_none() {
+            let (path_style, margin) = if fill {
+                (PathStyle::Fill(style.color().rgb), 0.0)
+            } else {
+                let width = style.stroke_width();
+                (
+                    PathStyle::Stroke(style.color().rgb, width, StrokeDetails::of(style)),
+                    width as f64 / 2.0,
+                )
+            };
+            let r = radius;
+            let corners = [(center.0 - r, center.1 - r), (center.0 + r, center.1 + r)];
+            let bounds = path_bounds(corners, margin);
+            self.add_to_path(path_style, style.color().alpha, bounds, |d| {
+                d.circle(center, radius)
+            });
+        } else {
+            let color = make_svg_color(style.color());
+            let opacity = if paint_id.is_some() {
+                1.0
+            } else {
+                style.color().alpha
+            };
+            let (stroke, fill) = match (fill, paint_id) {
+                (false, _) => (Some(color), None),
+                (true, Some(id)) => (None, Some(Fill::Paint(id))),
+                (true, None) => (None, Some(Fill::Color(color))),
+            };
+            let mut attrwriter = self.open_shape_tag(SVGTag::Circle);
+            attrwriter.write_key("cx").write_value(center.0);
+            attrwriter.write_key("cy").write_value(center.1);
+            attrwriter.write_key("r").write_value(radius);
+            attrwriter.write_key("opacity").write_value(opacity);
+            let is_stroked = stroke.is_some();
+            attrwriter.write_key("fill").write_value(fill);
+            attrwriter.write_key("stroke").write_value(stroke);
+            attrwriter
+                .write_key("stroke-width")
+                .write_value(style.stroke_width());
+            if is_stroked {
+                StrokeDetails::of(style).write_attrs(&mut attrwriter);
             }
-            ElementContext::Tick {
-                axis_id,
-                position,
-                label,
-            } => {
-                aw.write_key("class").write_value("plotters-tick");
-                aw.write_key("data-axis").write_value(axis_id.as_str());
-                aw.write_key("data-pos").write_value(*position);
-                aw.write_key("data-label").write_value(label.as_str());
+            attrwriter.close();
+        }
+        self.track_rect(
+            to_i32((center.0 - radius, center.1 - radius)),
+            to_i32((center.0 + radius, center.1 + radius)),
+        );
+        self.track_color(style.color());
+        Ok(())
+    }
+
+    /// Draw a text, either as a `<text>` element or as the outlines of its glyphs
+    fn write_text<S: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &S,
+        pos: BackendFloatCoord,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        let pos = self.snap(pos);
+        let color = style.color();
+        if color.alpha == 0.0 {
+            return Ok(());
+        }
+
+        if self.text_outlines && self.draw_text_outline(text, style, pos)? {
+            return Ok(());
+        }
+
+        let (x0, y0) = pos;
+        let text_anchor = match style.anchor().h_pos {
+            HPos::Left => "start",
+            HPos::Right => "end",
+            HPos::Center => "middle",
+        };
+
+        let dy = match style.anchor().v_pos {
+            VPos::Top => "0.76em",
+            VPos::Center => "0.5ex",
+            VPos::Bottom => "-0.5ex",
+        };
+
+        #[cfg(feature = "debug")]
+        {
+            let ((fx0, fy0), (fx1, fy1)) =
+                font.layout_box(text).map_err(DrawingErrorKind::FontError)?;
+            let x0 = match style.anchor().h_pos {
+                HPos::Left => x0,
+                HPos::Center => x0 - fx1 / 2 + fx0 / 2,
+                HPos::Right => x0 - fx1 + fx0,
+            };
+            let y0 = match style.anchor().v_pos {
+                VPos::Top => y0,
+                VPos::Center => y0 - fy1 / 2 + fy0 / 2,
+                VPos::Bottom => y0 - fy1 + fy0,
+            };
+            self.draw_rect(
+                (x0, y0),
+                (x0 + fx1 - fx0, y0 + fy1 - fy0),
+                &crate::prelude::RED,
+                false,
+            )
+            .unwrap();
+            self.draw_circle((x0, y0), 2, &crate::prelude::RED, false)
+                .unwrap();
+        }
+
+        let mut attrwriter = self.open_text_tag(SVGTag::Text);
+        attrwriter.write_key("x").write_value(x0);
+        attrwriter.write_key("y").write_value(y0);
+        attrwriter.write_key("dy").write_value(dy);
+        attrwriter.write_key("text-anchor").write_value(text_anchor);
+        attrwriter
+            .write_key("font-family")
+            .write_value(style.family().as_str());
+        attrwriter
+            .write_key("font-size")
+            .write_value(style.size() / 1.24);
+        attrwriter.write_key("opacity").write_value(color.alpha);
+        attrwriter
+            .write_key("fill")
+            .write_value(make_svg_color(color));
+
+        match style.style() {
+            FontStyle::Normal => {}
+            FontStyle::Bold => {
+                attrwriter.write_key("font-weight").write_value("bold");
             }
-            ElementContext::Label { text } => {
-                aw.write_key("class").write_value("plotters-label");
-                aw.write_key("data-text").write_value(text.as_str());
+            other_style => {
+                attrwriter
+                    .write_key("font-style")
+                    .write_value(other_style.as_str());
             }
-            ElementContext::DataSeries { id, label, .. } => {
-                aw.write_key("class").write_value("plotters-series");
-                aw.write_key("data-series-id").write_value(*id as i32);
-                if !label.is_empty() {
-                    aw.write_key("data-series-label")
-                        .write_value(label.as_str());
-                }
+        };
+
+        let trans = style.transform();
+        match trans {
+            FontTransform::Rotate90 => {
+                attrwriter
+                    .write_key("transform")
+                    .write_value(("rotate(90, ", x0, ", ", y0, ')'));
             }
-            ElementContext::DataPoint {
-                coord,
-                x_label,
-                y_label,
-                series_id,
-            } => {
-                aw.write_key("class").write_value("plotters-dp");
-                aw.write_key("data-x").write_value(coord.0);
-                aw.write_key("data-y").write_value(coord.1);
-                aw.write_key("data-xl").write_value(x_label.as_str());
-                aw.write_key("data-yl").write_value(y_label.as_str());
-                aw.write_key("data-series-id")
-                    .write_value(*series_id as i32);
+            FontTransform::Rotate180 => {
+                attrwriter
+                    .write_key("transform")
+                    .write_value(("rotate(180, ", x0, ", ", y0, ')'));
             }
-            ElementContext::DataLine { series_id, .. } => {
-                aw.write_key("class").write_value("plotters-dl");
-                aw.write_key("data-series-id")
-                    .write_value(*series_id as i32);
+            FontTransform::Rotate270 => {
+                attrwriter
+                    .write_key("transform")
+                    .write_value(("rotate(270, ", x0, ", ", y0, ')'));
             }
+            _ => {}
         }
-        aw.finish_without_closing();
-        self.bbox_stack.push(BBoxTracker::default());
-        self.context_stack.push(ctx);
+        attrwriter.finish_without_closing();
+
+        Self::escape_and_push(self.target.get_mut(), text);
+        self.target.get_mut().push('\n');
+
+        self.close_tag();
+
+        Ok(())
     }
 
-    fn end_context(&mut self) {
-        let Some(ctx) = self.context_stack.pop() else {
+    /// Draw a marker as a `<use>` of its definition
+    fn use_marker<S: BackendStyle>(
+        &mut self,
+        center: BackendFloatCoord,
+        shape: MarkerShape,
+        size: i32,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        let center = self.snap(center);
+        if style.color().alpha == 0.0 {
+            return Ok(());
+        }
+        let id = self.define_marker(shape, size, style);
+        let mut aw = self.open_shape_tag(SVGTag::Use);
+        aw.write_key("href").write_value(("#", id.as_str()));
+        aw.write_key("x").write_value(center.0);
+        aw.write_key("y").write_value(center.1);
+        aw.close();
+
+        if shape == MarkerShape::Triangle {
+            let points = rasterizer::triangle_vertices(to_i32(center), size);
+            if let Some(bb) = self.bbox_stack.last_mut() {
+                points.iter().for_each(|&pt| bb.expand_coord(pt));
+                bb.has_fill = true;
+            }
+            self.track_shape(&points);
+        } else {
+            let r = match shape {
+                MarkerShape::Circle { .. } => size.max(0),
+                _ => size.abs(),
+            };
+            let (x, y) = to_i32(center);
+            self.track_rect((x - r, y - r), (x + r, y + r));
+        }
+        self.track_color(style.color());
+        Ok(())
+    }
+
+    /// Write a bitmap as an `<image>` with a data URI, encoded as JPEG if it has no alpha channel
+    /// and JPEG has been chosen, or as PNG otherwise.
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    fn embed_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (w, h): (u32, u32),
+        src: &[u8],
+        color: image::ExtendedColorType,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        use image::codecs::jpeg::JpegEncoder;
+        use image::codecs::png::PngEncoder;
+        use image::ImageEncoder;
+
+        let mut data = vec![0; 0];
+
+        let (mime, result) = match self.jpeg_quality {
+            Some(quality) if color == image::ExtendedColorType::Rgb8 => {
+                let encoder = JpegEncoder::new_with_quality(Cursor::new(&mut data), quality);
+                ("image/jpeg", encoder.write_image(src, w, h, color))
+            }
+            _ => {
+                let encoder = PngEncoder::new(Cursor::new(&mut data));
+                ("image/png", encoder.write_image(src, w, h, color))
+            }
+        };
+        result.map_err(|e| {
+            DrawingErrorKind::DrawingError(Error::other(format!("Image error: {}", e)))
+        })?;
+
+        let padding = (3 - data.len() % 3) % 3;
+        data.resize(data.len() + padding, 0);
+
+        let mut rem_bits = 0;
+        let mut rem_num = 0;
+
+        fn cvt_base64(from: u8) -> char {
+            (if from < 26 {
+                b'A' + from
+            } else if from < 52 {
+                b'a' + from - 26
+            } else if from < 62 {
+                b'0' + from - 52
+            } else if from == 62 {
+                b'+'
+            } else {
+                b'/'
+            })
+            .into()
+        }
+
+        let mut buf = String::new();
+        buf.push_str("data:");
+        buf.push_str(mime);
+        buf.push_str(";base64,");
+
+        for byte in data {
+            let value = (rem_bits << (6 - rem_num)) | (byte >> (rem_num + 2));
+            rem_bits = byte & ((1 << (2 + rem_num)) - 1);
+            rem_num += 2;
+
+            buf.push(cvt_base64(value));
+            if rem_num == 6 {
+                buf.push(cvt_base64(rem_bits));
+                rem_bits = 0;
+                rem_num = 0;
+            }
+        }
+
+        for _ in 0..padding {
+            buf.pop();
+            buf.push('=');
+        }
+
+        let mut attrwriter = self.open_shape_tag(SVGTag::Image);
+        attrwriter.write_key("x").write_value(pos.0);
+        attrwriter.write_key("y").write_value(pos.1);
+        attrwriter.write_key("width").write_value(w);
+        attrwriter.write_key("height").write_value(h);
+        attrwriter.write_key("href").write_value(buf.as_str());
+        attrwriter.close();
+
+        self.track_rect(pos, (pos.0 + w as i32, pos.1 + h as i32));
+        Ok(())
+    }
+
+    /// Write the pending optimized path
+    fn flush_path(&mut self) {
+        let Some(PendingPath {
+            style,
+            alpha,
+            data,
+            classes,
+            ..
+        }) = self.pending_path.take()
+        else {
             return;
         };
-        let bbox = self.bbox_stack.pop().unwrap_or_default();
-        if !self.interactive {
+        if data.is_empty() {
             return;
         }
-        // Resolve the series label (shared by DataPoint and DataLine)
-        let series_label = self
-            .context_stack
-            .iter()
-            .rev()
-            .find_map(|c| {
-                if let ElementContext::DataSeries { id, color, label } = c {
-                    Some(label.clone())
-                } else {
-                    None
-                }
-            })
-            .unwrap_or_default();
+        let mut aw = self.write_shape_tag(SVGTag::Path, classes);
+        if alpha < 1.0 {
+            aw.write_key("opacity").write_value(alpha);
+        }
+        match style {
+            PathStyle::Fill((r, g, b)) => {
+                aw.write_key("fill").write_value(Rgb(r, g, b));
+            }
+            PathStyle::Stroke((r, g, b), width, details) => {
+                aw.write_key("fill").write_value("none");
+                aw.write_key("stroke").write_value(Rgb(r, g, b));
+                aw.write_key("stroke-width").write_value(width);
+                details.write_attrs(&mut aw);
+            }
+        }
+        aw.write_key("d").write_value(data.as_str());
+        aw.close();
+    }
 
-        // For DataPoint contexts, emit a hover target covering the drawn bounding box ( so complex
-        // elements like boxplots are fully hoverable).
-        if let ElementContext::DataPoint {
-            coord,
-            x_label,
-            y_label,
+    /// Write out the buffered part of a streamed document. The series written out can't be
+    /// patched anymore.
+    fn flush_stream(&mut self) {
+        let Target::Stream(buf, out) = &mut self.target else {
+            return;
+        };
+        if self.accessible || self.stream_error.is_some() {
+            return;
+        }
+        match out.write_all(buf.as_bytes()) {
+            Ok(()) => {
+                buf.clear();
+                self.flushed_series = self.series_tags.len();
+            }
+            Err(e) => self.stream_error = Some(e),
+        }
+    }
+
+    /// Emit the series attributes which couldn't be patched into a streamed document, the
+    /// tooltip script copies them to their series.
+    fn write_late_series_attrs(&mut self) {
+        let attrs = std::mem::take(&mut self.late_series_attrs);
+        let mut keys: Vec<_> = attrs.iter().map(|(key, _, _)| *key).collect();
+        keys.sort_unstable();
+        keys.dedup();
+        for key in keys {
+            let mut aw = self.open_tag(SVGTag::Group);
+            aw.write_key("class").write_value("plotters-series-attrs");
+            aw.write_key("data-series-key").write_value(key as u32);
+            for (_, attr, value) in attrs.iter().filter(|(k, _, _)| *k == key) {
+                aw.write_key(attr).write_escaped_value(value);
+            }
+            aw.close();
+        }
+    }
+
+    /// Opens a tag and provides facilities for writing attrs and closing the tag
+    fn open_tag(&mut self, tag: SVGTag) -> AttrWriter<'_, Init> {
+        self.flush_path();
+        AttrWriter::open_tag(self.target.get_mut(), tag, &mut self.tag_stack)
+    }
+
+    /// Opens the tag of a shape, which gets the semantic classes of the current contexts
+    fn open_shape_tag(&mut self, tag: SVGTag) -> AttrWriter<'_, Init> {
+        self.flush_path();
+        let classes = self.semantic_classes();
+        self.write_shape_tag(tag, classes)
+    }
+
+    /// Opens the tag of a text, which is a label even outside of a label context, e.g. the tick
+    /// labels of an axis
+    fn open_text_tag(&mut self, tag: SVGTag) -> AttrWriter<'_, Init> {
+        self.flush_path();
+        let mut classes = self.semantic_classes();
+        if self.css_classes && !classes.split(' ').any(|c| c == "plotters-label") {
+            if !classes.is_empty() {
+                classes.push(' ');
+            }
+            classes.push_str("plotters-label");
+        }
+        self.write_shape_tag(tag, classes)
+    }
+
+    fn write_shape_tag(&mut self, tag: SVGTag, classes: String) -> AttrWriter<'_, Init> {
+        let shape = if self.css_classes {
+            Some(ShapeClass {
+                classes,
+                declarations: self
+                    .stylesheet
+                    .as_mut()
+                    .map(|stylesheet| (String::new(), stylesheet)),
+            })
+        } else {
+            None
+        };
+        let mut aw = AttrWriter::open_tag(self.target.get_mut(), tag, &mut self.tag_stack);
+        aw.shape = shape;
+        aw
+    }
+
+    /// The classes describing what the current contexts draw, e.g. `plotters-axis plotters-label`
+    fn semantic_classes(&self) -> String {
+        let mut classes = String::new();
+        if !self.css_classes {
+            return classes;
+        }
+        let mut add = |class: &str| {
+            if !classes.split(' ').any(|c| c == class) {
+                if !classes.is_empty() {
+                    classes.push(' ');
+                }
+                classes.push_str(class);
+            }
+        };
+        for ctx in &self.context_stack {
+            match ctx {
+                ElementContext::Background => add("plotters-bg"),
+                ElementContext::Axis { .. } => add("plotters-axis"),
+                ElementContext::Grid { bold } => add(grid_class(*bold)),
+                ElementContext::Tick { .. } => add("plotters-tick"),
+                ElementContext::Label { .. } => add("plotters-label"),
+                ElementContext::DataSeries { id, .. } => add(&format!("plotters-series-{}", id)),
+                ElementContext::LegendEntry { series_id, .. } => {
+                    add("plotters-legend");
+                    add(&format!("plotters-series-{}", series_id));
+                }
+                // The data elements are styled by their series
+                _ => {}
+            }
+        }
+        classes
+    }
+
+    /// Write the `<style>` block with the presentation attributes of the shapes
+    fn write_stylesheet(&mut self) {
+        let Some(stylesheet) = self.stylesheet.as_ref() else {
+            return;
+        };
+        let mut css = String::new();
+        for (id, declarations) in stylesheet.rules.iter().enumerate() {
+            // The rules have no specificity, like presentation attributes, thus any rule of the
+            // host page overrides them
+            let _ = writeln!(css, ":where(.plotters-s{}){{{}}}", id, declarations);
+        }
+        self.open_tag(SVGTag::Style).finish_without_closing();
+        self.target.get_mut().push_str(&css);
+        self.close_tag();
+    }
+
+    fn init_svg_file(&mut self) {
+        self.svg_start = self.target.get_mut().len();
+        let tag = self.svg_tag();
+        self.target.get_mut().push_str(&tag);
+        self.tag_stack.push(SVGTag::Svg);
+    }
+
+    /// The opening `<svg>` tag
+    fn svg_tag(&self) -> String {
+        let size = self.size;
+        let mut buf = String::new();
+        let mut tag_stack = vec![];
+        let mut attrwriter = AttrWriter::open_tag(&mut buf, SVGTag::Svg, &mut tag_stack);
+        match self.size_attrs {
+            SizeAttrs::Pixels => {
+                attrwriter.write_key("width").write_value(size.0);
+                attrwriter.write_key("height").write_value(size.1);
+            }
+            SizeAttrs::Percent(width, height) => {
+                attrwriter.write_key("width").write_value((width, '%'));
+                attrwriter.write_key("height").write_value((height, '%'));
+            }
+            SizeAttrs::Omitted => {}
+        }
+        attrwriter
+            .write_key("viewBox")
+            .write_value(("0 0 ", size.0, ' ', size.1));
+        if let Some(value) = self.preserve_aspect_ratio.as_deref() {
+            attrwriter
+                .write_key("preserveAspectRatio")
+                .write_value(value);
+        }
+        attrwriter
+            .write_key("xmlns")
+            .write_value("http://www.w3.org/2000/svg");
+        attrwriter.finish_without_closing();
+        buf
+    }
+
+    /// Write the `<svg>` tag again after its attributes have been changed
+    fn rewrite_svg_tag(&mut self) {
+        let start = self.svg_start;
+        let Some(tag_len) = self.target.get_mut()[start..].find(">\n") else {
+            return;
+        };
+        let tag = self.svg_tag();
+        self.splice_buffer(start..start + tag_len + 2, &tag);
+    }
+
+    fn with_target(target: Target<'a>, size: (u32, u32)) -> Self {
+        let mut ret = Self {
+            target,
+            size,
+            tag_stack: vec![],
+            saved: false,
+            interactive: false,
+            inline_tooltip_assets: true,
+            context_stack: vec![],
+            bbox_stack: vec![],
+            series_tags: vec![],
+            accessible: false,
+            svg_start: 0,
+            doc_title: None,
+            tooltip_template: None,
+            tooltip_mode: TooltipMode::Nearest,
+            tooltip_axis_names: vec![],
+            tooltip_axis_units: vec![],
+            flushed_series: 0,
+            late_series_attrs: vec![],
+            stream_error: None,
+            optimized_paths: false,
+            path_precision: 0,
+            subpixel_precision: None,
+            pending_path: None,
+            reuse_markers: false,
+            marker_ids: HashSet::new(),
+            paint_ids: HashSet::new(),
+            clipping: false,
+            clip_rects: Vec::new(),
+            clip_depths: Vec::new(),
+            text_outlines: false,
+            gzip: false,
+            css_classes: false,
+            stylesheet: None,
+            size_attrs: SizeAttrs::Pixels,
+            preserve_aspect_ratio: None,
+            #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+            jpeg_quality: None,
+        };
+
+        ret.init_svg_file();
+        ret
+    }
+
+    /// Omit the `width` and `height` of the document, so that a web page scales the chart to the
+    /// width of its container, keeping the aspect ratio given by the `viewBox`
+    pub fn with_responsive_size(mut self) -> Self {
+        self.size_attrs = SizeAttrs::Omitted;
+        self.rewrite_svg_tag();
+        self
+    }
+
+    /// Set the `width` and `height` of the document to percentages of its container, e.g.
+    /// `(100.0, 100.0)` to fill it
+    ///
+    /// How the chart is fit into a box which doesn't have its aspect ratio is chosen by
+    /// [with_preserve_aspect_ratio](Self::with_preserve_aspect_ratio).
+    pub fn with_percent_size(mut self, width: f64, height: f64) -> Self {
+        self.size_attrs = SizeAttrs::Percent(width, height);
+        self.rewrite_svg_tag();
+        self
+    }
+
+    /// Set the `preserveAspectRatio` of the document, e.g. `"xMinYMin meet"` or `"none"` to
+    /// stretch the chart to its box. The default of the viewers is `"xMidYMid meet"`.
+    pub fn with_preserve_aspect_ratio(mut self, value: &str) -> Self {
+        self.preserve_aspect_ratio = Some(value.to_string());
+        self.rewrite_svg_tag();
+        self
+    }
+
+    /// Create a new SVG drawing backend
+    ///
+    /// The file is compressed with gzip if its extension is `.svgz`, which needs the opt-in
+    /// `gzip` feature. Without it, presenting such a file fails.
+    pub fn new<T: AsRef<Path> + ?Sized>(path: &'a T, size: (u32, u32)) -> Self {
+        let path = path.as_ref();
+        let mut ret = Self::with_target(Target::File(String::default(), path), size);
+        ret.gzip = matches!(path.extension(), Some(ext) if ext.eq_ignore_ascii_case("svgz"));
+        ret
+    }
+
+    /// Create a new SVG drawing backend and store the document into a String buffer
+    pub fn with_string(buf: &'a mut String, size: (u32, u32)) -> Self {
+        Self::with_target(Target::Buffer(buf), size)
+    }
+
+    /// Create a new SVG drawing backend which streams the document into a writer
+    ///
+    /// Unlike the other targets, the document isn't kept in memory until `present()`: it is
+    /// written out in chunks as its tags are closed, which keeps the memory usage low for charts
+    /// with a huge number of elements. The tooltip assets are still appended at the end. Series
+    /// labels set after the series has been written out are emitted at the end of the document
+    /// too, where the tooltip script picks them up. The accessibility mode rewrites the whole
+    /// document at `present()`, thus it keeps the document in memory with this target as well.
+    ///
+    /// Errors of the writer are reported by `present()`.
+    pub fn with_writer<W: Write + 'a>(writer: W, size: (u32, u32)) -> Self {
+        Self::with_target(Target::Stream(String::default(), Box::new(writer)), size)
+    }
+
+    /// Add semantic classes to the shapes, so that the chart can be themed by the CSS of the host
+    /// page, e.g. for a dark mode
+    ///
+    /// The classes are derived from the element contexts: `plotters-bg`, `plotters-axis`,
+    /// `plotters-tick`, `plotters-grid-bold`, `plotters-grid-light`, `plotters-legend` and
+    /// `plotters-series-N` where `N` is the id of the series in its chart. The texts get the
+    /// `plotters-label` class, e.g. `plotters-axis plotters-label` for the tick labels.
+    /// The presentation attributes are kept, those are overridden by any CSS rule.
+    pub fn with_css_classes(mut self) -> Self {
+        self.css_classes = true;
+        self
+    }
+
+    /// Add semantic classes to the shapes as [with_css_classes](Self::with_css_classes) does, and
+    /// move their presentation attributes into a `<style>` block
+    ///
+    /// Each distinct set of attributes becomes a rule on a `plotters-sN` class. The rules use the
+    /// `:where()` selector, which isn't supported by every SVG renderer, thus this is meant for
+    /// documents shown by web browsers.
+    pub fn with_css_stylesheet(mut self) -> Self {
+        self.css_classes = true;
+        self.stylesheet = Some(StyleSheet::default());
+        self
+    }
+
+    /// Create a new SVG drawing backend and append the document to a byte buffer, e.g. for
+    /// serving it from an HTTP handler
+    pub fn with_bytes(buf: &'a mut Vec<u8>, size: (u32, u32)) -> Self {
+        Self::with_target(Target::Bytes(String::default(), buf), size)
+    }
+
+    /// Compress the document with gzip, i.e. produce an `.svgz` document
+    ///
+    /// This applies to the file and byte buffer targets, files with the `.svgz` extension are
+    /// compressed without it. The compression is done in-process at `present()`. It's only
+    /// available with the `gzip` feature, which isn't enabled by default.
+    #[cfg(feature = "gzip")]
+    pub fn with_gzip(mut self) -> Self {
+        self.gzip = true;
+        self
+    }
+
+    /// Enable interactive tooltips
+    ///
+    /// When enabled, `begin_context` / `end_context` calls emit `<g>` wrapper elements with
+    /// `data-*` attributes. A `<style>` and `<script>` block are injected at `present()` time to
+    /// drive mouse-hover tooltips. The entries of the series legend are linked to their series:
+    /// clicking an entry hides or shows the series, hovering it highlights the series.
+    pub fn with_tooltips(mut self) -> Self {
+        self.interactive = true;
+        self
+    }
+
+    /// Enable interactive tooltips, leaving the CSS and JavaScript out of the document
+    ///
+    /// Only the `data-*` markup and the tooltip container are emitted. This avoids one copy of
+    /// the assets per chart when a page embeds many charts, and allows pages with a strict
+    /// content security policy, which block inline scripts, to use the tooltips. The host page
+    /// should include [tooltip_stylesheet](crate::tooltip_stylesheet) and
+    /// [tooltip_script](crate::tooltip_script) once, the script then binds to every chart of the
+    /// document.
+    pub fn with_external_tooltip_assets(mut self) -> Self {
+        self.interactive = true;
+        self.inline_tooltip_assets = false;
+        self
+    }
+
+    /// Enable the optimized output of the shapes
+    ///
+    /// Lines, rectangles, polygons and circles are written as `<path>` elements with compact
+    /// relative commands, e.g. `d="M10 20l5-3h8"`, rather than one `<line>`, `<polyline>`,
+    /// `<rect>` or `<circle>` with absolute coordinates each. Consecutive opaque primitives with
+    /// the same style are merged into a single path. This renders identically and makes large
+    /// charts several times smaller.
+    pub fn with_optimized_paths(mut self) -> Self {
+        self.optimized_paths = true;
+        self
+    }
+
+    /// Set the number of decimals kept for the coordinates of the optimized paths, `0` by default
+    ///
+    /// This only has an effect when the optimized output is enabled with
+    /// [with_optimized_paths](SVGBackend::with_optimized_paths).
+    pub fn with_path_precision(mut self, decimals: usize) -> Self {
+        self.path_precision = decimals;
+        self
+    }
+
+    /// Keep the sub-pixel positions of the elements, with the given number of decimals
+    ///
+    /// By default, Plotters rounds every coordinate to a whole pixel, which makes lines of
+    /// dense data look jagged once the document is scaled up. With this option, the paths,
+    /// polygons, circles and texts are placed at their exact positions. The other elements, e.g.
+    /// the rectangles and the mesh, are still aligned to the pixels.
+    pub fn with_subpixel_precision(mut self, decimals: usize) -> Self {
+        self.subpixel_precision = Some(decimals);
+        self
+    }
+
+    /// Enable the reuse of identical markers
+    ///
+    /// The markers of point series, e.g. [Circle], [Cross] and [TriangleMarker], are defined
+    /// once per distinct shape, size and style in a `<defs>` element and each data point is drawn
+    /// as a `<use>` referencing it. This makes scatter plots much smaller and keeps the DOM small.
+    /// The ids of the definitions are derived from the shape and the style, thus documents
+    /// embedded into the same page don't conflict.
+    ///
+    /// [Circle]: https://docs.rs/plotters/latest/plotters/element/struct.Circle.html
+    /// [Cross]: https://docs.rs/plotters/latest/plotters/element/struct.Cross.html
+    /// [TriangleMarker]: https://docs.rs/plotters/latest/plotters/element/struct.TriangleMarker.html
+    pub fn with_marker_reuse(mut self) -> Self {
+        self.reuse_markers = true;
+        self
+    }
+
+    /// Clip the data series to their plotting area
+    ///
+    /// The series are wrapped in a `<g>` referencing a clip path of the plotting area, thus the
+    /// lines leaving it keep their slopes and the shapes crossing its edges are cut, rather than
+    /// having their coordinates truncated to the plotting area. The clip paths of identical
+    /// rectangles are shared.
+    pub fn with_clipping(mut self) -> Self {
+        self.clipping = true;
+        self
+    }
+
+    /// Render the texts as the outlines of their glyphs
+    ///
+    /// The texts are written as `<path>` elements traced from the glyphs of the font loaded by
+    /// Plotters, laid out exactly as for the bitmap backend, rather than as `<text>` elements
+    /// rendered with whatever font the viewer has. The chart then looks the same in every viewer,
+    /// including PDF converters. The text stays available to screen readers as the `aria-label`
+    /// of the path.
+    ///
+    /// This needs a font implementation which provides outlines, i.e. the `ttf` feature of
+    /// Plotters, otherwise the texts are written as `<text>` elements.
+    pub fn with_text_outlines(mut self) -> Self {
+        self.text_outlines = true;
+        self
+    }
+
+    /// Embed the bitmaps without an alpha channel as JPEG rather than PNG, which is much more
+    /// compact for photographic images
+    ///
+    /// - `quality`: The JPEG quality, from 1 to 100
+    ///
+    /// Bitmaps with transparent pixels are still embedded as PNG.
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    pub fn with_jpeg_bitmaps(mut self, quality: u8) -> Self {
+        self.jpeg_quality = Some(quality.clamp(1, 100));
+        self
+    }
+
+    /// Enable the accessibility mode
+    ///
+    /// When enabled, the element contexts reported by the chart are turned into ARIA roles,
+    /// `aria-label` attributes and `<title>` elements, which screen readers announce, e.g. a data
+    /// point is titled "Series sin(x), point x=1.2 y=0.93". The first caption drawn becomes the
+    /// `<title>` of the document and a `<desc>` lists the series. This is independent of the
+    /// tooltips and doesn't need any script.
+    pub fn with_accessibility(mut self) -> Self {
+        self.accessible = true;
+        self
+    }
+
+    /// Set how the tooltips pick the data they show, see [TooltipMode]
+    ///
+    /// This only has an effect when tooltips are enabled with
+    /// [with_tooltips](SVGBackend::with_tooltips).
+    pub fn with_tooltip_mode(mut self, mode: TooltipMode) -> Self {
+        self.tooltip_mode = mode;
+        self
+    }
+
+    /// Set the text template of the tooltips
+    ///
+    /// The template may contain the placeholders `{series}`, `{x}`, `{y}` and `{z}` for the
+    /// series label and the formatted values, `{x_name}`, `{y_name}` and `{z_name}` for the axis
+    /// names (see [with_tooltip_axis_name](SVGBackend::with_tooltip_axis_name)) and `{x_units}`,
+    /// `{y_units}` and `{z_units}` for the axis units. The metadata of a data point is available
+    /// by key, e.g. `{run_id}`, with keys lowercased and other characters than letters, digits,
+    /// `_` and `-` replaced by `-`. Each line of the template is rendered as a separate line of
+    /// the tooltip, lines which end up empty are omitted.
+    ///
+    /// For example `"{series}\n{y} {y_units} at {x}"`. Without a template, the tooltip shows the
+    /// series label in bold followed by the x, y (and z) values and one line per metadata entry.
+    ///
+    /// This only has an effect when tooltips are enabled with
+    /// [with_tooltips](SVGBackend::with_tooltips).
+    pub fn with_tooltip_template<S: Into<String>>(mut self, template: S) -> Self {
+        self.tooltip_template = Some(template.into());
+        self
+    }
+
+    /// Set the name of an axis shown by the tooltips, the axis id itself is used by default
+    ///
+    /// - `axis`: The axis id, `"x"`, `"y"` or `"z"`
+    /// - `name`: The display name of the axis
+    pub fn with_tooltip_axis_name(mut self, axis: &str, name: &str) -> Self {
+        Self::set_axis_entry(&mut self.tooltip_axis_names, axis, name);
+        self
+    }
+
+    /// Set the units of an axis shown by the tooltips
+    ///
+    /// By default, the units of the continuous interpolation of the axis are used if the chart
+    /// reports one.
+    ///
+    /// - `axis`: The axis id, `"x"`, `"y"` or `"z"`
+    /// - `units`: The units of the axis values
+    pub fn with_tooltip_axis_units(mut self, axis: &str, units: &str) -> Self {
+        Self::set_axis_entry(&mut self.tooltip_axis_units, axis, units);
+        self
+    }
+
+    fn set_axis_entry(entries: &mut Vec<(String, String)>, axis: &str, value: &str) {
+        entries.retain(|(id, _)| id != axis);
+        entries.push((axis.to_string(), value.to_string()));
+    }
+
+    /// Expand the current bounding-box tracker (if any) with a Coordinate.
+    fn track_coord(&mut self, coord: BackendCoord) {
+        if let Some(bb) = self.bbox_stack.last_mut() {
+            bb.expand_coord(coord);
+        }
+    }
+
+    /// Expand the current bounding-box tracker with a rectangle.
+    fn track_rect(&mut self, a: BackendCoord, b: BackendCoord) {
+        if let Some(bb) = self.bbox_stack.last_mut() {
+            bb.expand_rect(a, b);
+        }
+    }
+
+    /// Record the vertices of a drawn path or polygon, if the current context has no shape yet.
+    fn track_shape(&mut self, points: &[BackendCoord]) {
+        if let Some(bb) = self.bbox_stack.last_mut() {
+            if bb.shape.is_empty() {
+                bb.shape.extend_from_slice(points);
+            }
+        }
+    }
+
+    /// Record the colour of a drawn primitive for every active context which has none yet.
+    fn track_color(&mut self, color: BackendColor) {
+        for bb in self.bbox_stack.iter_mut().filter(|bb| bb.color.is_none()) {
+            bb.color = Some(color);
+        }
+    }
+
+    /// Replace a range of the buffer, keeping the offsets of the series tags in sync.
+    fn splice_buffer(&mut self, range: std::ops::Range<usize>, replacement: &str) {
+        let delta = replacement.len() as isize - range.len() as isize;
+        self.target
+            .get_mut()
+            .replace_range(range.clone(), replacement);
+
+        let shift = |offset: &mut usize| {
+            if *offset > range.start {
+                *offset = (*offset as isize + delta) as usize;
+            }
+        };
+        for tag in self.series_tags.iter_mut() {
+            shift(&mut tag.start);
+            if let Some(end) = tag.end.as_mut() {
+                shift(end);
+            }
+        }
+    }
+
+    /// Record the end of a series tag which has just been closed.
+    fn finish_series_tag(&mut self, ctx: &ElementContext) {
+        if let ElementContext::DataSeries { id, .. } = ctx {
+            let end = self.target.get_mut().len();
+            if let Some(tag) = self
+                .series_tags
+                .iter_mut()
+                .rev()
+                .find(|tag| tag.id == *id && tag.end.is_none())
+            {
+                tag.end = Some(end);
+            }
+        }
+    }
+
+    /// Find the latest emitted series with the id, series ids restart with every chart.
+    fn find_series_tag(&self, series_id: usize) -> Option<usize> {
+        self.series_tags.iter().rposition(|tag| tag.id == series_id)
+    }
+
+    /// Set an attribute on the `<g>` tag of an already emitted series, replacing the previous
+    /// value if there's one.
+    fn patch_series_attr(&mut self, series_id: usize, key: &str, value: impl FormatEscaped) {
+        let Some(idx) = self.find_series_tag(series_id) else {
+            return;
+        };
+        if idx < self.flushed_series {
+            let mut escaped = String::new();
+            FormatEscaped::format_escaped(&mut escaped, value);
+            match self
+                .late_series_attrs
+                .iter_mut()
+                .find(|(k, attr, _)| *k == idx && attr == key)
+            {
+                Some(entry) => entry.2 = escaped,
+                None => self.late_series_attrs.push((idx, key.to_string(), escaped)),
+            }
+            return;
+        }
+        let start = self.series_tags[idx].start;
+        let buf = self.target.get_mut();
+        // Attribute values are escaped, thus the first '>' is the end of the opening tag
+        let Some(tag_len) = buf[start..].find('>') else {
+            return;
+        };
+        let tag_end = start + tag_len;
+
+        let mut escaped = String::new();
+        FormatEscaped::format_escaped(&mut escaped, value);
+
+        let needle = format!(" {}=\"", key);
+        let (range, replacement) = match buf[start..tag_end].find(&needle) {
+            Some(pos) => {
+                let value_start = start + pos + needle.len();
+                let value_end = value_start + buf[value_start..tag_end].find('"').unwrap_or(0);
+                (value_start..value_end, escaped)
+            }
+            None => (tag_end..tag_end, format!("{}{}\"", needle, escaped)),
+        };
+        self.splice_buffer(range, &replacement);
+    }
+
+    /// Rename an already emitted series in accessibility mode, which rewrites the titles of the
+    /// series and of its elements, as they all start with the series name.
+    fn rename_series(&mut self, series_id: usize, name: String) {
+        let Some(idx) = self.find_series_tag(series_id) else {
+            return;
+        };
+        let old_name = std::mem::replace(&mut self.series_tags[idx].name, name.clone());
+        if old_name == name {
+            return;
+        }
+        let mut needle = "<title>".to_string();
+        Self::escape_and_push(&mut needle, &old_name);
+        let mut replacement = "<title>".to_string();
+        Self::escape_and_push(&mut replacement, &name);
+
+        let mut pos = self.series_tags[idx].start;
+        loop {
+            let end = self.series_tags[idx]
+                .end
+                .unwrap_or_else(|| self.target.get_mut().len());
+            let buf = self.target.get_mut();
+            let Some(found) = buf[pos..end].find(&needle) else {
+                break;
+            };
+            let found = pos + found;
+            let after = found + needle.len();
+            // Only whole names, "Series 1" must not match the title of "Series 10"
+            if matches!(buf[after..].chars().next(), Some('<') | Some(',')) {
+                self.splice_buffer(found..after, &replacement);
+                pos = found + replacement.len();
+            } else {
+                pos = after;
+            }
+        }
+    }
+
+    /// The accessible name of a series
+    fn series_name(id: usize, label: &str) -> String {
+        if label.is_empty() {
+            format!("Series {}", id + 1)
+        } else {
+            format!("Series {}", label)
+        }
+    }
+
+    /// The `<title>` text of an element in accessibility mode, `None` for elements which are
+    /// described by their `aria-label` or hidden.
+    fn accessible_title(&self, ctx: &ElementContext, series_key: Option<usize>) -> Option<String> {
+        let series_name = |series_id: usize| match self.series_tags.last() {
+            Some(tag) if tag.id == series_id && tag.end.is_none() => tag.name.clone(),
+            _ => Self::series_name(series_id, ""),
+        };
+        match ctx {
+            ElementContext::DataSeries { .. } => {
+                series_key.map(|key| self.series_tags[key].name.clone())
+            }
+            ElementContext::DataPoint {
+                x_label,
+                y_label,
+                z_label,
+                metadata,
+                series_id,
+                ..
+            } => {
+                let mut title = format!(
+                    "{}, point x={} y={}",
+                    series_name(*series_id),
+                    x_label,
+                    y_label
+                );
+                if let Some(z_label) = z_label {
+                    let _ = write!(title, " z={}", z_label);
+                }
+                for (key, value) in metadata {
+                    let _ = write!(title, ", {}={}", key, value);
+                }
+                Some(title)
+            }
+            ElementContext::DataLine {
+                x_interpolation,
+                series_id,
+                ..
+            } => {
+                let mut title = format!("{}, line", series_name(*series_id));
+                if let Interpolation::Discrete { points } = x_interpolation {
+                    if let (Some((_, first)), Some((_, last))) = (points.first(), points.last()) {
+                        let _ = write!(
+                            title,
+                            " with {} points from x={} to x={}",
+                            points.len(),
+                            first,
+                            last
+                        );
+                    }
+                }
+                Some(title)
+            }
+            _ => None,
+        }
+    }
+
+    /// Write the ARIA attributes of an element context.
+    fn write_aria_attrs(aw: &mut AttrWriter<'_, Init>, ctx: &ElementContext) {
+        match ctx {
+            // Decorations, the axes are summarized by their labels and the legend text is read
+            // on its own
+            ElementContext::Background
+            | ElementContext::Grid { .. }
+            | ElementContext::Tick { .. }
+            | ElementContext::LegendEntry { .. } => {
+                aw.write_key("aria-hidden").write_value("true");
+            }
+            ElementContext::Axis {
+                axis_id,
+                interpolation,
+            } => {
+                let mut label = format!("{} axis", axis_id);
+                match interpolation {
+                    Some(Interpolation::Continuous {
+                        value_range, units, ..
+                    }) => {
+                        let _ = write!(label, " from {} to {}", value_range.0, value_range.1);
+                        if !units.is_empty() {
+                            let _ = write!(label, " {}", units);
+                        }
+                    }
+                    Some(Interpolation::Discrete { points }) => {
+                        if let (Some((_, first)), Some((_, last))) = (points.first(), points.last())
+                        {
+                            let _ = write!(label, " from {} to {}", first, last);
+                        }
+                    }
+                    None => {}
+                }
+                aw.write_key("role").write_value("graphics-object");
+                aw.write_key("aria-label").write_value(label.as_str());
+            }
+            ElementContext::Label { .. } => {
+                aw.write_key("role").write_value("heading");
+            }
+            ElementContext::DataSeries { .. } => {
+                aw.write_key("role").write_value("graphics-object");
+                aw.write_key("aria-roledescription").write_value("series");
+            }
+            ElementContext::DataPoint { .. } | ElementContext::DataLine { .. } => {
+                aw.write_key("role").write_value("graphics-symbol");
+            }
+            _ => {}
+        }
+    }
+
+    /// Add the document role, title and description to the `<svg>` tag in accessibility mode.
+    fn inject_document_info(&mut self) {
+        let start = self.svg_start;
+        let buf = self.target.get_mut();
+        let Some(tag_len) = buf[start..].find('>') else {
+            return;
+        };
+        let tag_end = start + tag_len;
+
+        let mut info = String::new();
+        if let Some(title) = self.doc_title.as_deref() {
+            info.push_str("<title>");
+            Self::escape_and_push(&mut info, title);
+            info.push_str("</title>\n");
+        }
+        if !self.series_tags.is_empty() {
+            let names = self
+                .series_tags
+                .iter()
+                .map(|tag| tag.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let summary = format!(
+                "Chart with {} data series: {}",
+                self.series_tags.len(),
+                names
+            );
+            info.push_str("<desc>");
+            Self::escape_and_push(&mut info, &summary);
+            info.push_str("</desc>\n");
+        }
+        // After the line break ending the opening tag
+        buf.insert_str(tag_end + 2, &info);
+        buf.insert_str(tag_end, r#" role="graphics-document document""#);
+    }
+
+    /// Write the labels and the metadata of a data point context, which are read by the tooltip
+    /// script. Metadata keys are reduced to the characters allowed in attribute names.
+    fn write_point_attrs(aw: &mut AttrWriter<'_, Init>, ctx: &ElementContext) {
+        let ElementContext::DataPoint {
+            x_label,
+            y_label,
+            z_label,
+            metadata,
+            ..
+        } = ctx
+        else {
+            return;
+        };
+        aw.write_key("data-xl").write_value(x_label.as_str());
+        aw.write_key("data-yl").write_value(y_label.as_str());
+        if let Some(z_label) = z_label {
+            aw.write_key("data-zl").write_value(z_label.as_str());
+        }
+        for (key, value) in metadata {
+            let key: String = key
+                .chars()
+                .map(|c| match c {
+                    'a'..='z' | '0'..='9' | '_' | '-' => c,
+                    'A'..='Z' => c.to_ascii_lowercase(),
+                    _ => '-',
+                })
+                .collect();
+            aw.write_key(&format!("data-meta-{}", key))
+                .write_value(value.as_str());
+        }
+    }
+
+    /// Write a continuous interpolation as `"p0,p1,v0,v1"`, mapping the pixels `p0` and `p1` to the
+    /// values `v0` and `v1`. Other interpolations are skipped.
+    fn write_map_attr(aw: &mut AttrWriter<'_, Init>, key: &str, interpolation: &Interpolation) {
+        if let Interpolation::Continuous {
+            backend_range,
+            value_range,
+            ..
+        } = interpolation
+        {
+            aw.write_key(key).write_value((
+                backend_range.0,
+                ',',
+                backend_range.1,
+                ',',
+                value_range.0,
+                ',',
+                value_range.1,
+            ));
+        }
+    }
+
+    /// Inject the CSS and JavaScript needed for interactive tooltips.
+    ///
+    /// Called once from `present()` when `interactive` is enabled. The CSS and JavaScript are
+    /// left out when the host page provides them, only the tooltip container is emitted then.
+    fn inject_tooltip_assets(&mut self) {
+        // --- CSS ---
+        if self.inline_tooltip_assets {
+            let aw = self.open_tag(SVGTag::Style);
+            aw.finish_without_closing();
+            let buf = self.target.get_mut();
+            buf.push_str("<![CDATA[");
+            buf.push_str(tooltip_stylesheet());
+            buf.push_str("]]>");
+            self.close_tag(); // </style>
+        }
+
+        // --- Tooltip container ( hidden by default ) ---
+        // The tooltip configuration is carried by data attributes, so that the script doesn't
+        // depend on the chart
+        let template = self.tooltip_template.clone();
+        let mode = self.tooltip_mode;
+        let axis_attrs: Vec<_> = self
+            .tooltip_axis_names
+            .iter()
+            .map(|e| ("name", e))
+            .chain(self.tooltip_axis_units.iter().map(|e| ("units", e)))
+            .map(|(kind, (axis, value))| {
+                let axis: String = axis
+                    .chars()
+                    .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+                    .collect();
+                (format!("data-{}-{}", axis, kind), value.clone())
+            })
+            .collect();
+        let mut aw = self.open_tag(SVGTag::Group);
+        aw.write_key("class").write_value("plotters-tooltip");
+        if let Some(template) = &template {
+            aw.write_key("data-template").write_value(template.as_str());
+        }
+        if mode == TooltipMode::SharedX {
+            aw.write_key("data-mode").write_value("shared-x");
+        }
+        for (key, value) in &axis_attrs {
+            aw.write_key(key).write_value(value.as_str());
+        }
+        aw.finish_without_closing();
+        self.target.get_mut().push_str(
+            r#"<rect x="0" y="0" width="1" height="1"/>
+<text x="0" y="0"></text>
+"#,
+        );
+        self.close_tag(); // </g>
+
+        // --- JavaScript ---
+        if self.inline_tooltip_assets {
+            let aw = self.open_tag(SVGTag::Script);
+            aw.finish_without_closing();
+            let buf = self.target.get_mut();
+            buf.push_str("<![CDATA[");
+            buf.push_str(tooltip_script());
+            buf.push_str("]]>");
+            self.close_tag(); // </script>
+        }
+    }
+}
+
+impl<'a> DrawingBackend for SVGBackend<'a> {
+    type ErrorType = Error;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+        Ok(())
+    }
+
+    fn is_subpixel_aware(&self) -> bool {
+        self.subpixel_precision.is_some()
+    }
+
+    fn is_clip_aware(&self) -> bool {
+        self.clipping
+    }
+
+    fn push_clip_rect(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        let rect = (
+            (
+                upper_left.0.min(bottom_right.0),
+                upper_left.1.min(bottom_right.1),
+            ),
+            (
+                upper_left.0.max(bottom_right.0),
+                upper_left.1.max(bottom_right.1),
+            ),
+        );
+        let ((x0, y0), (x1, y1)) = rect;
+        let (width, height) = (x1 - x0 + 1, y1 - y0 + 1);
+        // The id is derived from the rectangle, so the documents inlined in the same page only
+        // share the clip paths of identical rectangles
+        let id = format!("plotters-clip-{}-{}-{}-{}", x0, y0, width, height);
+        if !self.clip_rects.contains(&rect) {
+            self.clip_rects.push(rect);
+            self.open_tag(SVGTag::Defs).finish_without_closing();
+            let mut aw = self.open_tag(SVGTag::ClipPath);
+            aw.write_key("id").write_value(id.as_str());
+            aw.finish_without_closing();
+            // Both corners are included, as they are by the pixels of the bitmaps
+            let mut aw = self.open_tag(SVGTag::Rectangle);
+            aw.write_key("x").write_value(x0);
+            aw.write_key("y").write_value(y0);
+            aw.write_key("width").write_value(width);
+            aw.write_key("height").write_value(height);
+            aw.close();
+            self.close_tag(); // </clipPath>
+            self.close_tag(); // </defs>
+        }
+        let mut aw = self.open_tag(SVGTag::Group);
+        aw.write_key("clip-path")
+            .write_value(("url(#", id.as_str(), ')'));
+        aw.finish_without_closing();
+        self.clip_depths.push(self.tag_stack.len());
+        Ok(())
+    }
+
+    fn pop_clip(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+        // The tags left open inside the clip group, e.g. by a failed drawing, are closed with it
+        if let Some(depth) = self.clip_depths.pop() {
+            while self.tag_stack.len() >= depth && self.close_tag() {}
+        }
+        Ok(())
+    }
+
+    fn is_context_aware(&self) -> bool {
+        self.interactive || self.accessible
+    }
+
+    fn begin_context(&mut self, ctx: ElementContext) {
+        if !self.interactive && !self.accessible {
+            self.context_stack.push(ctx);
+            return;
+        }
+        // The series offsets must not include a pending path
+        self.flush_path();
+        // The key linking legend entries to their series group
+        let mut series_key = None;
+        match &ctx {
+            ElementContext::DataSeries { id, label, .. } => {
+                series_key = Some(self.series_tags.len());
+                self.series_tags.push(SeriesTag {
+                    id: *id,
+                    start: self.target.get_mut().len(),
+                    end: None,
+                    name: Self::series_name(*id, label),
+                });
+            }
+            ElementContext::LegendEntry { series_id, .. } => {
+                series_key = self.find_series_tag(*series_id);
+            }
+            ElementContext::Label { text } if self.doc_title.is_none() => {
+                self.doc_title = Some(text.clone());
+            }
+            ElementContext::Axis {
+                axis_id,
+                interpolation: Some(Interpolation::Continuous { units, .. }),
+            } if !units.is_empty()
+                && !self.tooltip_axis_units.iter().any(|(id, _)| id == axis_id) =>
+            {
+                self.tooltip_axis_units
+                    .push((axis_id.clone(), units.clone()));
+            }
+            _ => {}
+        }
+        let title = if self.accessible {
+            self.accessible_title(&ctx, series_key)
+        } else {
+            None
+        };
+        // Open a <g> with data attributes derived from the context
+        let interactive = self.interactive;
+        let accessible = self.accessible;
+        let tooltip_mode = self.tooltip_mode;
+        let mut aw = self.open_tag(SVGTag::Group);
+        if accessible {
+            Self::write_aria_attrs(&mut aw, &ctx);
+        }
+        match &ctx {
+            _ if !interactive => {}
+            ElementContext::Background => {
+                aw.write_key("class").write_value("plotters-bg");
+            }
+            ElementContext::Axis {
+                axis_id,
+                interpolation,
+            } => {
+                aw.write_key("class").write_value("plotters-axis");
+                aw.write_key("data-axis").write_value(axis_id.as_str());
+                if let Some(Interpolation::Continuous { units, .. }) = interpolation {
+                    if !units.is_empty() {
+                        aw.write_key("data-units").write_value(units.as_str());
+                    }
+                }
+            }
+            ElementContext::Grid { bold } => {
+                aw.write_key("class").write_value(grid_class(*bold));
+            }
+            ElementContext::Tick {
+                axis_id,
+                position,
+                label,
+            } => {
+                aw.write_key("class").write_value("plotters-tick");
+                aw.write_key("data-axis").write_value(axis_id.as_str());
+                aw.write_key("data-pos").write_value(*position);
+                aw.write_key("data-label").write_value(label.as_str());
+            }
+            ElementContext::Label { text } => {
+                aw.write_key("class").write_value("plotters-label");
+                aw.write_key("data-text").write_value(text.as_str());
+            }
+            ElementContext::DataSeries {
+                id,
+                color,
+                label,
+                plot_area,
+                ..
+            } => {
+                aw.write_key("class").write_value("plotters-series");
+                aw.write_key("data-series-id").write_value(*id as i32);
+                if let Some(key) = series_key {
+                    aw.write_key("data-series-key").write_value(key as u32);
+                }
+                aw.write_key("data-series-label")
+                    .write_value(label.as_str());
+                if color.alpha > 0.0 {
+                    aw.write_key("data-series-color")
+                        .write_value(make_svg_color(*color));
+                }
+                // The crosshair covers the plotting area of the chart the series belongs to
+                if let (TooltipMode::SharedX, Some(((x0, y0), (x1, y1)))) =
+                    (tooltip_mode, plot_area)
+                {
+                    aw.write_key("data-plot-area").write_value((
+                        *x0,
+                        ',',
+                        *y0,
+                        ',',
+                        *x1 + 1,
+                        ',',
+                        *y1 + 1,
+                    ));
+                }
+            }
+            ElementContext::DataPoint {
+                coord, series_id, ..
+            } => {
+                aw.write_key("class").write_value("plotters-dp");
+                aw.write_key("data-x").write_value(coord.0);
+                aw.write_key("data-y").write_value(coord.1);
+                Self::write_point_attrs(&mut aw, &ctx);
+                aw.write_key("data-series-id")
+                    .write_value(*series_id as i32);
+            }
+            ElementContext::DataLine { series_id, .. } => {
+                aw.write_key("class").write_value("plotters-dl");
+                aw.write_key("data-series-id")
+                    .write_value(*series_id as i32);
+            }
+            ElementContext::LegendEntry {
+                series_id, label, ..
+            } => {
+                aw.write_key("class").write_value("plotters-legend-entry");
+                aw.write_key("data-series-id")
+                    .write_value(*series_id as i32);
+                if let Some(key) = series_key {
+                    aw.write_key("data-series-key").write_value(key as u32);
+                }
+                aw.write_key("data-series-label")
+                    .write_value(label.as_str());
+            }
+            _ => {}
+        }
+        aw.finish_without_closing();
+        if let Some(title) = title {
+            let buf = self.target.get_mut();
+            buf.push_str("<title>");
+            Self::escape_and_push(buf, &title);
+            buf.push_str("</title>\n");
+        }
+        self.bbox_stack.push(BBoxTracker::default());
+        self.context_stack.push(ctx);
+    }
+
+    fn end_context(&mut self) {
+        let Some(ctx) = self.context_stack.pop() else {
+            return;
+        };
+        let bbox = self.bbox_stack.pop().unwrap_or_default();
+        if !self.interactive {
+            if self.accessible {
+                self.close_tag();
+                self.finish_series_tag(&ctx);
+            }
+            return;
+        }
+        // The series colour wasn't known up front, take the one actually drawn
+        if let ElementContext::DataSeries { id, color, .. } = &ctx {
+            if let Some(color) = bbox.color.filter(|_| color.alpha == 0.0) {
+                self.patch_series_attr(*id, "data-series-color", make_svg_color(color));
+            }
+        }
+
+        // For DataPoint contexts, emit a hover target covering the drawn bounding box ( so complex
+        // elements like boxplots are fully hoverable).
+        if let ElementContext::DataPoint { coord, .. } = &ctx {
+            // Highlight ring at the nominal coordinate
+            let mut ring = self.open_tag(SVGTag::Circle);
+            ring.write_key("class").write_value("plotters-dp-ring");
+            ring.write_key("cx").write_value(coord.0);
+            ring.write_key("cy").write_value(coord.1);
+            ring.write_key("r").write_value(6u32);
+            ring.write_key("fill").write_value("none");
+            ring.write_key("stroke").write_value("#333");
+            ring.write_key("stroke-width").write_value(2i32);
+            ring.close();
+            if bbox.any {
+                // Use a bounding-box rectangle as the hover target
+                let pad = 4;
+                let mut aw = self.open_tag(SVGTag::Rectangle);
+                aw.write_key("class").write_value("plotters-dp-hover");
+                aw.write_key("x").write_value(bbox.min_x - pad);
+                aw.write_key("y").write_value(bbox.min_y - pad);
+                aw.write_key("width")
+                    .write_value(bbox.max_x - bbox.min_x + pad * 2);
+                aw.write_key("height")
+                    .write_value(bbox.max_y - bbox.min_y + pad * 2);
+                aw.write_key("fill").write_value("transparent");
+                aw.write_key("data-cx").write_value(coord.0);
+                aw.write_key("data-cy").write_value(coord.1);
+                Self::write_point_attrs(&mut aw, &ctx);
+                aw.close();
+            } else {
+                // Fallback: small circle at the nominal coordinate
+                let mut aw = self.open_tag(SVGTag::Circle);
+                aw.write_key("class").write_value("plotters-dp-hover");
+                aw.write_key("cx").write_value(coord.0);
+                aw.write_key("cy").write_value(coord.1);
+                aw.write_key("r").write_value(8i32);
+                aw.write_key("fill").write_value("transparent");
+                Self::write_point_attrs(&mut aw, &ctx);
+                aw.close();
+            }
+        }
+
+        // For LegendEntry contexts, cover the whole row, including the label text, with a target
+        // for clicks and hovering
+        if let ElementContext::LegendEntry { area, .. } = &ctx {
+            let mut aw = self.open_tag(SVGTag::Rectangle);
+            aw.write_key("class").write_value("plotters-legend-hit");
+            aw.write_key("x").write_value(area.0 .0.min(area.1 .0));
+            aw.write_key("y").write_value(area.0 .1.min(area.1 .1));
+            aw.write_key("width")
+                .write_value((area.1 .0 - area.0 .0).abs());
+            aw.write_key("height")
+                .write_value((area.1 .1 - area.0 .1).abs());
+            aw.write_key("fill").write_value("transparent");
+            aw.close();
+        }
+
+        // For Axis contexts with a continuous mapping, emit a hover target over the axis so the
+        // value under the cursor can be read out.
+        if let ElementContext::Axis {
+            axis_id,
+            interpolation: Some(interpolation @ Interpolation::Continuous { .. }),
+        } = &ctx
+        {
+            if bbox.any {
+                let pad = 2;
+                let mut aw = self.open_tag(SVGTag::Rectangle);
+                aw.write_key("class").write_value("plotters-axis-hover");
+                aw.write_key("x").write_value(bbox.min_x - pad);
+                aw.write_key("y").write_value(bbox.min_y - pad);
+                aw.write_key("width")
+                    .write_value(bbox.max_x - bbox.min_x + pad * 2);
+                aw.write_key("height")
+                    .write_value(bbox.max_y - bbox.min_y + pad * 2);
+                aw.write_key("fill").write_value("transparent");
+                aw.write_key("data-axis").write_value(axis_id.as_str());
+                Self::write_map_attr(&mut aw, "data-map", interpolation);
+                aw.close();
+            }
+        }
+
+        // For DataLine contexts, emit an invisible hover target. If the shape is a closed polygon
+        // (first point == last point in the bounding data), use a filled polygon so the interior is
+        // hoverable. Otherwise, use a wide-stroke polyline.
+        if let ElementContext::DataLine {
+            x_interpolation,
+            y_interpolation,
+            z_interpolation,
+            continuous_interpolation,
             ..
         } = &ctx
         {
-            // Highlight ring at the nominal coordinate
-            let mut ring = self.open_tag(SVGTag::Circle);
-            ring.write_key("class").write_value("plotters-dp-ring");
-            ring.write_key("cx").write_value(coord.0);
-            ring.write_key("cy").write_value(coord.1);
-            ring.write_key("r").write_value(6u32);
-            ring.write_key("fill").write_value("none");
-            ring.write_key("stroke").write_value("#333");
-            ring.write_key("stroke-width").write_value(2i32);
-            ring.close();
-            if bbox.any {
-                // Use a bounding-box rectangle as the hover target
-                let pad = 4;
-                let mut aw = self.open_tag(SVGTag::Rectangle);
-                aw.write_key("class").write_value("plotters-dp-hover");
-                aw.write_key("x").write_value(bbox.min_x - pad);
-                aw.write_key("y").write_value(bbox.min_y - pad);
-                aw.write_key("width")
-                    .write_value(bbox.max_x - bbox.min_x + pad * 2);
-                aw.write_key("height")
-                    .write_value(bbox.max_y - bbox.min_y + pad * 2);
-                aw.write_key("fill").write_value("transparent");
-                aw.write_key("data-cx").write_value(coord.0);
-                aw.write_key("data-cy").write_value(coord.1);
-                aw.write_key("data-xl").write_value(x_label.as_str());
-                aw.write_key("data-yl").write_value(y_label.as_str());
-                if !series_label.is_empty() {
-                    aw.write_key("data-sl").write_value(series_label.as_str());
+            // The vertices of the line and the vertex data for the tooltip JS
+            let (coords, pts_data): (Vec<BackendCoord>, String) =
+                match (x_interpolation, y_interpolation) {
+                    (
+                        Interpolation::Discrete { points: xpts },
+                        Interpolation::Discrete { points: ypts },
+                    ) if xpts.len() == ypts.len() => {
+                        let zpts = match z_interpolation {
+                            Some(Interpolation::Discrete { points })
+                                if points.len() == xpts.len() =>
+                            {
+                                &points[..]
+                            }
+                            _ => &[],
+                        };
+                        // The z label is only present for 3D charts
+                        let pts_data = xpts
+                            .iter()
+                            .zip(ypts.iter())
+                            .enumerate()
+                            .map(|(idx, ((xp, xl), (yp, yl)))| match zpts.get(idx) {
+                                Some((_, zl)) => format!("{},{},{},{},{}", xp, yp, xl, yl, zl),
+                                None => format!("{},{},{},{}", xp, yp, xl, yl),
+                            })
+                            .collect::<Vec<_>>()
+                            .join(";");
+                        let coords = xpts
+                            .iter()
+                            .zip(ypts.iter())
+                            .map(|((xp, _), (yp, _))| (*xp, *yp))
+                            .collect();
+                        (coords, pts_data)
+                    }
+                    _ => (vec![], String::new()),
+                };
+
+            if coords.len() >= 2 {
+                let pts_str: String = coords
+                    .iter()
+                    .map(|(x, y)| format!("{},{}", x, y))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+
+                let first = coords.first().unwrap();
+                let last = coords.last().unwrap();
+                let is_closed = (first.0 - last.0).abs() <= 2 && (first.1 - last.1).abs() <= 2;
+                let use_fill = bbox.has_fill || is_closed;
+
+                // Check if the bounding box of drawn content extends significantly beyond the
+                // polyline path, indicating a complex element (e.g. boxplot) whose hover target
+                // should cover the full rendered area.
+                let path_min_x = coords.iter().map(|c| c.0).min().unwrap();
+                let path_max_x = coords.iter().map(|c| c.0).max().unwrap();
+                let path_min_y = coords.iter().map(|c| c.1).min().unwrap();
+                let path_max_y = coords.iter().map(|c| c.1).max().unwrap();
+                let extend_threshold = 6;
+                // A filled element described by two vertices is a rectangle, a polygon through
+                // them would have no area to hover
+                let use_bbox_rect = bbox.any
+                    && ((coords.len() == 2 && bbox.has_fill)
+                        || (bbox.min_x < path_min_x - extend_threshold)
+                        || (bbox.max_x > path_max_x + extend_threshold)
+                        || (bbox.min_y < path_min_y - extend_threshold)
+                        || (bbox.max_y > path_max_y + extend_threshold));
+
+                // Only paths are evaluated between their vertices, areas and boxes show the
+                // labels of the nearest vertex
+                let mut maps = None;
+                let mut aw = if use_bbox_rect {
+                    // Bounding-box rectangle hover target (boxplots, etc.)
+                    let pad = 4;
+                    let mut aw = self.open_tag(SVGTag::Rectangle);
+                    aw.write_key("class").write_value("plotters-dl-hover");
+                    aw.write_key("x").write_value(bbox.min_x - pad);
+                    aw.write_key("y").write_value(bbox.min_y - pad);
+                    aw.write_key("width")
+                        .write_value(bbox.max_x - bbox.min_x + pad * 2);
+                    aw.write_key("height")
+                        .write_value(bbox.max_y - bbox.min_y + pad * 2);
+                    aw.write_key("fill").write_value("transparent");
+                    aw
+                } else if use_fill {
+                    // Filled polygon hover target (area charts)
+                    let mut aw = self.open_tag(SVGTag::Polygon);
+                    aw.write_key("class").write_value("plotters-dl-hover");
+                    aw.write_key("fill").write_value("transparent");
+                    aw.write_key("stroke").write_value("none");
+                    aw.write_key("points").write_value(pts_str.as_str());
+                    aw
+                } else {
+                    // Wide-stroke polyline hover target (line charts)
+                    let mut aw = self.open_tag(SVGTag::Polyline);
+                    aw.write_key("class").write_value("plotters-dl-hover");
+                    aw.write_key("fill").write_value("none");
+                    aw.write_key("stroke").write_value("transparent");
+                    aw.write_key("stroke-width").write_value(12i32);
+                    aw.write_key("points").write_value(pts_str.as_str());
+                    maps = continuous_interpolation.as_ref();
+                    aw
+                };
+                aw.write_key("data-pts").write_value(pts_data.as_str());
+                if let Some((x_map, y_map)) = maps {
+                    Self::write_map_attr(&mut aw, "data-xmap", x_map);
+                    Self::write_map_attr(&mut aw, "data-ymap", y_map);
+                }
+                aw.close();
+            }
+        }
+        // Close the <g>
+        self.close_tag();
+        self.finish_series_tag(&ctx);
+    }
+
+    fn update_context(&mut self, ctx: ElementContext) {
+        if let ElementContext::DataSeries {
+            id, color, label, ..
+        } = ctx
+        {
+            if self.interactive {
+                self.patch_series_attr(id, "data-series-label", label.as_str());
+                if color.alpha > 0.0 {
+                    self.patch_series_attr(id, "data-series-color", make_svg_color(color));
+                }
+            }
+            if self.accessible {
+                self.rename_series(id, Self::series_name(id, &label));
+            }
+        }
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+        if !self.saved {
+            self.write_stylesheet();
+            if self.interactive {
+                self.write_late_series_attrs();
+                self.inject_tooltip_assets();
+            }
+            while self.close_tag() {}
+            if self.accessible {
+                self.inject_document_info();
+            }
+            match self.target {
+                Target::File(ref buf, path) => {
+                    let outfile = File::create(path).map_err(DrawingErrorKind::DrawingError)?;
+                    write_document(BufWriter::new(outfile), buf, self.gzip)
+                        .map_err(DrawingErrorKind::DrawingError)?;
+                }
+                Target::Bytes(ref buf, ref mut out) => {
+                    write_document(&mut **out, buf, self.gzip)
+                        .map_err(DrawingErrorKind::DrawingError)?;
+                }
+                Target::Buffer(_) => {}
+                Target::Stream(ref mut buf, ref mut out) => {
+                    if let Some(e) = self.stream_error.take() {
+                        self.saved = true;
+                        return Err(DrawingErrorKind::DrawingError(e));
+                    }
+                    out.write_all(buf.as_bytes())
+                        .and_then(|_| out.flush())
+                        .map_err(DrawingErrorKind::DrawingError)?;
+                    buf.clear();
+                }
+            }
+            self.saved = true;
+        }
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        if color.alpha == 0.0 {
+            return Ok(());
+        }
+        if self.optimized_paths {
+            let corner = (point.0 + 1, point.1 + 1);
+            let bounds = path_bounds([to_f64(point), to_f64(corner)], 0.0);
+            self.add_to_path(PathStyle::Fill(color.rgb), color.alpha, bounds, |d| {
+                d.rect(to_f64(point), to_f64(corner))
+            });
+        } else {
+            let mut attrwriter = self.open_shape_tag(SVGTag::Rectangle);
+            attrwriter.write_key("x").write_value(point.0);
+            attrwriter.write_key("y").write_value(point.1);
+            attrwriter.write_key("width").write_value("1");
+            attrwriter.write_key("height").write_value("1");
+            attrwriter.write_key("stroke").write_value("none");
+            attrwriter.write_key("opacity").write_value(color.alpha);
+            attrwriter
+                .write_key("fill")
+                .write_value(make_svg_color(color));
+            attrwriter.close();
+        }
+        self.track_coord(point);
+        self.track_color(color);
+        Ok(())
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.write_line(to_f64(from), to_f64(to), style)
+    }
+
+    fn draw_line_f64<S: BackendStyle>(
+        &mut self,
+        from: BackendFloatCoord,
+        to: BackendFloatCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.write_line(from, to, style)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.color().alpha == 0.0 {
+            return Ok(());
+        }
+        let is_filled = fill;
+        let paint_id = if fill { self.define_paint(style) } else { None };
+        let opacity = if paint_id.is_some() {
+            1.0
+        } else {
+            style.color().alpha
+        };
+
+        let color = make_svg_color(style.color());
+        let (fill, stroke) = match (fill, paint_id) {
+            (false, _) => (None, Some(color)),
+            (true, Some(id)) => (Some(Fill::Paint(id)), None),
+            (true, None) => (Some(Fill::Color(color)), None),
+        };
+        let is_painted = matches!(fill, Some(Fill::Paint(_)));
+
+        if self.optimized_paths && !is_painted {
+            // Unfilled rectangles are stroked with the default width
+            let (path_style, margin) = if is_filled {
+                (PathStyle::Fill(style.color().rgb), 0.0)
+            } else {
+                (
+                    PathStyle::Stroke(style.color().rgb, 1, StrokeDetails::of(style)),
+                    0.5,
+                )
+            };
+            let bounds = path_bounds([to_f64(upper_left), to_f64(bottom_right)], margin);
+            self.add_to_path(path_style, style.color().alpha, bounds, |d| {
+                d.rect(to_f64(upper_left), to_f64(bottom_right))
+            });
+        } else {
+            let mut attrwriter = self.open_shape_tag(SVGTag::Rectangle);
+            attrwriter.write_key("x").write_value(upper_left.0);
+            attrwriter.write_key("y").write_value(upper_left.1);
+            attrwriter
+                .write_key("width")
+                .write_value(bottom_right.0 - upper_left.0);
+            attrwriter
+                .write_key("height")
+                .write_value(bottom_right.1 - upper_left.1);
+            attrwriter.write_key("opacity").write_value(opacity);
+            attrwriter.write_key("fill").write_value(fill);
+            attrwriter.write_key("stroke").write_value(stroke);
+            if !is_filled {
+                StrokeDetails::of(style).write_attrs(&mut attrwriter);
+            }
+            attrwriter.close();
+        }
+        if let Some(bb) = self.bbox_stack.last_mut() {
+            bb.expand_rect(upper_left, bottom_right);
+            if is_filled {
+                bb.has_fill = true;
+            }
+        }
+        self.track_color(style.color());
+        Ok(())
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.write_path(path.into_iter().map(to_f64), style)
+    }
+
+    fn draw_path_f64<S: BackendStyle, I: IntoIterator<Item = BackendFloatCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.write_path(path, style)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.write_polygon(path.into_iter().map(to_f64), style)
+    }
+
+    fn fill_polygon_f64<S: BackendStyle, I: IntoIterator<Item = BackendFloatCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.write_polygon(path, style)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.write_circle(to_f64(center), f64::from(radius), style, fill)
+    }
+
+    fn draw_circle_f64<S: BackendStyle>(
+        &mut self,
+        center: BackendFloatCoord,
+        radius: f64,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        // The circle markers are drawn with this method on a sub-pixel aware backend
+        if self.reuse_markers
+            && radius.fract() == 0.0
+            && radius <= f64::from(i32::MAX)
+            && StrokeDetails::of(style).is_default()
+        {
+            let shape = MarkerShape::Circle { filled: fill };
+            return self.use_marker(center, shape, radius as i32, style);
+        }
+        self.write_circle(center, radius, style, fill)
+    }
+
+    fn draw_marker<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        shape: MarkerShape,
+        size: i32,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        // The definitions of the markers only keep the colour and the width of the stroke
+        if !self.reuse_markers || !StrokeDetails::of(style).is_default() {
+            return rasterizer::draw_marker(self, center, shape, size, style);
+        }
+        self.use_marker(to_f64(center), shape, size, style)
+    }
+
+    fn draw_text<S: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &S,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.write_text(text, style, to_f64(pos))
+    }
+
+    fn draw_text_f64<S: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &S,
+        pos: BackendFloatCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.write_text(text, style, pos)
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.embed_bitmap(pos, size, src, image::ExtendedColorType::Rgb8)
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    fn blit_bitmap_rgba(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        // JPEG has no alpha channel, but opaque images can still use it
+        if self.jpeg_quality.is_some() && src.chunks_exact(4).all(|pixel| pixel[3] == 255) {
+            let rgb: Vec<_> = src
+                .chunks_exact(4)
+                .flat_map(|pixel| [pixel[0], pixel[1], pixel[2]])
+                .collect();
+            return self.embed_bitmap(pos, size, &rgb, image::ExtendedColorType::Rgb8);
+        }
+        self.embed_bitmap(pos, size, src, image::ExtendedColorType::Rgba8)
+    }
+}
+
+impl Drop for SVGBackend<'_> {
+    fn drop(&mut self) {
+        if !self.saved {
+            // drop should not panic, so we ignore a failed present
+            let _ = self.present();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use plotters::element::{Circle, Cross, Text, TriangleMarker};
+    use plotters::prelude::{
+        ChartBuilder, Color, IntoDrawingArea, IntoFont, LineSeries, PathElement, Polygon,
+        Rectangle, SeriesLabelPosition, ShapeStyle, TextStyle, BLACK, BLUE, GREEN, RED, WHITE,
+    };
+    use plotters::style::text_anchor::{HPos, Pos, VPos};
+    use std::fs;
+    use std::path::Path;
+
+    static DST_DIR: &str = "target/test/svg";
+
+    fn checked_save_file(name: &str, content: &str) {
+        /*
+          Please use the SVG file to manually verify the results.
+        */
+        assert!(!content.is_empty());
+        fs::create_dir_all(DST_DIR).unwrap();
+        let file_name = format!("{}.svg", name);
+        let file_path = Path::new(DST_DIR).join(file_name);
+        println!("{:?} created", file_path);
+        fs::write(file_path, &content).unwrap();
+    }
+
+    fn draw_mesh_with_custom_ticks(tick_size: i32, test_name: &str) {
+        let mut content: String = Default::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (500, 500)).into_drawing_area();
+
+            let mut chart = ChartBuilder::on(&root)
+                .caption("This is a test", ("sans-serif", 20u32))
+                .set_all_label_area_size(40u32)
+                .build_cartesian_2d(0..10, 0..10)
+                .unwrap();
+
+            chart
+                .configure_mesh()
+                .set_all_tick_mark_size(tick_size)
+                .draw()
+                .unwrap();
+        }
+
+        checked_save_file(test_name, &content);
+
+        assert!(content.contains("This is a test"));
+    }
+
+    #[test]
+    fn test_draw_mesh_no_ticks() {
+        draw_mesh_with_custom_ticks(0, "test_draw_mesh_no_ticks");
+    }
+
+    #[test]
+    fn test_draw_mesh_negative_ticks() {
+        draw_mesh_with_custom_ticks(-10, "test_draw_mesh_negative_ticks");
+    }
+
+    #[test]
+    fn test_text_alignments() {
+        let mut content: String = Default::default();
+        {
+            let mut root = SVGBackend::with_string(&mut content, (500, 500));
+
+            let style = TextStyle::from(("sans-serif", 20).into_font())
+                .pos(Pos::new(HPos::Right, VPos::Top));
+            root.draw_text("right-align", &style, (150, 50)).unwrap();
+
+            let style = style.pos(Pos::new(HPos::Center, VPos::Top));
+            root.draw_text("center-align", &style, (150, 150)).unwrap();
+
+            let style = style.pos(Pos::new(HPos::Left, VPos::Top));
+            root.draw_text("left-align", &style, (150, 200)).unwrap();
+        }
+
+        checked_save_file("test_text_alignments", &content);
+
+        for svg_line in content.split("</text>") {
+            if let Some(anchor_and_rest) = svg_line.split("text-anchor=\"").nth(1) {
+                if anchor_and_rest.starts_with("end") {
+                    assert!(anchor_and_rest.contains("right-align"))
                 }
-                aw.close();
-            } else {
-                // Fallback: small circle at the nominal coordinate
-                let mut aw = self.open_tag(SVGTag::Circle);
-                aw.write_key("class").write_value("plotters-dp-hover");
-                aw.write_key("cx").write_value(coord.0);
-                aw.write_key("cy").write_value(coord.1);
-                aw.write_key("r").write_value(8i32);
-                aw.write_key("fill").write_value("transparent");
-                aw.write_key("data-xl").write_value(x_label.as_str());
-                aw.write_key("data-yl").write_value(y_label.as_str());
-                if !series_label.is_empty() {
-                    aw.write_key("data-sl").write_value(series_label.as_str());
+                if anchor_and_rest.starts_with("middle") {
+                    assert!(anchor_and_rest.contains("center-align"))
                 }
-                aw.close();
+                if anchor_and_rest.starts_with("start") {
+                    assert!(anchor_and_rest.contains("left-align"))
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_text_draw() {
+        let mut content: String = Default::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (1500, 800)).into_drawing_area();
+            let root = root
+                .titled("Image Title", ("sans-serif", 60).into_font())
+                .unwrap();
+
+            let mut chart = ChartBuilder::on(&root)
+                .caption("All anchor point positions", ("sans-serif", 20u32))
+                .set_all_label_area_size(40u32)
+                .build_cartesian_2d(0..100i32, 0..50i32)
+                .unwrap();
+
+            chart
+                .configure_mesh()
+                .disable_x_mesh()
+                .disable_y_mesh()
+                .x_desc("X Axis")
+                .y_desc("Y Axis")
+                .draw()
+                .unwrap();
+
+            let ((x1, y1), (x2, y2), (x3, y3)) = ((-30, 30), (0, -30), (30, 30));
+
+            for (dy, trans) in [
+                FontTransform::None,
+                FontTransform::Rotate90,
+                FontTransform::Rotate180,
+                FontTransform::Rotate270,
+            ]
+            .iter()
+            .enumerate()
+            {
+                for (dx1, h_pos) in [HPos::Left, HPos::Right, HPos::Center].iter().enumerate() {
+                    for (dx2, v_pos) in [VPos::Top, VPos::Center, VPos::Bottom].iter().enumerate() {
+                        let x = 150_i32 + (dx1 as i32 * 3 + dx2 as i32) * 150;
+                        let y = 120 + dy as i32 * 150;
+                        let draw = |x, y, text| {
+                            root.draw(&Circle::new((x, y), 3, &BLACK.mix(0.5))).unwrap();
+                            let style = TextStyle::from(("sans-serif", 20).into_font())
+                                .pos(Pos::new(*h_pos, *v_pos))
+                                .transform(trans.clone());
+                            root.draw_text(text, &style, (x, y)).unwrap();
+                        };
+                        draw(x + x1, y + y1, "dood");
+                        draw(x + x2, y + y2, "dog");
+                        draw(x + x3, y + y3, "goog");
+                    }
+                }
+            }
+        }
+
+        checked_save_file("test_text_draw", &content);
+
+        assert_eq!(content.matches("dog").count(), 36);
+        assert_eq!(content.matches("dood").count(), 36);
+        assert_eq!(content.matches("goog").count(), 36);
+    }
+
+    #[test]
+    fn test_text_clipping() {
+        let mut content: String = Default::default();
+        {
+            let (width, height) = (500_i32, 500_i32);
+            let root = SVGBackend::with_string(&mut content, (width as u32, height as u32))
+                .into_drawing_area();
+
+            let style = TextStyle::from(("sans-serif", 20).into_font())
+                .pos(Pos::new(HPos::Center, VPos::Center));
+            root.draw_text("TOP LEFT", &style, (0, 0)).unwrap();
+            root.draw_text("TOP CENTER", &style, (width / 2, 0))
+                .unwrap();
+            root.draw_text("TOP RIGHT", &style, (width, 0)).unwrap();
+
+            root.draw_text("MIDDLE LEFT", &style, (0, height / 2))
+                .unwrap();
+            root.draw_text("MIDDLE RIGHT", &style, (width, height / 2))
+                .unwrap();
+
+            root.draw_text("BOTTOM LEFT", &style, (0, height)).unwrap();
+            root.draw_text("BOTTOM CENTER", &style, (width / 2, height))
+                .unwrap();
+            root.draw_text("BOTTOM RIGHT", &style, (width, height))
+                .unwrap();
+        }
+
+        checked_save_file("test_text_clipping", &content);
+    }
+
+    #[test]
+    fn test_series_labels() {
+        let mut content = String::default();
+        {
+            let (width, height) = (500, 500);
+            let root = SVGBackend::with_string(&mut content, (width, height)).into_drawing_area();
+
+            let mut chart = ChartBuilder::on(&root)
+                .caption("All series label positions", ("sans-serif", 20u32))
+                .set_all_label_area_size(40u32)
+                .build_cartesian_2d(0..50i32, 0..50i32)
+                .unwrap();
+
+            chart
+                .configure_mesh()
+                .disable_x_mesh()
+                .disable_y_mesh()
+                .draw()
+                .unwrap();
+
+            chart
+                .draw_series(std::iter::once(Circle::new((5, 15), 5u32, &RED)))
+                .expect("Drawing error")
+                .label("Series 1")
+                .legend(|(x, y)| Circle::new((x, y), 3u32, RED.filled()));
+
+            chart
+                .draw_series(std::iter::once(Circle::new((5, 15), 10u32, &BLUE)))
+                .expect("Drawing error")
+                .label("Series 2")
+                .legend(|(x, y)| Circle::new((x, y), 3u32, BLUE.filled()));
+
+            for pos in vec![
+                SeriesLabelPosition::UpperLeft,
+                SeriesLabelPosition::MiddleLeft,
+                SeriesLabelPosition::LowerLeft,
+                SeriesLabelPosition::UpperMiddle,
+                SeriesLabelPosition::MiddleMiddle,
+                SeriesLabelPosition::LowerMiddle,
+                SeriesLabelPosition::UpperRight,
+                SeriesLabelPosition::MiddleRight,
+                SeriesLabelPosition::LowerRight,
+                SeriesLabelPosition::Coordinate(70, 70),
+            ]
+            .into_iter()
+            {
+                chart
+                    .configure_series_labels()
+                    .border_style(&BLACK.mix(0.5))
+                    .position(pos)
+                    .draw()
+                    .expect("Drawing error");
             }
         }
 
-        // For DataLine contexts, emit an invisible hover target. If the shape is a closed polygon
-        // (first point == last point in the bounding data), use a filled polygon so the interior is
-        // hoverable. Otherwise, use a wide-stroke polyline.
-        if let ElementContext::DataLine {
-            x_interpolation,
-            y_interpolation,
-            ..
-        } = &ctx
+        checked_save_file("test_series_labels", &content);
+    }
+
+    #[test]
+    fn test_series_contexts_with_tooltips() {
+        let mut content = String::default();
         {
-            if let (
-                plotters_backend::Interpolation::Discrete { points: xpts },
-                plotters_backend::Interpolation::Discrete { points: ypts },
-            ) = (x_interpolation, y_interpolation)
-            {
-                if xpts.len() >= 2 && xpts.len() == ypts.len() {
-                    // Encode vertex data for the tooltip JS
-                    let pts_data = xpts
-                        .iter()
-                        .zip(ypts.iter())
-                        .map(|((xp, xl), (yp, yl))| format!("{},{},{},{}", xp, yp, xl, yl))
-                        .collect::<Vec<_>>()
-                        .join(";");
-                    let coords: Vec<(i32, i32)> = xpts
-                        .iter()
-                        .zip(ypts.iter())
-                        .map(|((xp, _), (yp, _))| (*xp, *yp))
-                        .collect();
+            let root = SVGBackend::with_string(&mut content, (300, 300))
+                .with_tooltips()
+                .into_drawing_area();
 
-                    let pts_str: String = coords
-                        .iter()
-                        .map(|(x, y)| format!("{},{}", x, y))
-                        .collect::<Vec<_>>()
-                        .join(" ");
-
-                    let first = coords.first().unwrap();
-                    let last = coords.last().unwrap();
-                    let is_closed = (first.0 - last.0).abs() <= 2 && (first.1 - last.1).abs() <= 2;
-                    let use_fill = bbox.has_fill || is_closed;
-
-                    // Check if the bounding box of drawn content extends significantly beyond the
-                    // polyline path, indicating a complex element (e.g. boxplot) whose hover target
-                    // should cover the full rendered area.
-                    let path_min_x = coords.iter().map(|c| c.0).min().unwrap();
-                    let path_max_x = coords.iter().map(|c| c.0).max().unwrap();
-                    let path_min_y = coords.iter().map(|c| c.1).min().unwrap();
-                    let path_max_y = coords.iter().map(|c| c.1).max().unwrap();
-                    let extend_threshold = 6;
-                    let use_bbox_rect = bbox.any
-                        && ((bbox.min_x < path_min_x - extend_threshold)
-                            || (bbox.max_x > path_max_x + extend_threshold)
-                            || (bbox.min_y < path_min_y - extend_threshold)
-                            || (bbox.max_y > path_max_y + extend_threshold));
-
-                    if use_bbox_rect {
-                        // Bounding-box rectangle hover target (boxplots, etc.)
-                        let pad = 4;
-                        let mut aw = self.open_tag(SVGTag::Rectangle);
-                        aw.write_key("class").write_value("plotters-dl-hover");
-                        aw.write_key("x").write_value(bbox.min_x - pad);
-                        aw.write_key("y").write_value(bbox.min_y - pad);
-                        aw.write_key("width")
-                            .write_value(bbox.max_x - bbox.min_x + pad * 2);
-                        aw.write_key("height")
-                            .write_value(bbox.max_y - bbox.min_y + pad * 2);
-                        aw.write_key("fill").write_value("transparent");
-                        aw.write_key("data-pts").write_value(pts_data.as_str());
-                        if !series_label.is_empty() {
-                            aw.write_key("data-sl").write_value(series_label.as_str());
-                        }
-                        aw.close();
-                    } else if use_fill {
-                        // Filled polygon hover target (area charts)
-                        let mut aw = self.open_tag(SVGTag::Polygon);
-                        aw.write_key("class").write_value("plotters-dl-hover");
-                        aw.write_key("fill").write_value("transparent");
-                        aw.write_key("stroke").write_value("none");
-                        aw.write_key("points").write_value(pts_str.as_str());
-                        aw.write_key("data-pts").write_value(pts_data.as_str());
-                        if !series_label.is_empty() {
-                            aw.write_key("data-sl").write_value(series_label.as_str());
-                        }
-                        aw.close();
-                    } else {
-                        // Wide-stroke polyline hover target (line charts)
-                        let mut aw = self.open_tag(SVGTag::Polyline);
-                        aw.write_key("class").write_value("plotters-dl-hover");
-                        aw.write_key("fill").write_value("none");
-                        aw.write_key("stroke").write_value("transparent");
-                        aw.write_key("stroke-width").write_value(12i32);
-                        aw.write_key("points").write_value(pts_str.as_str());
-                        aw.write_key("data-pts").write_value(pts_data.as_str());
-                        if !series_label.is_empty() {
-                            aw.write_key("data-sl").write_value(series_label.as_str());
-                        }
-                        aw.close();
-                    }
-                }
-            }
+            let mut chart = ChartBuilder::on(&root)
+                .set_all_label_area_size(30u32)
+                .build_cartesian_2d(0..10i32, 0..10i32)
+                .unwrap();
+
+            chart.configure_mesh().draw().unwrap();
+
+            chart
+                .draw_series(std::iter::once(Circle::new((5, 7), 5u32, RED.filled())))
+                .unwrap()
+                .label("Late label");
         }
-        // Clone the <g>
-        self.close_tag();
+
+        checked_save_file("test_series_contexts_with_tooltips", &content);
+
+        assert!(content.contains(r#"data-series-label="Late label""#));
+        assert!(content.contains(r##"data-series-color="#FF0000""##));
+        assert!(content.contains(r#"data-xl="5" data-yl="7""#));
     }
 
-    fn present(&mut self) -> Result<(), DrawingErrorKind<Error>> {
-        if !self.saved {
-            if self.interactive {
-                self.inject_tooltip_assets();
+    #[test]
+    fn test_streamed_document() {
+        struct Chunks<'a>(&'a mut Vec<Vec<u8>>);
+        impl Write for Chunks<'_> {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.push(buf.to_vec());
+                Ok(buf.len())
             }
-            while self.close_tag() {}
-            match self.target {
-                Target::File(ref buf, path) => {
-                    let outfile = File::create(path).map_err(DrawingErrorKind::DrawingError)?;
-                    let mut outfile = BufWriter::new(outfile);
-                    outfile
-                        .write_all(buf.as_ref())
-                        .map_err(DrawingErrorKind::DrawingError)?;
-                }
-                Target::Buffer(_) => {}
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
             }
-            self.saved = true;
         }
-        Ok(())
-    }
 
-    fn draw_pixel(
-        &mut self,
-        point: BackendCoord,
-        color: BackendColor,
-    ) -> Result<(), DrawingErrorKind<Error>> {
-        if color.alpha == 0.0 {
-            return Ok(());
-        }
-        let mut attrwriter = self.open_tag(SVGTag::Rectangle);
-        attrwriter.write_key("x").write_value(point.0);
-        attrwriter.write_key("y").write_value(point.1);
-        attrwriter.write_key("width").write_value("1");
-        attrwriter.write_key("height").write_value("1");
-        attrwriter.write_key("stroke").write_value("none");
-        attrwriter.write_key("opacity").write_value(color.alpha);
-        attrwriter
-            .write_key("fill")
-            .write_value(make_svg_color(color));
-        attrwriter.close();
-        self.track_coord(point);
-        Ok(())
-    }
+        let mut chunks = vec![];
+        {
+            let root = SVGBackend::with_writer(Chunks(&mut chunks), (300, 300))
+                .with_tooltips()
+                .into_drawing_area();
 
-    fn draw_line<S: BackendStyle>(
-        &mut self,
-        from: BackendCoord,
-        to: BackendCoord,
-        style: &S,
-    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        if style.color().alpha == 0.0 {
-            return Ok(());
+            let mut chart = ChartBuilder::on(&root)
+                .set_all_label_area_size(30u32)
+                .build_cartesian_2d(0..100i32, 0..100i32)
+                .unwrap();
+
+            chart.configure_mesh().draw().unwrap();
+            chart
+                .draw_series(
+                    (0..100).flat_map(|x| (0..20).map(move |y| Circle::new((x, y), 2u32, RED))),
+                )
+                .unwrap()
+                .label("Dots");
+            root.present().unwrap();
         }
-        let mut attrwriter = self.open_tag(SVGTag::Line);
-        attrwriter
-            .write_key("opacity")
-            .write_value(style.color().alpha);
-        attrwriter
-            .write_key("stroke")
-            .write_value(make_svg_color(style.color()));
-        attrwriter
-            .write_key("stroke-width")
-            .write_value(style.stroke_width());
-        attrwriter.write_key("x1").write_value(from.0);
-        attrwriter.write_key("y1").write_value(from.1);
-        attrwriter.write_key("x2").write_value(to.0);
-        attrwriter.write_key("y2").write_value(to.1);
-        attrwriter.close();
-        self.track_rect(from, to);
-        Ok(())
+
+        assert!(chunks.len() > 2);
+        assert!(chunks
+            .iter()
+            .all(|chunk| chunk.len() < 2 * STREAM_CHUNK_SIZE));
+        let content = String::from_utf8(chunks.concat()).unwrap();
+        checked_save_file("test_streamed_document", &content);
+
+        assert!(content.starts_with("<svg"));
+        assert!(content.ends_with("</svg>\n"));
+        assert!(content.contains(r#"data-series-key="0" data-series-label="""#));
+        assert!(content.contains(
+            r##"<g class="plotters-series-attrs" data-series-key="0" data-series-color="#FF0000" data-series-label="Dots"/>"##
+        ));
+        assert!(content.contains("<script"));
     }
 
-    fn draw_rect<S: BackendStyle>(
-        &mut self,
-        upper_left: BackendCoord,
-        bottom_right: BackendCoord,
-        style: &S,
-        fill: bool,
-    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        if style.color().alpha == 0.0 {
-            return Ok(());
-        }
-        let is_filled = fill;
+    #[test]
+    fn test_external_tooltip_assets() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (300, 300))
+                .with_external_tooltip_assets()
+                .into_drawing_area();
 
-        let color = make_svg_color(style.color());
-        let (fill, stroke) = if !fill {
-            (None, Some(color))
-        } else {
-            (Some(color), None)
-        };
+            let mut chart = ChartBuilder::on(&root)
+                .set_all_label_area_size(30u32)
+                .build_cartesian_2d(0..10i32, 0..10i32)
+                .unwrap();
 
-        let mut attrwriter = self.open_tag(SVGTag::Rectangle);
-        attrwriter.write_key("x").write_value(upper_left.0);
-        attrwriter.write_key("y").write_value(upper_left.1);
-        attrwriter
-            .write_key("width")
-            .write_value(bottom_right.0 - upper_left.0);
-        attrwriter
-            .write_key("height")
-            .write_value(bottom_right.1 - upper_left.1);
-        attrwriter
-            .write_key("opacity")
-            .write_value(style.color().alpha);
-        attrwriter.write_key("fill").write_value(fill);
-        attrwriter.write_key("stroke").write_value(stroke);
-        attrwriter.close();
-        if let Some(bb) = self.bbox_stack.last_mut() {
-            bb.expand_rect(upper_left, bottom_right);
-            if is_filled {
-                bb.has_fill = true;
-            }
+            chart.configure_mesh().draw().unwrap();
+            chart
+                .draw_series(std::iter::once(Circle::new((5, 7), 5u32, RED.filled())))
+                .unwrap();
         }
-        Ok(())
-    }
 
-    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
-        &mut self,
-        path: I,
-        style: &S,
-    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        if style.color().alpha == 0.0 {
-            return Ok(());
-        }
-        let path_points: Vec<_> = path.into_iter().collect();
-        let mut attrwriter = self.open_tag(SVGTag::Polyline);
-        attrwriter.write_key("fill").write_value("none");
-        attrwriter
-            .write_key("opacity")
-            .write_value(style.color().alpha);
-        attrwriter
-            .write_key("stroke")
-            .write_value(make_svg_color(style.color()));
-        attrwriter
-            .write_key("stroke-width")
-            .write_value(style.stroke_width());
-        attrwriter
-            .write_key("points")
-            .write_value(FormatEscapedIter(
-                path_points.iter().map(|c| (c.0, ',', c.1, ' ')),
-            ));
-        attrwriter.close();
-        for &pt in &path_points {
-            self.track_coord(pt);
-        }
-        Ok(())
+        assert!(content.contains(r#"data-xl="5" data-yl="7""#));
+        assert!(content.contains(r#"<g class="plotters-tooltip""#));
+        assert!(!content.contains("<style"));
+        assert!(!content.contains("<script"));
+
+        let html = format!(
+            "<html><head><style>{}</style><script>{}</script></head><body>{}{}</body></html>",
+            crate::tooltip_stylesheet(),
+            crate::tooltip_script(),
+            content,
+            content
+        );
+        fs::create_dir_all(DST_DIR).unwrap();
+        fs::write(
+            Path::new(DST_DIR).join("test_external_tooltip_assets.html"),
+            html,
+        )
+        .unwrap();
+        assert!(crate::tooltip_script().contains("document.querySelectorAll(\"svg\")"));
     }
 
-    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
-        &mut self,
-        path: I,
-        style: &S,
-    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        if style.color().alpha == 0.0 {
-            return Ok(());
-        }
-        let poly_points: Vec<_> = path.into_iter().collect();
-        let mut attrwriter = self.open_tag(SVGTag::Polygon);
-        attrwriter
-            .write_key("opacity")
-            .write_value(style.color().alpha);
-        attrwriter
-            .write_key("fill")
-            .write_value(make_svg_color(style.color()));
-        attrwriter
-            .write_key("points")
-            .write_value(FormatEscapedIter(
-                poly_points.iter().map(|c| (c.0, ',', c.1, ' ')),
-            ));
-        attrwriter.close();
-        if let Some(bb) = self.bbox_stack.last_mut() {
-            for &pt in &poly_points {
-                bb.expand_coord(pt);
-            }
-            bb.has_fill = true;
-        }
+    #[test]
+    fn test_3d_tooltips() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (300, 300))
+                .with_tooltips()
+                .into_drawing_area();
 
-        Ok(())
-    }
+            let mut chart = ChartBuilder::on(&root)
+                .build_cartesian_3d(0..10i32, 0..10i32, 0..10i32)
+                .unwrap();
 
-    fn draw_circle<S: BackendStyle>(
-        &mut self,
-        center: BackendCoord,
-        radius: u32,
-        style: &S,
-        fill: bool,
-    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        if style.color().alpha == 0.0 {
-            return Ok(());
+            chart.configure_axes().draw().unwrap();
+
+            chart
+                .draw_series_with_tooltips(
+                    std::iter::once(Circle::new((1, 2, 3), 5u32, RED.filled())),
+                    &RED,
+                    "Points",
+                )
+                .unwrap();
+            chart
+                .draw_series_with_tooltips(
+                    LineSeries::new(vec![(0, 0, 0), (1, 1, 4)], &BLUE),
+                    &BLUE,
+                    "Line",
+                )
+                .unwrap();
         }
-        let color = make_svg_color(style.color());
-        let (stroke, fill) = if !fill {
-            (Some(color), None)
-        } else {
-            (None, Some(color))
-        };
-        let mut attrwriter = self.open_tag(SVGTag::Circle);
-        attrwriter.write_key("cx").write_value(center.0);
-        attrwriter.write_key("cy").write_value(center.1);
-        attrwriter.write_key("r").write_value(radius);
-        attrwriter
-            .write_key("opacity")
-            .write_value(style.color().alpha);
-        attrwriter.write_key("fill").write_value(fill);
-        attrwriter.write_key("stroke").write_value(stroke);
-        attrwriter
-            .write_key("stroke-width")
-            .write_value(style.stroke_width());
-        attrwriter.close();
-        self.track_rect(
-            (center.0 - radius as i32, center.1 - radius as i32),
-            (center.0 + radius as i32, center.1 + radius as i32),
-        );
-        Ok(())
+
+        checked_save_file("test_3d_tooltips", &content);
+
+        assert!(content.contains(r#"data-xl="1" data-yl="2" data-zl="3""#));
+        assert!(content.contains(",1,1,4\""));
     }
 
-    fn draw_text<S: BackendTextStyle>(
-        &mut self,
-        text: &str,
-        style: &S,
-        pos: BackendCoord,
-    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        let color = style.color();
-        if color.alpha == 0.0 {
-            return Ok(());
+    #[test]
+    fn test_tooltip_template() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (300, 300))
+                .with_tooltips()
+                .with_tooltip_template("{series}\n{y} {y_units} at {x_name} {x}")
+                .with_tooltip_axis_name("x", "Time")
+                .with_tooltip_axis_units("y", "m/s")
+                .into_drawing_area();
+
+            let mut chart = ChartBuilder::on(&root)
+                .set_all_label_area_size(30u32)
+                .build_cartesian_2d(0..10i32, 0..10i32)
+                .unwrap();
+
+            chart.configure_mesh().draw().unwrap();
+            chart
+                .draw_series_with_tooltips(
+                    std::iter::once(Circle::new((5, 7), 5u32, RED.filled())),
+                    &RED,
+                    "Speed",
+                )
+                .unwrap();
         }
 
-        let (x0, y0) = pos;
-        let text_anchor = match style.anchor().h_pos {
-            HPos::Left => "start",
-            HPos::Right => "end",
-            HPos::Center => "middle",
-        };
+        checked_save_file("test_tooltip_template", &content);
 
-        let dy = match style.anchor().v_pos {
-            VPos::Top => "0.76em",
-            VPos::Center => "0.5ex",
-            VPos::Bottom => "-0.5ex",
-        };
+        assert!(content.contains(
+            r#"class="plotters-tooltip" data-template="{series}&#10;{y} {y_units} at {x_name} {x}" data-x-name="Time" data-y-units="m/s">"#
+        ));
+    }
 
-        #[cfg(feature = "debug")]
+    #[test]
+    fn test_accessibility() {
+        let mut content = String::default();
         {
-            let ((fx0, fy0), (fx1, fy1)) =
-                font.layout_box(text).map_err(DrawingErrorKind::FontError)?;
-            let x0 = match style.anchor().h_pos {
-                HPos::Left => x0,
-                HPos::Center => x0 - fx1 / 2 + fx0 / 2,
-                HPos::Right => x0 - fx1 + fx0,
-            };
-            let y0 = match style.anchor().v_pos {
-                VPos::Top => y0,
-                VPos::Center => y0 - fy1 / 2 + fy0 / 2,
-                VPos::Bottom => y0 - fy1 + fy0,
-            };
-            self.draw_rect(
-                (x0, y0),
-                (x0 + fx1 - fx0, y0 + fy1 - fy0),
-                &crate::prelude::RED,
-                false,
-            )
-            .unwrap();
-            self.draw_circle((x0, y0), 2, &crate::prelude::RED, false)
+            let root = SVGBackend::with_string(&mut content, (300, 300))
+                .with_accessibility()
+                .into_drawing_area();
+
+            let mut chart = ChartBuilder::on(&root)
+                .caption("Speed & time", ("sans-serif", 20))
+                .set_all_label_area_size(30u32)
+                .build_cartesian_2d(0..10i32, 0..10i32)
                 .unwrap();
+
+            chart.configure_mesh().draw().unwrap();
+            // The label is only known after the series is drawn
+            chart
+                .draw_series(std::iter::once(Circle::new((5, 7), 5u32, RED.filled())))
+                .unwrap()
+                .label("sin(x)");
         }
 
-        let mut attrwriter = self.open_tag(SVGTag::Text);
-        attrwriter.write_key("x").write_value(x0);
-        attrwriter.write_key("y").write_value(y0);
-        attrwriter.write_key("dy").write_value(dy);
-        attrwriter.write_key("text-anchor").write_value(text_anchor);
-        attrwriter
-            .write_key("font-family")
-            .write_value(style.family().as_str());
-        attrwriter
-            .write_key("font-size")
-            .write_value(style.size() / 1.24);
-        attrwriter.write_key("opacity").write_value(color.alpha);
-        attrwriter
-            .write_key("fill")
-            .write_value(make_svg_color(color));
+        checked_save_file("test_accessibility", &content);
+
+        assert!(content.contains(
+            "role=\"graphics-document document\">\n<title>Speed &amp; time</title>\n<desc>Chart with 1 data series: Series sin(x)</desc>"
+        ));
+        assert!(content.contains(r#"<g role="heading">"#));
+        assert!(content.contains(r#"<g role="graphics-object" aria-label="x axis from 0 to 10">"#));
+        assert!(content.contains(
+            "<g role=\"graphics-object\" aria-roledescription=\"series\">\n<title>Series sin(x)</title>"
+        ));
+        assert!(content
+            .contains("<g role=\"graphics-symbol\">\n<title>Series sin(x), point x=5 y=7</title>"));
+        // Independent of the tooltips
+        assert!(!content.contains("<script"));
+        assert!(!content.contains("data-series-id"));
+    }
 
-        match style.style() {
-            FontStyle::Normal => {}
-            FontStyle::Bold => {
-                attrwriter.write_key("font-weight").write_value("bold");
-            }
-            other_style => {
-                attrwriter
-                    .write_key("font-style")
-                    .write_value(other_style.as_str());
-            }
-        };
+    #[test]
+    fn test_point_metadata() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (300, 300))
+                .with_tooltips()
+                .into_drawing_area();
 
-        let trans = style.transform();
-        match trans {
-            FontTransform::Rotate90 => {
-                attrwriter
-                    .write_key("transform")
-                    .write_value(("rotate(90, ", x0, ", ", y0, ')'));
-            }
-            FontTransform::Rotate180 => {
-                attrwriter
-                    .write_key("transform")
-                    .write_value(("rotate(180, ", x0, ", ", y0, ')'));
-            }
-            FontTransform::Rotate270 => {
-                attrwriter
-                    .write_key("transform")
-                    .write_value(("rotate(270, ", x0, ", ", y0, ')'));
-            }
-            _ => {}
-        }
-        attrwriter.finish_without_closing();
+            let mut chart = ChartBuilder::on(&root)
+                .set_all_label_area_size(30u32)
+                .build_cartesian_2d(0..10i32, 0..10i32)
+                .unwrap();
 
-        Self::escape_and_push(self.target.get_mut(), text);
-        self.target.get_mut().push('\n');
+            chart.configure_mesh().draw().unwrap();
+            chart
+                .draw_series_with_metadata(
+                    std::iter::once(Circle::new((5, 7), 5u32, RED.filled())),
+                    &RED,
+                    "Runs",
+                    |&(x, _)| {
+                        vec![
+                            ("Run Id".to_string(), format!("run-{}", x)),
+                            (
+                                "url".to_string(),
+                                "https://example.com/?a=1&b=2".to_string(),
+                            ),
+                        ]
+                    },
+                )
+                .unwrap();
+        }
 
-        self.close_tag();
+        checked_save_file("test_point_metadata", &content);
 
-        Ok(())
+        assert!(content.contains(
+            r#"data-meta-run-id="run-5" data-meta-url="https://example.com/?a=1&amp;b=2""#
+        ));
     }
 
-    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
-    fn blit_bitmap(
-        &mut self,
-        pos: BackendCoord,
-        (w, h): (u32, u32),
-        src: &[u8],
-    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        use image::codecs::png::PngEncoder;
-        use image::ImageEncoder;
+    #[test]
+    fn test_continuous_tooltips() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (300, 300))
+                .with_tooltips()
+                .into_drawing_area();
 
-        let mut data = vec![0; 0];
+            let mut chart = ChartBuilder::on(&root)
+                .set_all_label_area_size(30u32)
+                .build_cartesian_2d(0.0..10.0, 0.0..1.0)
+                .unwrap();
 
-        {
-            let cursor = Cursor::new(&mut data);
+            chart.configure_mesh().draw().unwrap();
+            chart
+                .draw_series_with_tooltips(
+                    LineSeries::new(vec![(0.0, 0.0), (5.0, 1.0), (10.0, 0.5)], &BLUE),
+                    &BLUE,
+                    "Line",
+                )
+                .unwrap();
+        }
 
-            let encoder = PngEncoder::new(cursor);
+        checked_save_file("test_continuous_tooltips", &content);
 
-            let color = image::ColorType::Rgb8;
+        // The line hover target carries the vertices with their labels and both value mappings
+        assert!(content.contains(
+            r#"data-pts="30,269,0.0,0.0;149,30,5.0,1.0;269,150,10.0,0.5" data-xmap="30,269,0,10" data-ymap="269,30,0,1""#
+        ));
+        assert!(content.contains(r#"class="plotters-axis-hover""#));
+        assert!(content.contains(r#"data-axis="y" data-map="269,30,0,1""#));
+    }
 
-            encoder.write_image(src, w, h, color).map_err(|e| {
-                DrawingErrorKind::DrawingError(Error::new(
-                    std::io::ErrorKind::Other,
-                    format!("Image error: {}", e),
-                ))
-            })?;
+    #[test]
+    fn test_bar_tooltips() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (300, 300))
+                .with_tooltips()
+                .into_drawing_area();
+
+            let mut chart = ChartBuilder::on(&root)
+                .set_all_label_area_size(30u32)
+                .build_cartesian_2d(0..10i32, 0..10i32)
+                .unwrap();
+
+            chart.configure_mesh().draw().unwrap();
+            chart
+                .draw_series_with_tooltips(
+                    (1..4).map(|x| Rectangle::new([(x * 2, 0), (x * 2 + 1, x * 2)], BLUE.filled())),
+                    &BLUE,
+                    "Bars",
+                )
+                .unwrap();
         }
 
-        let padding = (3 - data.len() % 3) % 3;
-        data.resize(data.len() + padding, 0);
+        checked_save_file("test_bar_tooltips", &content);
 
-        let mut rem_bits = 0;
-        let mut rem_num = 0;
+        // Every bar gets a hover target labelled with the formatted integer values, the
+        // continuous mappings are only used for paths
+        assert_eq!(content.matches(r#"class="plotters-dl-hover""#).count(), 3);
+        assert!(content.contains(r#"data-pts="77,269,2,0;101,222,3,2""#));
+        assert!(!content.contains("data-xmap="));
+    }
 
-        fn cvt_base64(from: u8) -> char {
-            (if from < 26 {
-                b'A' + from
-            } else if from < 52 {
-                b'a' + from - 26
-            } else if from < 62 {
-                b'0' + from - 52
-            } else if from == 62 {
-                b'+'
-            } else {
-                b'/'
-            })
-            .into()
+    #[test]
+    fn test_shared_x_tooltips() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (300, 300))
+                .with_tooltips()
+                .with_tooltip_mode(TooltipMode::SharedX)
+                .into_drawing_area();
+
+            let mut chart = ChartBuilder::on(&root)
+                .set_all_label_area_size(30u32)
+                .build_cartesian_2d(0..10i32, 0..10i32)
+                .unwrap();
+
+            chart.configure_mesh().draw().unwrap();
+            for (idx, color) in [RED, BLUE].iter().enumerate() {
+                chart
+                    .draw_series_with_tooltips(
+                        LineSeries::new((0..10).map(|x| (x, x / (idx as i32 + 1))), color),
+                        color,
+                        &format!("Series {}", idx),
+                    )
+                    .unwrap();
+            }
         }
 
-        let mut buf = String::new();
-        buf.push_str("data:png;base64,");
+        checked_save_file("test_shared_x_tooltips", &content);
 
-        for byte in data {
-            let value = (rem_bits << (6 - rem_num)) | (byte >> (rem_num + 2));
-            rem_bits = byte & ((1 << (2 + rem_num)) - 1);
-            rem_num += 2;
+        // The crosshair covers the plotting area, inside the label areas
+        assert!(content.contains(r#"class="plotters-tooltip" data-mode="shared-x""#));
+        assert_eq!(
+            content.matches(r#"data-plot-area="30,30,270,270""#).count(),
+            2
+        );
+        assert!(content.contains(r#"data-series-label="Series 1""#));
+    }
 
-            buf.push(cvt_base64(value));
-            if rem_num == 6 {
-                buf.push(cvt_base64(rem_bits));
-                rem_bits = 0;
-                rem_num = 0;
+    #[test]
+    fn test_shared_x_with_legend() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (600, 300))
+                .with_tooltips()
+                .with_tooltip_mode(TooltipMode::SharedX)
+                .into_drawing_area();
+
+            for area in root.split_evenly((1, 2)) {
+                let mut chart = ChartBuilder::on(&area)
+                    .build_cartesian_2d(0..10i32, 0..10i32)
+                    .unwrap();
+                chart
+                    .draw_series(LineSeries::new((0..10).map(|x| (x, x)), &RED))
+                    .unwrap()
+                    .label("Line")
+                    .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+                chart.configure_series_labels().draw().unwrap();
             }
         }
 
-        for _ in 0..padding {
-            buf.pop();
-            buf.push('=');
+        checked_save_file("test_shared_x_with_legend", &content);
+
+        // Every chart has its own crosshair, over its own plotting area
+        assert!(content.contains(
+            r#"class="plotters-series" data-series-id="0" data-series-key="0" data-series-label="Line" data-plot-area="0,0,300,300""#
+        ));
+        assert!(content.contains(
+            r#"class="plotters-series" data-series-id="0" data-series-key="1" data-series-label="Line" data-plot-area="300,0,600,300""#
+        ));
+        // The script puts the crosshair of a chart below its legend entries, which stay clickable
+        let tooltip = content.find(r#"class="plotters-tooltip""#).unwrap();
+        for key in 0..2 {
+            let entry = content
+                .find(&format!(
+                    r#"class="plotters-legend-entry" data-series-id="0" data-series-key="{}""#,
+                    key
+                ))
+                .unwrap();
+            assert!(entry < tooltip);
         }
+        assert!(tooltip_script().contains("before.parentNode.insertBefore(overlay, before)"));
+    }
 
-        let mut attrwriter = self.open_tag(SVGTag::Image);
-        attrwriter.write_key("x").write_value(pos.0);
-        attrwriter.write_key("y").write_value(pos.1);
-        attrwriter.write_key("width").write_value(w);
-        attrwriter.write_key("height").write_value(h);
-        attrwriter.write_key("href").write_value(buf.as_str());
-        attrwriter.close();
+    #[test]
+    fn test_legend_entries() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (600, 300))
+                .with_tooltips()
+                .into_drawing_area();
 
-        Ok(())
-    }
-}
+            // Both charts use the series id 0, the keys tell them apart
+            for area in root.split_evenly((1, 2)) {
+                let mut chart = ChartBuilder::on(&area)
+                    .build_cartesian_2d(0..10i32, 0..10i32)
+                    .unwrap();
+                chart
+                    .draw_series(LineSeries::new((0..10).map(|x| (x, x)), &RED))
+                    .unwrap()
+                    .label("Line")
+                    .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+                chart.configure_series_labels().draw().unwrap();
+            }
+        }
 
-impl Drop for SVGBackend<'_> {
-    fn drop(&mut self) {
-        if !self.saved {
-            // drop should not panic, so we ignore a failed present
-            let _ = self.present();
+        checked_save_file("test_legend_entries", &content);
+
+        for key in 0..2 {
+            assert!(content.contains(&format!(
+                r#"class="plotters-series" data-series-id="0" data-series-key="{}""#,
+                key
+            )));
+            assert!(content.contains(&format!(
+                r#"class="plotters-legend-entry" data-series-id="0" data-series-key="{}" data-series-label="Line""#,
+                key
+            )));
         }
+        assert!(content.contains(r#"class="plotters-legend-hit""#));
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use plotters::element::Circle;
-    use plotters::prelude::{
-        ChartBuilder, Color, IntoDrawingArea, IntoFont, SeriesLabelPosition, TextStyle, BLACK,
-        BLUE, RED, WHITE,
-    };
-    use plotters::style::text_anchor::{HPos, Pos, VPos};
-    use std::fs;
-    use std::path::Path;
+    #[test]
+    fn test_draw_pixel_alphas() {
+        let mut content = String::default();
+        {
+            let (width, height) = (100_i32, 100_i32);
+            let root = SVGBackend::with_string(&mut content, (width as u32, height as u32))
+                .into_drawing_area();
+            root.fill(&WHITE).unwrap();
 
-    static DST_DIR: &str = "target/test/svg";
+            for i in -20..20 {
+                let alpha = i as f64 * 0.1;
+                root.draw_pixel((50 + i, 50 + i), &BLACK.mix(alpha))
+                    .unwrap();
+            }
+        }
 
-    fn checked_save_file(name: &str, content: &str) {
-        /*
-          Please use the SVG file to manually verify the results.
-        */
-        assert!(!content.is_empty());
-        fs::create_dir_all(DST_DIR).unwrap();
-        let file_name = format!("{}.svg", name);
-        let file_path = Path::new(DST_DIR).join(file_name);
-        println!("{:?} created", file_path);
-        fs::write(file_path, &content).unwrap();
+        checked_save_file("test_draw_pixel_alphas", &content);
     }
 
-    fn draw_mesh_with_custom_ticks(tick_size: i32, test_name: &str) {
-        let mut content: String = Default::default();
+    #[test]
+    fn test_text_outlines() {
+        let mut content = String::default();
         {
-            let root = SVGBackend::with_string(&mut content, (500, 500)).into_drawing_area();
+            let root = SVGBackend::with_string(&mut content, (300, 200))
+                .with_text_outlines()
+                .into_drawing_area();
+            root.fill(&WHITE).unwrap();
+            let root = root
+                .titled("Outlined & traced", ("sans-serif", 30).into_font())
+                .unwrap();
+            let mut chart = ChartBuilder::on(&root)
+                .set_all_label_area_size(40u32)
+                .build_cartesian_2d(0..10i32, 0..10i32)
+                .unwrap();
+            chart.configure_mesh().y_desc("Y Axis").draw().unwrap();
+        }
+
+        checked_save_file("test_text_outlines", &content);
+
+        assert!(!content.contains("<text"));
+        assert!(content.contains(
+            r##"<path aria-label="Outlined &amp; traced" opacity="1" fill="#000000" d="M"##
+        ));
+        assert!(content.contains(r#"<path aria-label="Y Axis""#));
+        assert!(content.contains(r#"transform="rotate(270, "#));
+    }
 
+    #[test]
+    fn test_marker_reuse() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (300, 300))
+                .with_marker_reuse()
+                .into_drawing_area();
+            root.fill(&WHITE).unwrap();
             let mut chart = ChartBuilder::on(&root)
-                .caption("This is a test", ("sans-serif", 20u32))
-                .set_all_label_area_size(40u32)
-                .build_cartesian_2d(0..10, 0..10)
+                .build_cartesian_2d(0..100i32, 0..100i32)
                 .unwrap();
-
             chart
-                .configure_mesh()
-                .set_all_tick_mark_size(tick_size)
-                .draw()
+                .draw_series((0..100).map(|x| Circle::new((x, x), 3, RED.filled())))
+                .unwrap();
+            chart
+                .draw_series((0..100).map(|x| Cross::new((x, 100 - x), 4, BLUE)))
+                .unwrap();
+            chart
+                .draw_series((0..100).map(|x| TriangleMarker::new((x, 50), 5, GREEN)))
                 .unwrap();
         }
 
-        checked_save_file(test_name, &content);
+        checked_save_file("test_marker_reuse", &content);
 
-        assert!(content.contains("This is a test"));
+        assert_eq!(content.matches("<defs>").count(), 3);
+        assert_eq!(content.matches("<use ").count(), 300);
+        assert!(content.contains(r##"<circle id="plotters-marker-disc-3-FF0000-1-1" r="3""##));
+        assert!(content.contains(r##"<use href="#plotters-marker-cross-4-0000FF-1-1" x="##));
+        assert!(content.contains(r#"<polygon id="plotters-marker-triangle-5-00FF00-1-1""#));
     }
 
     #[test]
-    fn test_draw_mesh_no_ticks() {
-        draw_mesh_with_custom_ticks(0, "test_draw_mesh_no_ticks");
-    }
+    fn test_optimized_paths() {
+        let draw = |optimized: bool| {
+            let mut content = String::default();
+            {
+                let mut backend = SVGBackend::with_string(&mut content, (500, 300));
+                if optimized {
+                    backend = backend.with_optimized_paths();
+                }
+                let root = backend.into_drawing_area();
+                root.fill(&WHITE).unwrap();
+                let mut chart = ChartBuilder::on(&root)
+                    .set_all_label_area_size(30u32)
+                    .build_cartesian_2d(0f64..10f64, -1f64..1f64)
+                    .unwrap();
+                chart.configure_mesh().draw().unwrap();
+                chart
+                    .draw_series(LineSeries::new(
+                        (0..1000).map(|x| x as f64 / 100.0).map(|x| (x, x.sin())),
+                        &BLUE,
+                    ))
+                    .unwrap();
+                chart
+                    .draw_series((0..10).map(|x| Circle::new((x as f64, 0.5), 3u32, RED.filled())))
+                    .unwrap();
+            }
+            content
+        };
+        let plain = draw(false);
+        let content = draw(true);
+        checked_save_file("test_optimized_paths", &content);
 
-    #[test]
-    fn test_draw_mesh_negative_ticks() {
-        draw_mesh_with_custom_ticks(-10, "test_draw_mesh_negative_ticks");
+        assert!(content.len() * 2 < plain.len());
+        for tag in ["<line", "<polyline", "<polygon", "<rect", "<circle"] {
+            assert!(!content.contains(tag), "{} in optimized output", tag);
+        }
+        assert!(content.contains(r##"<path fill="none" stroke="#0000FF" stroke-width="1" d="M"##));
+        // The markers are merged into a single path
+        assert_eq!(content.matches(r##"<path fill="#FF0000""##).count(), 1);
+        assert!(content.contains("a3 3 0 1 1 6 0a3 3 0 1 1-6 0z"));
     }
 
+    #[cfg(feature = "gzip")]
     #[test]
-    fn test_text_alignments() {
-        let mut content: String = Default::default();
-        {
-            let mut root = SVGBackend::with_string(&mut content, (500, 500));
+    fn test_gzip_output() {
+        use std::io::Read;
 
-            let style = TextStyle::from(("sans-serif", 20).into_font())
-                .pos(Pos::new(HPos::Right, VPos::Top));
-            root.draw_text("right-align", &style, (150, 50)).unwrap();
+        fn draw(backend: SVGBackend) {
+            let root = backend.into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .set_all_label_area_size(30u32)
+                .build_cartesian_2d(0..10i32, 0..10i32)
+                .unwrap();
+            chart.configure_mesh().draw().unwrap();
+            root.present().unwrap();
+        }
+        fn decode(bytes: &[u8]) -> String {
+            assert_eq!(&bytes[..2], &[0x1f, 0x8b]);
+            let mut content = String::new();
+            flate2::read::GzDecoder::new(bytes)
+                .read_to_string(&mut content)
+                .unwrap();
+            content
+        }
 
-            let style = style.pos(Pos::new(HPos::Center, VPos::Top));
-            root.draw_text("center-align", &style, (150, 150)).unwrap();
+        let mut plain = String::new();
+        draw(SVGBackend::with_string(&mut plain, (200, 200)));
 
-            let style = style.pos(Pos::new(HPos::Left, VPos::Top));
-            root.draw_text("left-align", &style, (150, 200)).unwrap();
-        }
+        fs::create_dir_all(DST_DIR).unwrap();
+        let file_path = Path::new(DST_DIR).join("test_gzip_output.SVGZ");
+        draw(SVGBackend::new(&file_path, (200, 200)));
+        assert_eq!(decode(&fs::read(&file_path).unwrap()), plain);
+
+        let mut bytes = vec![];
+        draw(SVGBackend::with_bytes(&mut bytes, (200, 200)));
+        assert_eq!(bytes, plain.as_bytes());
+
+        let mut bytes = vec![];
+        draw(SVGBackend::with_bytes(&mut bytes, (200, 200)).with_gzip());
+        assert!(bytes.len() < plain.len());
+        assert_eq!(decode(&bytes), plain);
+    }
 
-        checked_save_file("test_text_alignments", &content);
+    #[test]
+    fn test_css_classes() {
+        let draw = |stylesheet: bool| {
+            let mut content = String::new();
+            {
+                let backend = SVGBackend::with_string(&mut content, (300, 300));
+                let backend = if stylesheet {
+                    backend.with_css_stylesheet()
+                } else {
+                    backend.with_css_classes()
+                };
+                let root = backend.into_drawing_area();
+                let mut chart = ChartBuilder::on(&root)
+                    .caption("Classes", ("sans-serif", 20))
+                    .set_all_label_area_size(30u32)
+                    .build_cartesian_2d(0..10i32, 0..10i32)
+                    .unwrap();
+                chart.configure_mesh().draw().unwrap();
+                chart
+                    .draw_series(LineSeries::new((0..10).map(|x| (x, x)), &RED))
+                    .unwrap()
+                    .label("Line")
+                    .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+                chart.configure_series_labels().draw().unwrap();
+            }
+            content
+        };
 
-        for svg_line in content.split("</text>") {
-            if let Some(anchor_and_rest) = svg_line.split("text-anchor=\"").nth(1) {
-                if anchor_and_rest.starts_with("end") {
-                    assert!(anchor_and_rest.contains("right-align"))
-                }
-                if anchor_and_rest.starts_with("middle") {
-                    assert!(anchor_and_rest.contains("center-align"))
-                }
-                if anchor_and_rest.starts_with("start") {
-                    assert!(anchor_and_rest.contains("left-align"))
+        let content = draw(false);
+        checked_save_file("test_css_classes", &content);
+        for class in [
+            "plotters-axis",
+            "plotters-grid-bold",
+            "plotters-grid-light",
+            "plotters-label",
+            "plotters-legend plotters-series-0",
+        ] {
+            assert!(
+                content.contains(&format!(r#" class="{}""#, class)),
+                "{}",
+                class
+            );
+        }
+        assert!(content.contains(r##"<polyline fill="none" opacity="1" stroke="#FF0000""##));
+        assert!(content.contains(r#" class="plotters-series-0"/>"#));
+        assert!(content.contains(r#" class="plotters-axis plotters-label">"#));
+        assert!(!content.contains("<style>"));
+
+        let content = draw(true);
+        checked_save_file("test_css_stylesheet", &content);
+        assert!(!content.contains(" stroke=\""));
+        assert!(!content.contains(" opacity=\""));
+        assert!(content.contains(r#" class="plotters-series-0 plotters-s"#));
+        assert!(content.contains("<style>\n:where(.plotters-s0){"));
+        assert!(content.contains("stroke:#FF0000;stroke-width:1px;"));
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    #[test]
+    fn test_embedded_bitmaps() {
+        fn blit(jpeg: bool, rgba: bool, alpha: u8) -> image::DynamicImage {
+            let mut content = String::new();
+            {
+                let backend = SVGBackend::with_string(&mut content, (100, 100));
+                let mut backend = if jpeg {
+                    backend.with_jpeg_bitmaps(90)
+                } else {
+                    backend
+                };
+                let pixel = |x: u32, y: u32| [(x * 8) as u8, (y * 8) as u8, 128];
+                if rgba {
+                    let src: Vec<_> = (0..32 * 32)
+                        .flat_map(|i| {
+                            let [r, g, b] = pixel(i % 32, i / 32);
+                            [r, g, b, alpha]
+                        })
+                        .collect();
+                    backend.blit_bitmap_rgba((10, 10), (32, 32), &src).unwrap();
+                } else {
+                    let src: Vec<_> = (0..32 * 32).flat_map(|i| pixel(i % 32, i / 32)).collect();
+                    backend.blit_bitmap((10, 10), (32, 32), &src).unwrap();
                 }
+                backend.present().unwrap();
             }
+            let start = content.find("href=\"data:image/").unwrap() + 17;
+            let (mime, data) = content[start..].split_once(";base64,").unwrap();
+            let data = &data[..data.find('"').unwrap()];
+            let bytes = decode_base64(data);
+            let format = match mime {
+                "png" => image::ImageFormat::Png,
+                "jpeg" => image::ImageFormat::Jpeg,
+                other => panic!("unexpected mime type {}", other),
+            };
+            image::load_from_memory_with_format(&bytes, format).unwrap()
+        }
+        fn decode_base64(data: &str) -> Vec<u8> {
+            const ALPHABET: &[u8] =
+                b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+            let values: Vec<u32> = data
+                .bytes()
+                .filter(|&c| c != b'=')
+                .map(|c| ALPHABET.iter().position(|&a| a == c).unwrap() as u32)
+                .collect();
+            let mut bytes = vec![];
+            for chunk in values.chunks(4) {
+                let word = chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0, |word, (idx, v)| word | (v << (18 - 6 * idx)));
+                bytes.extend_from_slice(&word.to_be_bytes()[1..chunk.len()]);
+            }
+            bytes
         }
+
+        let png = blit(false, false, 255);
+        assert_eq!(png.color(), image::ColorType::Rgb8);
+        assert_eq!(png.to_rgb8().get_pixel(3, 5).0, [24, 40, 128]);
+
+        let png = blit(false, true, 100);
+        assert_eq!(png.color(), image::ColorType::Rgba8);
+        assert_eq!(png.to_rgba8().get_pixel(3, 5).0, [24, 40, 128, 100]);
+
+        // JPEG is used for opaque bitmaps only
+        let jpeg = blit(true, false, 255);
+        assert_eq!(jpeg.color(), image::ColorType::Rgb8);
+        assert_eq!((jpeg.width(), jpeg.height()), (32, 32));
+        assert_eq!(blit(true, true, 255).color(), image::ColorType::Rgb8);
+        assert_eq!(blit(true, true, 100).color(), image::ColorType::Rgba8);
     }
 
     #[test]
-    fn test_text_draw() {
-        let mut content: String = Default::default();
+    fn test_responsive_size() {
+        let mut content = String::new();
         {
-            let root = SVGBackend::with_string(&mut content, (1500, 800)).into_drawing_area();
-            let root = root
-                .titled("Image Title", ("sans-serif", 60).into_font())
+            let root = SVGBackend::with_string(&mut content, (400, 300))
+                .with_tooltips()
+                .with_accessibility()
+                .with_responsive_size()
+                .with_preserve_aspect_ratio("xMinYMin meet")
+                .into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .caption("Responsive", ("sans-serif", 20))
+                .set_all_label_area_size(30u32)
+                .build_cartesian_2d(0..10i32, 0..10i32)
+                .unwrap();
+            chart.configure_mesh().draw().unwrap();
+            chart
+                .draw_series(LineSeries::new((0..10).map(|x| (x, x)), &RED))
                 .unwrap();
+        }
+        checked_save_file("test_responsive_size", &content);
+        assert!(content.starts_with(
+            r#"<svg viewBox="0 0 400 300" preserveAspectRatio="xMinYMin meet" xmlns="http://www.w3.org/2000/svg" role="graphics-document document">"#
+        ));
+        assert!(content.contains(r#"class="plotters-series""#));
 
+        let mut content = String::new();
+        {
+            let root = SVGBackend::with_string(&mut content, (400, 300))
+                .with_percent_size(100.0, 50.5)
+                .into_drawing_area();
+            root.fill(&WHITE).unwrap();
+        }
+        assert!(content.starts_with(
+            r#"<svg width="100%" height="50.5%" viewBox="0 0 400 300" xmlns="http://www.w3.org/2000/svg">"#
+        ));
+    }
+    #[test]
+    fn test_subpixel_precision() {
+        let draw = |content: &mut String, precision: Option<usize>| {
+            let mut backend = SVGBackend::with_string(content, (200, 100)).with_marker_reuse();
+            if let Some(decimals) = precision {
+                backend = backend.with_subpixel_precision(decimals);
+            }
+            let root = backend.into_drawing_area();
             let mut chart = ChartBuilder::on(&root)
-                .caption("All anchor point positions", ("sans-serif", 20u32))
-                .set_all_label_area_size(40u32)
-                .build_cartesian_2d(0..100i32, 0..50i32)
+                .build_cartesian_2d(0.0..3.0, 0.0..3.0)
                 .unwrap();
-
             chart
-                .configure_mesh()
-                .disable_x_mesh()
-                .disable_y_mesh()
-                .x_desc("X Axis")
-                .y_desc("Y Axis")
-                .draw()
+                .draw_series(LineSeries::new([(0.0, 0.0), (1.0, 1.0), (2.0, 0.5)], &RED))
+                .unwrap();
+            chart
+                .draw_series([Circle::new((1.0, 1.0), 3, BLUE.filled())])
+                .unwrap();
+            chart
+                .draw_series([Text::new("x", (1.0, 1.0), ("sans-serif", 10))])
                 .unwrap();
+        };
 
-            let ((x1, y1), (x2, y2), (x3, y3)) = ((-30, 30), (0, -30), (30, 30));
+        let mut content = String::new();
+        draw(&mut content, Some(2));
+        checked_save_file("test_subpixel_precision", &content);
+        assert!(content.contains(r#"points="0,99 66.33,66 132.67,82.5 ""#));
+        assert!(content.contains(r##"<use href="#plotters-marker-disc-3-"##));
+        assert!(content.contains(r#"x="66.33" y="66"/>"#));
+        assert!(content.contains(r#"<text x="66.33" y="66""#));
+
+        // The coordinates are rounded to pixels by default
+        let mut content = String::new();
+        draw(&mut content, None);
+        assert!(content.contains(r#"points="0,99 66,66 132,83 ""#));
+        assert!(
+            content.contains(r##"<use href="#plotters-marker-disc-3-0000FF-1-1" x="66" y="66"/>"##)
+        );
+        assert!(content.contains(r#"<text x="66" y="66""#));
+    }
 
-            for (dy, trans) in [
-                FontTransform::None,
-                FontTransform::Rotate90,
-                FontTransform::Rotate180,
-                FontTransform::Rotate270,
-            ]
-            .iter()
-            .enumerate()
+    #[test]
+    fn test_stroke_styles() {
+        let draw = |optimized: bool| {
+            let mut content = String::new();
             {
-                for (dx1, h_pos) in [HPos::Left, HPos::Right, HPos::Center].iter().enumerate() {
-                    for (dx2, v_pos) in [VPos::Top, VPos::Center, VPos::Bottom].iter().enumerate() {
-                        let x = 150_i32 + (dx1 as i32 * 3 + dx2 as i32) * 150;
-                        let y = 120 + dy as i32 * 150;
-                        let draw = |x, y, text| {
-                            root.draw(&Circle::new((x, y), 3, &BLACK.mix(0.5))).unwrap();
-                            let style = TextStyle::from(("sans-serif", 20).into_font())
-                                .pos(Pos::new(*h_pos, *v_pos))
-                                .transform(trans.clone());
-                            root.draw_text(text, &style, (x, y)).unwrap();
-                        };
-                        draw(x + x1, y + y1, "dood");
-                        draw(x + x2, y + y2, "dog");
-                        draw(x + x3, y + y3, "goog");
-                    }
+                let mut backend = SVGBackend::with_string(&mut content, (100, 100));
+                if optimized {
+                    backend = backend.with_optimized_paths();
                 }
+                let root = backend.into_drawing_area();
+                let dashed = ShapeStyle::from(RED)
+                    .stroke_width(2)
+                    .dash(&[4.0, 2.0])
+                    .dash_offset(1.0);
+                root.draw(&PathElement::new(vec![(10, 10), (90, 10)], dashed))
+                    .unwrap();
+                let capped = ShapeStyle::from(BLUE)
+                    .stroke_width(3)
+                    .line_cap(LineCap::Round)
+                    .line_join(LineJoin::Miter { limit: 10.0 });
+                root.draw(&PathElement::new(
+                    vec![(10, 50), (50, 30), (90, 50)],
+                    capped,
+                ))
+                .unwrap();
+                root.draw(&Rectangle::new(
+                    [(20, 60), (80, 90)],
+                    ShapeStyle::from(GREEN).line_join(LineJoin::Bevel),
+                ))
+                .unwrap();
             }
-        }
-
-        checked_save_file("test_text_draw", &content);
+            content
+        };
 
-        assert_eq!(content.matches("dog").count(), 36);
-        assert_eq!(content.matches("dood").count(), 36);
-        assert_eq!(content.matches("goog").count(), 36);
+        let content = draw(false);
+        checked_save_file("test_stroke_styles", &content);
+        assert!(content.contains(r#"stroke-dasharray="4 2" stroke-dashoffset="1""#));
+        assert!(content.contains(r#"stroke-linecap="round" stroke-miterlimit="10""#));
+        assert!(content.contains(r#"stroke-linejoin="bevel""#));
+
+        // The paths of different stroke properties aren't merged
+        let content = draw(true);
+        assert!(content.contains(r#"stroke-dasharray="4 2" stroke-dashoffset="1""#));
+        assert!(content.contains(r#"stroke-linecap="round" stroke-miterlimit="10""#));
+        assert_eq!(content.matches("<path").count(), 3);
     }
 
     #[test]
-    fn test_text_clipping() {
-        let mut content: String = Default::default();
+    fn test_clip_series() {
+        let mut content = String::new();
         {
-            let (width, height) = (500_i32, 500_i32);
-            let root = SVGBackend::with_string(&mut content, (width as u32, height as u32))
+            let root = SVGBackend::with_string(&mut content, (100, 100))
+                .with_clipping()
                 .into_drawing_area();
-
-            let style = TextStyle::from(("sans-serif", 20).into_font())
-                .pos(Pos::new(HPos::Center, VPos::Center));
-            root.draw_text("TOP LEFT", &style, (0, 0)).unwrap();
-            root.draw_text("TOP CENTER", &style, (width / 2, 0))
-                .unwrap();
-            root.draw_text("TOP RIGHT", &style, (width, 0)).unwrap();
-
-            root.draw_text("MIDDLE LEFT", &style, (0, height / 2))
-                .unwrap();
-            root.draw_text("MIDDLE RIGHT", &style, (width, height / 2))
+            let area = root.margin(10, 10, 10, 10);
+            let mut chart = ChartBuilder::on(&area)
+                .build_cartesian_2d(0.0..1.0, 0.0..1.0)
                 .unwrap();
-
-            root.draw_text("BOTTOM LEFT", &style, (0, height)).unwrap();
-            root.draw_text("BOTTOM CENTER", &style, (width / 2, height))
+            chart
+                .draw_series(LineSeries::new(vec![(-1.0, 0.5), (2.0, 0.5)], &RED))
                 .unwrap();
-            root.draw_text("BOTTOM RIGHT", &style, (width, height))
+            chart
+                .draw_series(LineSeries::new(vec![(0.0, 0.0), (1.0, 1.0)], &BLUE))
                 .unwrap();
         }
 
-        checked_save_file("test_text_clipping", &content);
+        checked_save_file("test_clip_series", &content);
+
+        // The clip path is defined once and shared by the series
+        assert_eq!(content.matches("<clipPath ").count(), 1);
+        assert!(content.contains(r#"<clipPath id="plotters-clip-10-10-80-80">"#));
+        assert!(content.contains(r#"<rect x="10" y="10" width="80" height="80"/>"#));
+        assert_eq!(
+            content
+                .matches(r##"<g clip-path="url(#plotters-clip-10-10-80-80)">"##)
+                .count(),
+            2
+        );
+        assert_eq!(
+            content.matches("<g").count(),
+            content.matches("</g>").count()
+        );
     }
 
     #[test]
-    fn test_series_labels() {
-        let mut content = String::default();
+    fn test_pop_clip_closes_its_group() {
+        let mut content = String::new();
         {
-            let (width, height) = (500, 500);
-            let root = SVGBackend::with_string(&mut content, (width, height)).into_drawing_area();
-
-            let mut chart = ChartBuilder::on(&root)
-                .caption("All series label positions", ("sans-serif", 20u32))
-                .set_all_label_area_size(40u32)
-                .build_cartesian_2d(0..50i32, 0..50i32)
-                .unwrap();
-
-            chart
-                .configure_mesh()
-                .disable_x_mesh()
-                .disable_y_mesh()
-                .draw()
+            let mut backend = SVGBackend::with_string(&mut content, (100, 100))
+                .with_clipping()
+                .with_tooltips();
+            backend.push_clip_rect((10, 10), (89, 89)).unwrap();
+            // A context left open by a failed drawing is closed along with the clip group
+            backend.begin_context(ElementContext::data_point(
+                (50, 50),
+                "1".to_string(),
+                "2".to_string(),
+                0,
+            ));
+            backend.pop_clip().unwrap();
+            backend
+                .draw_rect(
+                    (0, 0),
+                    (5, 5),
+                    &BackendColor {
+                        alpha: 1.0,
+                        rgb: (0, 0, 0),
+                    },
+                    true,
+                )
                 .unwrap();
+            backend.present().unwrap();
+        }
 
-            chart
-                .draw_series(std::iter::once(Circle::new((5, 15), 5u32, &RED)))
-                .expect("Drawing error")
-                .label("Series 1")
-                .legend(|(x, y)| Circle::new((x, y), 3u32, RED.filled()));
-
-            chart
-                .draw_series(std::iter::once(Circle::new((5, 15), 10u32, &BLUE)))
-                .expect("Drawing error")
-                .label("Series 2")
-                .legend(|(x, y)| Circle::new((x, y), 3u32, BLUE.filled()));
+        let clip = content.find("<g clip-path=").unwrap();
+        let rect = content.find("<rect x=\"0\"").unwrap();
+        assert_eq!(content[clip..rect].matches("</g>").count(), 2);
+        assert_eq!(
+            content.matches("<g").count(),
+            content.matches("</g>").count()
+        );
+    }
 
-            for pos in vec![
-                SeriesLabelPosition::UpperLeft,
-                SeriesLabelPosition::MiddleLeft,
-                SeriesLabelPosition::LowerLeft,
-                SeriesLabelPosition::UpperMiddle,
-                SeriesLabelPosition::MiddleMiddle,
-                SeriesLabelPosition::LowerMiddle,
-                SeriesLabelPosition::UpperRight,
-                SeriesLabelPosition::MiddleRight,
-                SeriesLabelPosition::LowerRight,
-                SeriesLabelPosition::Coordinate(70, 70),
-            ]
-            .into_iter()
+    #[test]
+    fn test_clip_keeps_slopes() {
+        let draw = |margin: u32| {
+            let mut content = String::new();
             {
+                let root = SVGBackend::with_string(&mut content, (100, 100))
+                    .with_clipping()
+                    .into_drawing_area();
+                let area = root.margin(margin, margin, margin, margin);
+                let mut chart = ChartBuilder::on(&area)
+                    .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+                    .unwrap();
                 chart
-                    .configure_series_labels()
-                    .border_style(&BLACK.mix(0.5))
-                    .position(pos)
-                    .draw()
-                    .expect("Drawing error");
+                    .draw_series(LineSeries::new(vec![(0.0, 0.0), (4.0, 1.0)], &RED))
+                    .unwrap();
             }
-        }
+            content
+        };
 
-        checked_save_file("test_series_labels", &content);
+        // The line leaving the plotting area isn't bent towards it, the clip path cuts it
+        let content = draw(10);
+        assert!(content.contains(r#"points="10,89 326,10 ""#));
+
+        // The ids of the clip paths of different areas differ, even in different documents
+        let other = draw(20);
+        assert!(other.contains(r#"<clipPath id="plotters-clip-20-20-60-60">"#));
+        assert!(!other.contains("plotters-clip-10-10-80-80"));
     }
 
     #[test]
-    fn test_draw_pixel_alphas() {
-        let mut content = String::default();
-        {
-            let (width, height) = (100_i32, 100_i32);
-            let root = SVGBackend::with_string(&mut content, (width as u32, height as u32))
-                .into_drawing_area();
-            root.fill(&WHITE).unwrap();
-
-            for i in -20..20 {
-                let alpha = i as f64 * 0.1;
-                root.draw_pixel((50 + i, 50 + i), &BLACK.mix(alpha))
+    fn test_paints() {
+        let draw = |optimized: bool| {
+            let mut content = String::new();
+            {
+                let mut backend = SVGBackend::with_string(&mut content, (200, 100));
+                if optimized {
+                    backend = backend.with_optimized_paths();
+                }
+                let root = backend.into_drawing_area();
+                let fade = BLUE.filled().linear_gradient(
+                    (0.0, 0.0),
+                    (0.0, 1.0),
+                    &[(0.0, BLUE.to_rgba()), (1.0, BLUE.mix(0.0))],
+                );
+                for x in [10, 60] {
+                    root.draw(&Rectangle::new([(x, 10), (x + 40, 90)], fade.clone()))
+                        .unwrap();
+                }
+                let hatch = RED.filled().hatch(45.0, 6.0, 2.0);
+                root.draw(&Polygon::new(vec![(110, 10), (190, 10), (150, 90)], hatch))
                     .unwrap();
+                let glow =
+                    GREEN
+                        .filled()
+                        .radial_gradient((0.5, 0.5), 0.5, &[(0.0, GREEN), (1.0, WHITE)]);
+                root.draw(&Circle::new((150, 50), 20, glow)).unwrap();
             }
-        }
+            content
+        };
 
-        checked_save_file("test_draw_pixel_alphas", &content);
+        let content = draw(false);
+        checked_save_file("test_paints", &content);
+        let id_of = |tag: &str| {
+            let start = content.find(&format!("<{} id=\"", tag)).unwrap() + tag.len() + 6;
+            content[start..start + content[start..].find('"').unwrap()].to_string()
+        };
+        let (linear, hatch, radial) = (
+            id_of("linearGradient"),
+            id_of("pattern"),
+            id_of("radialGradient"),
+        );
+        assert!(linear.starts_with("plotters-paint-"));
+        assert!(linear != hatch && hatch != radial && radial != linear);
+        assert!(content.contains(&format!(
+            r#"<linearGradient id="{}" x1="0" y1="0" x2="0" y2="1">"#,
+            linear
+        )));
+        assert!(content.contains(r##"<stop offset="1" stop-color="#0000FF" stop-opacity="0"/>"##));
+        assert_eq!(content.matches("<linearGradient").count(), 1);
+        assert_eq!(
+            content
+                .matches(&format!(r#"fill="url(#{})""#, linear))
+                .count(),
+            2
+        );
+        assert!(content.contains(&format!(
+            r#"<pattern id="{}" patternUnits="userSpaceOnUse" width="6" height="6" patternTransform="rotate(45)">"#,
+            hatch
+        )));
+        assert!(content.contains(&format!(
+            r#"<radialGradient id="{}" cx="0.5" cy="0.5" r="0.5">"#,
+            radial
+        )));
+        assert!(content.contains(&format!(r#"fill="url(#{})""#, radial)));
+
+        // The ids only depend on the paints, thus they don't collide across documents
+        let mut other = String::new();
+        {
+            let root = SVGBackend::with_string(&mut other, (100, 100)).into_drawing_area();
+            let style = RED.filled().hatch(30.0, 6.0, 2.0);
+            root.draw(&Rectangle::new([(10, 10), (90, 90)], style))
+                .unwrap();
+        }
+        assert!(!other.contains(&hatch));
+        assert!(!other.contains(&linear));
+        let again = draw(false);
+        assert!(again.contains(&format!(r#"<pattern id="{}""#, hatch)));
+
+        // The painted shapes aren't merged into paths
+        let content = draw(true);
+        assert_eq!(content.matches("<rect").count(), 2 + 1);
+        assert!(content.contains("<polygon"));
+        assert!(content.contains("<circle"));
     }
 }
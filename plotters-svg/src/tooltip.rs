@@ -0,0 +1,501 @@
+/*!
+The CSS and JavaScript driving the interactive tooltips of the SVG backend
+*/
+
+const TOOLTIP_CSS: &str = r#"
+.plotters-tooltip {
+    pointer-events: none;
+    opacity: 0;
+    transition: opacity 0.15s;
+}
+.plotters-tooltip.plotters-tt-visible {
+    opacity: 1;
+}
+.plotters-tooltip rect {
+    fill: #222;
+    rx: 4;
+    ry: 4;
+}
+.plotters-tooltip text {
+    fill: #fff;
+    font-family: sans-serif;
+    font-size: 12px;
+}
+.plotters-tt-series {
+    font-weight: bold;
+}
+.plotters-dp-hover {
+    pointer-events: all;
+    cursor: crosshair;
+}
+rect.plotters-dp-hover,
+rect.plotters-dl-hover {
+    pointer-events: fill;
+}
+.plotters-dp:hover .plotters-dp-ring, .plotters-dp-hover:hover ~ .plotters-dp-ring, circle:hover ~ .plotters-dp-ring {
+    opacity: 1;
+}
+.plotters-dp > circle {
+    pointer-events: none;
+}
+.plotters-dp-ring {
+    pointer-events: none;
+    opacity: 0;
+    transition: opacity 0.12s;
+}
+.plotters-dl-hover {
+    pointer-events: stroke;
+    cursor: crosshair;
+}
+.plotters-axis-hover {
+    pointer-events: fill;
+    cursor: crosshair;
+}
+.plotters-legend-entry {
+    cursor: pointer;
+}
+.plotters-legend-hit {
+    pointer-events: fill;
+}
+.plotters-legend-off .plotters-legend-hit {
+    fill: #fff;
+    fill-opacity: 0.6;
+}
+.plotters-series {
+    transition: opacity 0.15s;
+}
+.plotters-series-hidden {
+    display: none;
+}
+.plotters-highlighting .plotters-series:not(.plotters-series-highlight) {
+    opacity: 0.2;
+}
+.plotters-crosshair-area {
+    pointer-events: fill;
+    cursor: crosshair;
+}
+.plotters-crosshair {
+    stroke: #888;
+    stroke-dasharray: 4 3;
+    pointer-events: none;
+    opacity: 0;
+}
+.plotters-crosshair.plotters-tt-visible {
+    opacity: 1;
+}
+"#;
+
+const TOOLTIP_SCRIPT: &str = r#"
+(function() {
+  // Bind the tooltips, the legend and the crosshair of a chart, once per <svg>
+  function bind(svg) {
+    if (svg.plottersTooltips) return;
+    let tt = svg.querySelector(".plotters-tooltip");
+    if (!tt) return;
+    svg.plottersTooltips = true;
+
+    // Series attributes known only after the series was streamed out are emitted at the end
+    svg.querySelectorAll(".plotters-series-attrs").forEach(function(el) {
+      let key = el.getAttribute("data-series-key");
+      let series = svg.querySelector('.plotters-series[data-series-key="' + key + '"]');
+      if (!series) return;
+      for (var i = 0; i < el.attributes.length; i++) {
+        let a = el.attributes[i];
+        if (a.name != "class" && a.name != "data-series-key") series.setAttribute(a.name, a.value);
+      }
+    });
+    let ttRect = tt.querySelector("rect");
+    let ttText = tt.querySelector("text");
+    let template = tt.getAttribute("data-template");
+    let pad = 8;
+    const SVG_NS = "http://www.w3.org/2000/svg";
+
+    function seriesLabel(el) {
+      let g = el.closest(".plotters-series");
+      return g ? g.getAttribute("data-series-label") || "" : "";
+    }
+
+    // Name or units of an axis, e.g. axisInfo("x", "units")
+    function axisInfo(axis, kind) {
+      let v = tt.getAttribute("data-" + axis + "-" + kind);
+      if (v != null) return v;
+      return kind == "name" ? axis : "";
+    }
+
+    // The user metadata of a data point as [key, value] pairs
+    function metadata(el) {
+      let meta = [];
+      for (var i = 0; i < el.attributes.length; i++) {
+        let a = el.attributes[i];
+        if (a.name.indexOf("data-meta-") == 0) meta.push([a.name.slice(10), a.value]);
+      }
+      return meta;
+    }
+
+    // Fill the tooltip text from the template, one tspan per non-empty line. The metadata is
+    // available as placeholders by key and listed line by line without a template.
+    function render(vals, meta, tpl) {
+      tpl = tpl || template;
+      if (tpl == null) {
+        tpl = "{series}\n{x_name}: {x} {y_name}: {y}";
+        if (vals.z != null) tpl += " {z_name}: {z}";
+        meta.forEach(function(kv) { tpl += "\n" + kv[0] + ": {" + kv[0] + "}"; });
+      }
+      meta.forEach(function(kv) {
+        if (!(kv[0] in vals)) vals[kv[0]] = kv[1];
+      });
+      setLines(tpl.split("\n").map(function(line) {
+        let text = line.replace(/\{([\w-]+)\}/g, function(m, key) {
+          let info = key.match(/^(\w+)_(name|units)$/);
+          if (info) return axisInfo(info[1], info[2]);
+          if (key in vals) return vals[key] == null ? "" : vals[key];
+          return m;
+        });
+        return { text: text, cls: line.trim() == "{series}" ? "plotters-tt-series" : "" };
+      }));
+    }
+
+    // Replace the tooltip text by the given lines, {text, cls, color}. Lines with a colour get a
+    // bullet in that colour, empty lines are omitted.
+    function setLines(lines) {
+      while (ttText.firstChild) ttText.removeChild(ttText.firstChild);
+      lines.forEach(function(line) {
+        if (!line.text.trim()) return;
+        let ts = document.createElementNS(SVG_NS, "tspan");
+        ts.setAttribute("class", line.cls || "plotters-tt-value");
+        ts.setAttribute("x", 0);
+        ts.setAttribute("dy", ttText.firstChild ? "1.3em" : "0");
+        if (line.color) {
+          let bullet = document.createElementNS(SVG_NS, "tspan");
+          bullet.setAttribute("fill", line.color);
+          bullet.textContent = "\u25CF ";
+          ts.appendChild(bullet);
+        }
+        ts.appendChild(document.createTextNode(line.text));
+        ttText.appendChild(ts);
+      });
+    }
+
+    // Show the tooltip next to the pixel (px, py)
+    function place(px, py) {
+      // Position everything at origin to measure
+      tt.classList.add("plotters-tt-visible");
+      ttText.setAttribute("x", 0);
+      ttText.setAttribute("y", 0);
+      let bbox = ttText.getBBox();
+
+      let tw = bbox.width + pad * 2;
+      let th = bbox.height + pad * 2;
+      let tx = px + 12;
+      let ty = py - th - 4;
+      let svgW = svg.viewBox.baseVal ? svg.viewBox.baseVal.width : svg.width.baseVal.value;
+      let svgH = svg.viewBox.baseVal ? svg.viewBox.baseVal.height : svg.height.baseVal.value;
+      if (tx + tw > svgW) tx = px - tw - 12;
+      if (ty < 0) ty = py + 16;
+      if (ty + th > svgH) ty = svgH - th - 2;
+
+      ttRect.setAttribute("x", tx);
+      ttRect.setAttribute("y", ty);
+      ttRect.setAttribute("width", tw);
+      ttRect.setAttribute("height", th);
+
+      // Align tspans inside the box, the text baseline is below the top of its bbox
+      ttText.querySelectorAll("tspan").forEach(function(ts) {
+        ts.setAttribute("x", tx + pad);
+      });
+      ttText.setAttribute("x", tx + pad);
+      ttText.setAttribute("y", ty + pad - bbox.y);
+    }
+
+    function show(evt) {
+      let el = evt.currentTarget;
+      render({
+        series: seriesLabel(el),
+        x: el.getAttribute("data-xl") || "",
+        y: el.getAttribute("data-yl") || "",
+        z: el.getAttribute("data-zl"),
+      }, metadata(el));
+
+      let px = parseFloat(el.getAttribute("cx") || el.getAttribute("data-cx") || 0);
+      let py = parseFloat(el.getAttribute("cy") || el.getAttribute("data-cy") || 0);
+      place(px, py);
+    }
+
+    function hide() {
+      tt.classList.remove("plotters-tt-visible");
+    }
+
+    // Evaluate a "p0,p1,v0,v1" linear pixel to value mapping at pixel p. The value is formatted
+    // with the precision of a single pixel.
+    function evalMap(map, p) {
+      let m = map.split(",").map(Number);
+      if (m[1] == m[0]) return String(m[2]);
+      let step = (m[3] - m[2]) / (m[1] - m[0]);
+      let digits = Math.max(0, Math.min(10, Math.ceil(-Math.log10(Math.abs(step) || 1))));
+      return (m[2] + (p - m[0]) * step).toFixed(digits);
+    }
+
+    function cursor(evt) {
+      let pt = svg.createSVGPoint();
+      pt.x = evt.clientX;
+      pt.y = evt.clientY;
+      return pt.matrixTransform(svg.getScreenCTM().inverse());
+    }
+
+    svg.querySelectorAll(".plotters-dp-hover").forEach(function(el) {
+      el.addEventListener("mouseenter", show);
+      el.addEventListener("mouseleave", hide);
+    });
+
+    // Parse the vertices of a line hover target: "x,y,xl,yl[,zl];...". Vertices without labels
+    // get them evaluated from the continuous mappings, if there are some
+    function parsePts(el) {
+      let xmap = el.getAttribute("data-xmap");
+      let ymap = el.getAttribute("data-ymap");
+      return (el.getAttribute("data-pts") || "").split(";").filter(Boolean).map(function(s) {
+        let p = s.split(",");
+        let v = { x: +p[0], y: +p[1], xl: p[2], yl: p[3], zl: p[4] };
+        if (p.length < 4 && xmap && ymap) {
+          v.xl = evalMap(xmap, v.x);
+          v.yl = evalMap(ymap, v.y);
+        }
+        return v;
+      });
+    }
+
+    /* --- Continous line tooltips via mousemove --- */
+    function lineMove(evt) {
+      let el = evt.target;
+      let verts = parsePts(el);
+      if (!verts.length) return;
+
+      // Get cursor position in SVG coordinates
+      let svgPt = cursor(evt);
+
+      // Continuous lines: interpolate linearly between the vertices around the cursor
+      let xmap = el.getAttribute("data-xmap");
+      let ymap = el.getAttribute("data-ymap");
+      if (xmap && ymap) {
+        let px = svgPt.x, py = verts[0].y;
+        let lo = Math.min(verts[0].x, verts[verts.length - 1].x);
+        let hi = Math.max(verts[0].x, verts[verts.length - 1].x);
+        px = Math.min(Math.max(px, lo), hi);
+        let xl = null, yl = null;
+        for (var j = 0; j + 1 < verts.length; j++) {
+          let a = verts[j], b = verts[j + 1];
+          if ((px - a.x) * (px - b.x) <= 0) {
+            py = a.x == b.x ? a.y : a.y + (px - a.x) * (b.y - a.y) / (b.x - a.x);
+            // Close to a vertex, show its labels formatted by the axes
+            let v = Math.abs(px - a.x) <= Math.abs(px - b.x) ? a : b;
+            if (Math.abs(px - v.x) < 2) {
+              px = v.x; py = v.y; xl = v.xl; yl = v.yl;
+            }
+            break;
+          }
+        }
+        render({
+          series: seriesLabel(el),
+          x: xl != null ? xl : evalMap(xmap, px),
+          y: yl != null ? yl : evalMap(ymap, py)
+        }, []);
+        place(px, py);
+        return;
+      }
+
+      // Find nearest vertex by x-distance
+      let best = verts[0], bestDist = Math.abs(svgPt.x - verts[0].x);
+      for (var i = 1; i < verts.length; i++) {
+        let d = Math.abs(svgPt.x - verts[i].x);
+        if (d < bestDist) { bestDist = d; best = verts[i]; }
+      }
+
+      // Show tooltip at that vertex
+      render({ series: seriesLabel(el), x: best.xl, y: best.yl, z: best.zl }, []);
+      place(best.x, best.y);
+    }
+
+    svg.querySelectorAll(".plotters-dl-hover").forEach(function(el) {
+      el.addEventListener("mousemove", lineMove);
+      el.addEventListener("mouseleave", hide);
+    });
+
+    /* --- Axis readouts, the value under the cursor along the axis --- */
+    function axisMove(evt) {
+      let el = evt.target;
+      let axis = el.getAttribute("data-axis");
+      let map = el.getAttribute("data-map");
+      if (!map) return;
+      let svgPt = cursor(evt);
+      let p = axis == "x" ? svgPt.x : svgPt.y;
+      let units = axisInfo(axis, "units");
+      render({ value: evalMap(map, p) }, [], "{" + axis + "_name}: {value}" + (units ? " " + units : ""));
+      place(svgPt.x, svgPt.y);
+    }
+
+    svg.querySelectorAll(".plotters-axis-hover").forEach(function(el) {
+      el.addEventListener("mousemove", axisMove);
+      el.addEventListener("mouseleave", hide);
+    });
+
+    /* --- Shared x mode, a crosshair lists the value of every series at the nearest x --- */
+    function nearestX(verts, x) {
+      let best = verts[0];
+      verts.forEach(function(v) {
+        if (Math.abs(v.x - x) < Math.abs(best.x - x)) best = v;
+      });
+      return best;
+    }
+
+    function sharedX() {
+      let groups = Array.prototype.slice.call(svg.querySelectorAll(".plotters-series"));
+      let series = groups.map(function(g) {
+        let verts = [];
+        g.querySelectorAll(".plotters-dl-hover").forEach(function(el) {
+          verts = verts.concat(parsePts(el));
+        });
+        g.querySelectorAll(".plotters-dp-hover").forEach(function(el) {
+          verts.push({
+            x: parseFloat(el.getAttribute("cx") || el.getAttribute("data-cx") || 0),
+            y: parseFloat(el.getAttribute("cy") || el.getAttribute("data-cy") || 0),
+            xl: el.getAttribute("data-xl") || "",
+            yl: el.getAttribute("data-yl") || "",
+          });
+        });
+        return {
+          group: g,
+          label: g.getAttribute("data-series-label") || "",
+          color: g.getAttribute("data-series-color"),
+          verts: verts,
+        };
+      }).filter(function(s) { return s.verts.length > 0; });
+
+      // The series drawn in the same plotting area belong to the same chart
+      let charts = {};
+      series.forEach(function(s) {
+        let area = s.group.getAttribute("data-plot-area") || "";
+        (charts[area] = charts[area] || []).push(s);
+      });
+      Object.keys(charts).forEach(function(area) { crosshair(area, charts[area]); });
+    }
+
+    function crosshair(area, series) {
+      // The plotting area is given by the backend, or approximated by the extent of the series
+      let x0 = Infinity, y0 = Infinity, x1 = -Infinity, y1 = -Infinity;
+      if (area) {
+        let a = area.split(",").map(Number);
+        x0 = a[0]; y0 = a[1]; x1 = a[2]; y1 = a[3];
+      } else series.forEach(function(s) {
+        let b = s.group.getBBox();
+        if (!b.width && !b.height) return;
+        x0 = Math.min(x0, b.x);
+        y0 = Math.min(y0, b.y);
+        x1 = Math.max(x1, b.x + b.width);
+        y1 = Math.max(y1, b.y + b.height);
+      });
+      if (x0 > x1) return;
+
+      // The crosshair goes above the series but below the legend of the chart, so that its
+      // entries can still be clicked
+      let keys = series.map(function(s) { return s.group.getAttribute("data-series-key"); });
+      let before = Array.prototype.slice.call(svg.querySelectorAll(".plotters-legend-entry"))
+        .filter(function(e) { return keys.indexOf(e.getAttribute("data-series-key")) >= 0; })[0]
+        || tt;
+
+      let line = document.createElementNS(SVG_NS, "line");
+      line.setAttribute("class", "plotters-crosshair");
+      line.setAttribute("y1", y0);
+      line.setAttribute("y2", y1);
+      let overlay = document.createElementNS(SVG_NS, "rect");
+      overlay.setAttribute("class", "plotters-crosshair-area");
+      overlay.setAttribute("x", x0);
+      overlay.setAttribute("y", y0);
+      overlay.setAttribute("width", x1 - x0);
+      overlay.setAttribute("height", y1 - y0);
+      overlay.setAttribute("fill", "transparent");
+      before.parentNode.insertBefore(line, before);
+      before.parentNode.insertBefore(overlay, before);
+
+      overlay.addEventListener("mousemove", function(evt) {
+        let p = cursor(evt);
+        line.setAttribute("x1", p.x);
+        line.setAttribute("x2", p.x);
+        line.classList.add("plotters-tt-visible");
+
+        let best = null;
+        let shown = series.filter(function(s) {
+          return !s.group.classList.contains("plotters-series-hidden");
+        });
+        if (!shown.length) return;
+        let lines = shown.map(function(s) {
+          let v = nearestX(s.verts, p.x);
+          if (!best || Math.abs(v.x - p.x) < Math.abs(best.x - p.x)) best = v;
+          return { text: (s.label ? s.label + ": " : "") + v.yl, color: s.color };
+        });
+        let header = { text: axisInfo("x", "name") + ": " + best.xl, cls: "plotters-tt-series" };
+        setLines([header].concat(lines));
+        place(p.x, p.y);
+      });
+      overlay.addEventListener("mouseleave", function() {
+        line.classList.remove("plotters-tt-visible");
+        hide();
+      });
+    }
+
+    if (tt.getAttribute("data-mode") == "shared-x") sharedX();
+
+    /* --- Legend entries, clicking toggles their series and hovering highlights it --- */
+    svg.querySelectorAll(".plotters-legend-entry").forEach(function(entry) {
+      let key = entry.getAttribute("data-series-key");
+      let series = key == null ? null
+        : svg.querySelector('.plotters-series[data-series-key="' + key + '"]');
+      if (!series) return;
+      entry.addEventListener("click", function() {
+        let hidden = series.classList.toggle("plotters-series-hidden");
+        entry.classList.toggle("plotters-legend-off", hidden);
+      });
+      entry.addEventListener("mouseenter", function() {
+        svg.classList.add("plotters-highlighting");
+        series.classList.add("plotters-series-highlight");
+      });
+      entry.addEventListener("mouseleave", function() {
+        svg.classList.remove("plotters-highlighting");
+        series.classList.remove("plotters-series-highlight");
+      });
+    });
+  }
+
+  function bindAll() {
+    document.querySelectorAll("svg").forEach(bind);
+  }
+  window.plottersBindTooltips = bindAll;
+
+  // Inlined in a chart, the script only binds its own <svg>. Included once by the host page, it
+  // binds every chart of the document.
+  let own = document.currentScript && document.currentScript.closest("svg");
+  if (own) bind(own);
+  else if (document.readyState == "loading") document.addEventListener("DOMContentLoaded", bindAll);
+  else bindAll();
+})();
+//# sourceURL=svg-tooltip.js
+
+"#;
+
+/// The stylesheet of the interactive tooltips
+///
+/// SVG documents created with
+/// [with_external_tooltip_assets](crate::SVGBackend::with_external_tooltip_assets) don't contain
+/// it, the host page should include it once, either inline or as an external `.css` file.
+pub fn tooltip_stylesheet() -> &'static str {
+    TOOLTIP_CSS
+}
+
+/// The script of the interactive tooltips
+///
+/// SVG documents created with
+/// [with_external_tooltip_assets](crate::SVGBackend::with_external_tooltip_assets) don't contain
+/// it, the host page should include it once, e.g. as an external `.js` file for pages with a
+/// strict content security policy. The script binds to every plotters chart of the document when
+/// it is loaded. Charts inserted later can be bound by calling `window.plottersBindTooltips()`.
+pub fn tooltip_script() -> &'static str {
+    TOOLTIP_SCRIPT
+}
@@ -18,7 +18,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_tooltips()
         .into_drawing_area();
 
-    root.fill(&WHITE);
+    root.fill(&WHITE)?;
 
     let mut chart = ChartBuilder::on(&root)
         .caption("Interactive Tooltips Demo", ("sans-serif", 28).into_font())
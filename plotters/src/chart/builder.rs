@@ -1,4 +1,4 @@
-use super::context::ChartContext;
+use super::context::{cartesian2d, ChartContext};
 
 use crate::coord::cartesian::{Cartesian2d, Cartesian3d};
 use crate::coord::ranged1d::AsRangedCoord;
@@ -433,6 +433,8 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             )),
             series_anno: vec![],
             next_series_id: 0,
+            point_formatter: None,
+            continuous_mapping: Some(cartesian2d::continuous_mapping),
             drawing_area_pos: (
                 actual_drawing_area_pos[2] + title_dx + self.margin[2] as i32,
                 actual_drawing_area_pos[0] + title_dy + self.margin[0] as i32,
@@ -493,6 +495,8 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             )),
             series_anno: vec![],
             next_series_id: 0,
+            point_formatter: None,
+            continuous_mapping: None,
             drawing_area_pos: (
                 title_dx + self.margin[2] as i32,
                 title_dy + self.margin[0] as i32,
@@ -568,6 +572,36 @@ mod test {
         assert_eq!(chart.title.as_ref().unwrap().1.font.get_name(), "serif");
     }
 
+    #[test]
+    fn test_build_with_unformattable_axes() {
+        use crate::coord::ranged1d::{KeyPointHint, NoDefaultFormatting, Ranged};
+        use std::ops::Range;
+
+        // An axis without any value formatter can still be used to draw series
+        struct Plain(Range<i32>);
+        impl Ranged for Plain {
+            type FormatOption = NoDefaultFormatting;
+            type ValueType = i32;
+            fn map(&self, value: &i32, limit: (i32, i32)) -> i32 {
+                limit.0 + (limit.1 - limit.0) * (value - self.0.start) / (self.0.end - self.0.start)
+            }
+            fn key_points<Hint: KeyPointHint>(&self, _hint: Hint) -> Vec<i32> {
+                vec![]
+            }
+            fn range(&self) -> Range<i32> {
+                self.0.clone()
+            }
+        }
+
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(Plain(0..10), Plain(0..10))
+            .unwrap();
+        chart
+            .draw_series(std::iter::once(Circle::new((5, 5), 5, RED)))
+            .unwrap();
+    }
+
     #[test]
     fn test_zero_limit_with_log_scale() {
         let drawing_area = create_mocked_drawing_area(640, 480, |_| {});
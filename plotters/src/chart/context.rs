@@ -1,11 +1,12 @@
 use std::borrow::Borrow;
 
-use plotters_backend::{BackendColor, BackendCoord, DrawingBackend, ElementContext};
+use plotters_backend::{BackendColor, BackendCoord, DrawingBackend, ElementContext, Interpolation};
 
 use crate::chart::{SeriesAnno, SeriesLabelStyle};
 use crate::coord::{CoordTranslate, ReverseCoordTranslate, Shift};
 use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
 use crate::element::{CoordMapper, Drawable, PointCollection};
+use crate::style::Color;
 
 pub(super) mod cartesian2d;
 pub(super) mod cartesian3d;
@@ -30,8 +31,30 @@ pub struct ChartContext<'a, DB: DrawingBackend, CT: CoordTranslate> {
     pub(crate) drawing_area_pos: (i32, i32),
     /// Monotonically increasing series id used by `begin_context`.
     pub(crate) next_series_id: usize,
+    /// Formats a guest coordinate into one label per axis for the per-element contexts. It's
+    /// installed by the methods which know how to format the values, e.g. `configure_mesh`.
+    pub(crate) point_formatter: Option<PointFormatter<CT>>,
+    /// Maps the pixels of the plotting area linearly back to the axis values, if the coordinate
+    /// system supports it. Lines then carry them next to their vertex labels.
+    pub(crate) continuous_mapping: Option<ContinuousMapping<CT>>,
 }
 
+/// Formats a guest coordinate into one label per axis.
+pub(crate) type PointFormatter<CT> = fn(&CT, &<CT as CoordTranslate>::From) -> Vec<String>;
+
+/// The colour of a series which isn't known when it's opened, the backends take the colour of
+/// the elements actually drawn instead.
+pub(crate) const UNKNOWN_COLOR: BackendColor = BackendColor {
+    alpha: 0.0,
+    rgb: (0, 0, 0),
+};
+
+/// Computes the continuous x and y interpolations of a coordinate system, if there's one.
+pub(crate) type ContinuousMapping<CT> = fn(&CT) -> Option<(Interpolation, Interpolation)>;
+
+/// Computes the user supplied key/value metadata of a data point from its guest coordinate.
+pub(crate) type PointMetadata<'f, T> = &'f dyn Fn(&T) -> Vec<(String, String)>;
+
 impl<'a, DB: DrawingBackend, CT: ReverseCoordTranslate> ChartContext<'a, DB, CT> {
     /// Convert the chart context into an closure that can be used for coordinate translation
     pub fn into_coord_trans(self) -> impl Fn(BackendCoord) -> Option<CT::From> {
@@ -94,6 +117,8 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
     pub(crate) fn draw_series_impl<B, E, R, S>(
         &mut self,
         series: S,
+        series_id: usize,
+        metadata: Option<PointMetadata<CT::From>>,
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
     where
         B: CoordMapper,
@@ -102,21 +127,209 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
-        for element in series {
-            self.drawing_area.draw(element.borrow())?;
+        // Formatting every coordinate is not free, so the per-element contexts are only emitted
+        // when the backend actually consumes them.
+        let context_aware = self.drawing_area.is_context_aware();
+        // The series are clipped to the plotting area by the backend if it can, otherwise their
+        // coordinates are truncated to it
+        let clipped = self.drawing_area.push_clip()?;
+        let draw = || {
+            for element in series {
+                let element = element.borrow();
+                let opened =
+                    context_aware && self.begin_element_context(element, series_id, metadata)?;
+                let drawn = if clipped {
+                    self.drawing_area.draw_clipped(element)
+                } else {
+                    self.drawing_area.draw(element)
+                };
+                // The context is closed even if the drawing failed, so the backend stays balanced
+                if opened {
+                    let ended = self.drawing_area.end_context();
+                    drawn.and(ended)?;
+                } else {
+                    drawn?;
+                }
+            }
+            Ok(())
+        };
+        let result = draw();
+        // The clip region is removed even if the drawing failed, so the backend stays usable
+        if clipped {
+            let popped = self.drawing_area.pop_clip();
+            return result.and(popped);
+        }
+        result
+    }
+
+    /// Open the semantic context for a single element of a series.
+    ///
+    /// Single-point elements get a [`ElementContext::DataPoint`] context, multi-point elements a
+    /// [`ElementContext::DataLine`] context carrying every vertex with its formatted label, along
+    /// with the continuous mapping of the coordinate system if there's one.
+    /// Returns whether a context has been opened.
+    fn begin_element_context<'b, B, E>(
+        &self,
+        element: &'b E,
+        series_id: usize,
+        metadata: Option<PointMetadata<CT::From>>,
+    ) -> Result<bool, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        B: CoordMapper,
+        &'b E: PointCollection<'b, CT::From, B>,
+    {
+        let coord_spec = self.drawing_area.as_coord_spec();
+        // The metadata only applies to single-point elements, thus it's only computed once
+        let mut point_metadata = None;
+        let mapped: Vec<_> = element
+            .point_iter()
+            .into_iter()
+            .enumerate()
+            .map(|(idx, pt)| {
+                let guest = pt.borrow();
+                if idx == 0 {
+                    point_metadata = metadata.map(|f| f(guest));
+                }
+                let labels = self
+                    .point_formatter
+                    .map_or_else(Vec::new, |fmt| fmt(coord_spec, guest));
+                (self.drawing_area.map_coordinate(guest), labels)
+            })
+            .collect();
+
+        if mapped.len() > 1 {
+            let mut x_points = Vec::with_capacity(mapped.len());
+            let mut y_points = Vec::with_capacity(mapped.len());
+            let mut z_points = vec![];
+            for (coord, labels) in mapped {
+                let mut labels = labels.into_iter();
+                x_points.push((coord.0, labels.next().unwrap_or_default()));
+                y_points.push((coord.1, labels.next().unwrap_or_default()));
+                if let Some(z_label) = labels.next() {
+                    z_points.push((coord.1, z_label));
+                }
+            }
+            self.drawing_area.begin_context(
+                ElementContext::data_line(
+                    Interpolation::Discrete { points: x_points },
+                    Interpolation::Discrete { points: y_points },
+                    series_id,
+                )
+                .with_z_interpolation(if z_points.is_empty() {
+                    None
+                } else {
+                    Some(Interpolation::Discrete { points: z_points })
+                })
+                .with_continuous_interpolation(
+                    self.continuous_mapping
+                        .and_then(|mapping| mapping(coord_spec)),
+                ),
+            )?;
+            return Ok(true);
         }
-        Ok(())
+
+        if let Some((coord, labels)) = mapped.into_iter().next() {
+            let mut labels = labels.into_iter();
+            self.drawing_area.begin_context(
+                ElementContext::data_point(
+                    coord,
+                    labels.next().unwrap_or_default(),
+                    labels.next().unwrap_or_default(),
+                    series_id,
+                )
+                .with_z_label(labels.next())
+                .with_metadata(point_metadata.unwrap_or_default()),
+            )?;
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+
+    /// Draw a series wrapped in a [`ElementContext::DataSeries`] context, so interactive backends
+    /// can group the elements visually.
+    pub(crate) fn draw_data_series<B, E, R, S>(
+        &mut self,
+        series: S,
+        series_id: usize,
+        color: BackendColor,
+        label: String,
+        metadata: Option<PointMetadata<CT::From>>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<DB, B>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        let (x, y) = self.drawing_area.get_pixel_range();
+        self.drawing_area.begin_context(
+            ElementContext::data_series(series_id, color, label)
+                .with_plot_area(Some(((x.start, y.start), (x.end - 1, y.end - 1)))),
+        )?;
+        let drawn = self.draw_series_impl(series, series_id, metadata);
+        let ended = self.drawing_area.end_context();
+        drawn.and(ended)
+    }
+
+    /// Draw a series with tooltips, attaching extra key/value metadata to every data point.
+    ///
+    /// This works like `draw_series_with_tooltips`, except that `metadata` is called with the
+    /// guest coordinate of each single-point element (markers, circles) and the returned pairs
+    /// are forwarded in the `metadata` field of its data point context, e.g. a sample count or a
+    /// URL to drill into. Interactive backends such as the SVG backend show them in the tooltip.
+    /// Multi-point elements don't carry metadata. The coordinates are formatted once the axes
+    /// have been configured, e.g. with `configure_mesh`.
+    pub fn draw_series_with_metadata<B, E, R, S, C, F>(
+        &mut self,
+        series: S,
+        series_color: &C,
+        series_label: &str,
+        metadata: F,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<DB, B>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+        C: Color,
+        F: Fn(&CT::From) -> Vec<(String, String)>,
+    {
+        let series_id = self.alloc_series_id();
+        self.draw_data_series(
+            series,
+            series_id,
+            series_color.to_backend_color(),
+            series_label.to_string(),
+            Some(&metadata),
+        )?;
+        Ok(self.alloc_series_anno(series_id))
+    }
+
+    pub(crate) fn alloc_series_id(&mut self) -> usize {
+        let series_id = self.next_series_id;
+        self.next_series_id += 1;
+        series_id
     }
 
-    pub(crate) fn alloc_series_anno(&mut self) -> &mut SeriesAnno<'a, DB> {
+    pub(crate) fn alloc_series_anno(&mut self, series_id: usize) -> &mut SeriesAnno<'a, DB> {
+        let area = self.drawing_area.strip_coord_spec();
         let idx = self.series_anno.len();
-        self.series_anno.push(SeriesAnno::new());
+        self.series_anno
+            .push(SeriesAnno::new(Some((series_id, area))));
         &mut self.series_anno[idx]
     }
 
     /**
     Draws a data series. A data series in Plotters is abstracted as an iterator of elements.
 
+    When the backend is context aware, each element is wrapped in a semantic context, the same way
+    [`ChartContext::draw_series_with_tooltips()`] does. Its coordinates are formatted once the
+    axes have been configured, e.g. with `configure_mesh`. The series label assigned through
+    [`SeriesAnno::label()`] is forwarded to the backend as well.
+
     See [`crate::series::LineSeries`] and [`ChartContext::configure_series_labels()`] for more information and examples.
     */
     pub fn draw_series<B, E, R, S>(
@@ -130,22 +343,9 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
-        let series_id = self.next_series_id;
-        self.next_series_id += 1;
-        // Wrap the series in a DatSeries context so interactive backends can group the elements
-        // visually.
-        self.drawing_area
-            .begin_context(ElementContext::DataSeries {
-                id: series_id,
-                color: BackendColor {
-                    alpha: 1.,
-                    rgb: (0, 0, 0),
-                },
-                label: String::new(),
-            })?;
-        self.draw_series_impl(series)?;
-        self.drawing_area.end_context()?;
-        Ok(self.alloc_series_anno())
+        let series_id = self.alloc_series_id();
+        self.draw_data_series(series, series_id, UNKNOWN_COLOR, String::new(), None)?;
+        Ok(self.alloc_series_anno(series_id))
     }
 }
 
@@ -202,6 +402,207 @@ mod test {
             .expect("Drawing error");
     }
 
+    #[test]
+    fn test_draw_series_emits_contexts() {
+        use plotters_backend::ElementContext;
+
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.context_aware = true;
+            m.drop_check(|b| {
+                let series_ids: Vec<_> = b
+                    .contexts
+                    .iter()
+                    .filter_map(|c| match c {
+                        ElementContext::DataSeries {
+                            id,
+                            color,
+                            plot_area,
+                            ..
+                        } => {
+                            assert_eq!(color.alpha, 0.0);
+                            assert_eq!(*plot_area, Some(((20, 20), (179, 179))));
+                            Some(*id)
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                assert_eq!(series_ids, vec![0, 1, 2]);
+
+                assert!(b.contexts.iter().any(|c| matches!(c,
+                    ElementContext::DataPoint { x_label, y_label, series_id: 0, .. }
+                        if x_label == "5" && y_label == "5")));
+                assert!(b.contexts.iter().any(|c| matches!(c,
+                    ElementContext::DataLine {
+                        x_interpolation: Interpolation::Discrete { points },
+                        continuous_interpolation: Some((
+                            Interpolation::Continuous { value_range, .. },
+                            Interpolation::Continuous { .. },
+                        )),
+                        series_id: 1,
+                        ..
+                    } if points[2].1 == "2" && *value_range == (0.0, 10.0))));
+                assert!(b.contexts.iter().any(|c| matches!(c,
+                    ElementContext::Axis {
+                        axis_id,
+                        interpolation: Some(Interpolation::Continuous { value_range, .. }),
+                    } if axis_id == "y" && *value_range == (0.0, 10.0))));
+                assert!(b
+                    .contexts
+                    .iter()
+                    .any(|c| matches!(c, ElementContext::DataPoint { series_id: 2, .. })));
+
+                assert!(b.contexts.iter().any(|c| matches!(c,
+                    ElementContext::LegendEntry { series_id: 1, label, .. } if label == "Line")));
+
+                assert_eq!(
+                    b.updated_contexts,
+                    vec![ElementContext::data_series(
+                        1,
+                        super::UNKNOWN_COLOR,
+                        "Line".to_string()
+                    )]
+                );
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .set_all_label_area_size(20)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        chart.configure_mesh().draw().expect("Draw mesh");
+
+        chart
+            .draw_series(std::iter::once(Circle::new((5, 5), 5, RED)))
+            .expect("Drawing error");
+        chart
+            .draw_series(LineSeries::new(vec![(0, 0), (1, 1), (2, 4)], BLUE))
+            .expect("Drawing error")
+            .label("Line");
+        chart
+            .configure_series_labels()
+            .draw()
+            .expect("Drawing error");
+
+        let mut chart = chart.set_secondary_coord(0.0..1.0, 0.0..1.0);
+        chart
+            .draw_secondary_series(std::iter::once(Circle::new((0.3, 0.8), 5, GREEN)))
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_draw_series_labels_need_formatter() {
+        use plotters_backend::ElementContext;
+
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.context_aware = true;
+            m.drop_check(|b| {
+                // The chart only learns how to format the values when the axes are configured
+                assert!(b.contexts.iter().any(|c| matches!(c,
+                    ElementContext::DataPoint { x_label, y_label, series_id: 0, .. }
+                        if x_label.is_empty() && y_label.is_empty())));
+                assert!(b.contexts.iter().any(|c| matches!(c,
+                    ElementContext::DataPoint { x_label, y_label, series_id: 1, .. }
+                        if x_label == "5" && y_label == "5")));
+                assert!(b.contexts.iter().any(|c| matches!(c,
+                    ElementContext::DataPoint { x_label, y_label, series_id: 2, .. }
+                        if !x_label.is_empty() && !y_label.is_empty())));
+            });
+        });
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        let mut chart = chart.set_secondary_coord(0.0..1.0, 0.0..1.0);
+
+        chart
+            .draw_series(std::iter::once(Circle::new((5, 5), 5, RED)))
+            .expect("Drawing error");
+        chart.configure_mesh();
+        chart.configure_secondary_axes();
+        chart
+            .draw_series(std::iter::once(Circle::new((5, 5), 5, RED)))
+            .expect("Drawing error");
+        chart
+            .draw_secondary_series(std::iter::once(Circle::new((0.3, 0.8), 5, GREEN)))
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_draw_series_with_tooltips_3d() {
+        use plotters_backend::ElementContext;
+
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.context_aware = true;
+            m.drop_check(|b| {
+                assert!(b.contexts.iter().any(|c| matches!(c,
+                    ElementContext::DataSeries { id: 0, color, label, .. }
+                        if label == "Points" && color.alpha > 0.0)));
+                assert!(b.contexts.iter().any(|c| matches!(c,
+                    ElementContext::DataPoint { x_label, y_label, z_label: Some(z_label), .. }
+                        if x_label == "1" && y_label == "2" && z_label == "3")));
+                assert!(b.contexts.iter().any(|c| matches!(c,
+                    ElementContext::DataLine {
+                        z_interpolation: Some(Interpolation::Discrete { points }),
+                        series_id: 1,
+                        ..
+                    } if points.len() == 2 && points[1].1 == "4")));
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_3d(0..10, 0..10, 0..10)
+            .expect("Create chart");
+        chart.configure_axes().draw().expect("Draw axis");
+
+        chart
+            .draw_series_with_tooltips(
+                std::iter::once(Circle::new((1, 2, 3), 5, RED)),
+                &RED,
+                "Points",
+            )
+            .expect("Drawing error");
+        chart
+            .draw_series_with_tooltips(
+                LineSeries::new(vec![(0, 0, 0), (1, 1, 4)], BLUE),
+                &BLUE,
+                "Line",
+            )
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_draw_series_with_metadata_3d() {
+        use plotters_backend::ElementContext;
+
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.context_aware = true;
+            m.drop_check(|b| {
+                assert!(b.contexts.iter().any(|c| matches!(c,
+                    ElementContext::DataSeries { id: 0, color, label, .. }
+                        if label == "Points" && color.alpha > 0.0)));
+                assert!(b.contexts.iter().any(|c| matches!(c,
+                    ElementContext::DataPoint {
+                        x_label, y_label, z_label: Some(z_label), metadata, ..
+                    } if x_label == "1" && y_label == "2" && z_label == "3"
+                        && metadata == &[("sum".to_string(), "6".to_string())])));
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_3d(0..10, 0..10, 0..10)
+            .expect("Create chart");
+        chart.configure_axes().draw().expect("Draw axis");
+
+        chart
+            .draw_series_with_metadata(
+                std::iter::once(Circle::new((1, 2, 3), 5, RED)),
+                &RED,
+                "Points",
+                |(x, y, z)| vec![("sum".to_string(), (x + y + z).to_string())],
+            )
+            .expect("Drawing error");
+    }
+
     #[test]
     fn test_chart_context_3d() {
         let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
@@ -2,10 +2,11 @@ use std::ops::Range;
 
 use plotters_backend::{DrawingBackend, ElementContext, Interpolation};
 
+use super::continuous_interpolation;
 use crate::chart::ChartContext;
 use crate::coord::{
     cartesian::{Cartesian2d, MeshLine},
-    ranged1d::{KeyPointHint, Ranged},
+    ranged1d::{KeyPointHint, KeyPointWeight, Ranged},
     Shift,
 };
 use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
@@ -33,6 +34,9 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         let mut y_labels = vec![];
         let xr = self.drawing_area.as_coord_spec().x_spec();
         let yr = self.drawing_area.as_coord_spec().y_spec();
+        self.drawing_area.begin_context(ElementContext::Grid {
+            bold: matches!(c.weight(), KeyPointWeight::Bold),
+        })?;
         self.drawing_area.draw_mesh(
             |b, l| {
                 let draw = match l {
@@ -58,6 +62,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
             r,
             c,
         )?;
+        self.drawing_area.end_context()?;
         Ok((x_labels, y_labels))
     }
 
@@ -138,6 +143,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         orientation: (i16, i16),
         axis_desc: Option<(&str, &TextStyle)>,
         tick_size: i32,
+        continuous: Option<Interpolation>,
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         let area = if let Some(target) = area {
             target
@@ -151,7 +157,10 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         } else {
             "y".to_string()
         };
-        let interp = if !labels.is_empty() {
+        // Numeric axes can be evaluated at any pixel, other ones only at their ticks
+        let interp = if continuous.is_some() {
+            continuous
+        } else if !labels.is_empty() {
             Some(Interpolation::Discrete {
                 points: labels.to_vec(),
             })
@@ -203,7 +212,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         let right_align_width = (min_width * 2).min(max_width);
 
         /* Then we need to draw the tick mark and the label */
-        for ((p, t), w) in labels.iter().zip(label_width.into_iter()) {
+        for ((p, t), w) in labels.iter().zip(label_width) {
             /* Make sure we are actually in the visible range */
             let rp = if orientation.0 == 0 { *p - x0 } else { *p - y0 };
 
@@ -325,6 +334,10 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         let (x_labels, y_labels) =
             self.draw_mesh_lines((r, c), (x_mesh, y_mesh), mesh_line_style, fmt_label)?;
 
+        let coord = self.drawing_area.as_coord_spec();
+        let x_continuous = continuous_interpolation(coord.x_spec(), coord.back_x);
+        let y_continuous = continuous_interpolation(coord.y_spec(), coord.back_y);
+
         for idx in 0..2 {
             self.draw_axis_and_labels(
                 self.x_label_area[idx].as_ref(),
@@ -335,6 +348,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
                 (0, -1 + idx as i16 * 2),
                 x_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
                 x_tick_size[idx],
+                x_continuous.clone(),
             )?;
 
             self.draw_axis_and_labels(
@@ -346,6 +360,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
                 (-1 + idx as i16 * 2, 0),
                 y_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
                 y_tick_size[idx],
+                y_continuous.clone(),
             )?;
         }
 
@@ -417,4 +432,3 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         Ok(())
     }
 }
-
@@ -14,6 +14,29 @@ use plotters_backend::{BackendCoord, DrawingBackend, Interpolation};
 
 mod draw_impl;
 
+/// The continuous interpolation of an axis mapping its values linearly to `backend_range`.
+pub(crate) fn continuous_interpolation<R: Ranged>(
+    spec: &R,
+    backend_range: (i32, i32),
+) -> Option<Interpolation> {
+    spec.linear_value_range()
+        .map(|value_range| Interpolation::Continuous {
+            backend_range,
+            value_range,
+            units: String::new(),
+        })
+}
+
+/// The continuous interpolation of both axes, available if they are both mapped linearly.
+pub(crate) fn continuous_mapping<X: Ranged, Y: Ranged>(
+    coord: &Cartesian2d<X, Y>,
+) -> Option<(Interpolation, Interpolation)> {
+    Some((
+        continuous_interpolation(coord.x_spec(), coord.back_x)?,
+        continuous_interpolation(coord.y_spec(), coord.back_y)?,
+    ))
+}
+
 impl<'a, DB, XT, YT, X, Y> ChartContext<'a, DB, Cartesian2d<X, Y>>
 where
     DB: DrawingBackend,
@@ -41,9 +64,20 @@ where
         }
     }
 
+    /// Format a guest coordinate into its x and y labels.
+    pub(crate) fn format_point(coord: &Cartesian2d<X, Y>, point: &(XT, YT)) -> Vec<String> {
+        vec![
+            X::format_ext(coord.x_spec(), &point.0),
+            Y::format_ext(coord.y_spec(), &point.1),
+        ]
+    }
+
     /// Initialize a mesh configuration object and mesh drawing can be finalized by calling
     /// the function `MeshStyle::draw`.
     pub fn configure_mesh(&mut self) -> MeshStyle<'a, '_, X, Y, DB> {
+        // Both axes are known to be formattable from here on, so series drawn with
+        // `draw_series` can carry formatted labels in their semantic contexts.
+        self.point_formatter = Some(Self::format_point);
         MeshStyle::new(self)
     }
 
@@ -51,17 +85,23 @@ where
     ///
     /// This is the tooltip-aware counterpart of [`ChartContext::draw_series`].
     /// Each element in the iterator must yield guest coordinates `(XT, YT)`.
-    /// The method inspects each element's points:
+    /// When the backend is context aware, the method inspects each element's points:
     ///
     /// - **Single-point** elements (markers, circles) are wrapped in a
-    /// [`ElementContext::DataPoint`] context with formatted x/y labels.
+    ///   [`ElementContext::DataPoint`] context with formatted x/y labels.
     /// - **Multi-point** elements (lines, paths) are wrapped in a [`ElementContext::DataLine`]
-    /// context that carries discrete interpolation data (every vertex with its formatted label).
+    ///   context carrying every vertex with its formatted label. If both axes map their values
+    ///   linearly (numeric ranges), it also carries continuous interpolations so the values can
+    ///   be evaluated anywhere along the line.
     ///
     /// The whole series is wrapped in a [`ElementContext::DataSeries`] context so interactive
     /// backends can group and style them.
     ///
     /// `series_color` and `series_label` describe the series metadata forwarded to the backend.
+    ///
+    /// [`ElementContext::DataPoint`]: plotters_backend::ElementContext::DataPoint
+    /// [`ElementContext::DataLine`]: plotters_backend::ElementContext::DataLine
+    /// [`ElementContext::DataSeries`]: plotters_backend::ElementContext::DataSeries
     pub fn draw_series_with_tooltips<B, E, R, S, C>(
         &mut self,
         series: S,
@@ -76,71 +116,16 @@ where
         S: IntoIterator<Item = R>,
         C: Color,
     {
-        let series_id = self.next_series_id;
-        self.next_series_id += 1;
-
-        let bc = series_color.to_backend_color();
-        self.drawing_area
-            .begin_context(plotters_backend::ElementContext::DataSeries {
-                id: series_id,
-                color: bc,
-                label: series_label.to_string(),
-            })?;
-
-        let x_spec = self.drawing_area.as_coord_spec().x_spec();
-        let y_spec = self.drawing_area.as_coord_spec().y_spec();
-
-        for element in series {
-            let elem = element.borrow();
-
-            // Collect all guest points and map them to backend coords + labels
-            let mapped: Vec<_> = elem
-                .point_iter()
-                .into_iter()
-                .map(|pt| {
-                    let guest = pt.borrow();
-                    let xl = X::format_ext(x_spec, &guest.0);
-                    let yl = Y::format_ext(y_spec, &guest.1);
-                    let coord = self.drawing_area.map_coordinate(guest);
-                    (coord, xl, yl)
-                })
-                .collect();
-
-            let opened = if mapped.len() <= 1 {
-                // Single-point element->DataPoint context
-                if let Some((coord, xl, yl)) = mapped.into_iter().next() {
-                    self.drawing_area.begin_context(
-                        plotters_backend::ElementContext::DataPoint {
-                            coord,
-                            x_label: xl,
-                            y_label: yl,
-                            series_id,
-                        },
-                    )?;
-                    true
-                } else {
-                    false
-                }
-            } else {
-                // Multi-point element -> DataLine context with discrete interpolation (every vertex
-                // carries a formatted label).
-                let x_points: Vec<_> = mapped.iter().map(|(c, xl, _)| (c.0, xl.clone())).collect();
-                let y_points: Vec<_> = mapped.iter().map(|(c, _, yl)| (c.1, yl.clone())).collect();
-                self.drawing_area
-                    .begin_context(plotters_backend::ElementContext::DataLine {
-                        x_interpolation: Interpolation::Discrete { points: x_points },
-                        y_interpolation: Interpolation::Discrete { points: y_points },
-                        series_id,
-                    })?;
-                true
-            };
-            self.drawing_area.draw(elem)?;
-            if opened {
-                self.drawing_area.end_context()?;
-            }
-        }
-        self.drawing_area.end_context()?; // close DataSeries
-        Ok(self.alloc_series_anno())
+        self.point_formatter = Some(Self::format_point);
+        let series_id = self.alloc_series_id();
+        self.draw_data_series(
+            series,
+            series_id,
+            series_color.to_backend_color(),
+            series_label.to_string(),
+            None,
+        )?;
+        Ok(self.alloc_series_anno(series_id))
     }
 }
 
@@ -183,6 +168,9 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         let mut pixel_range = self.drawing_area.get_pixel_range();
         pixel_range.1 = pixel_range.1.end..pixel_range.1.start;
 
-        DualCoordChartContext::new(self, Cartesian2d::new(x_coord, y_coord, pixel_range))
+        let mut ret =
+            DualCoordChartContext::new(self, Cartesian2d::new(x_coord, y_coord, pixel_range));
+        ret.secondary.continuous_mapping = Some(continuous_mapping);
+        ret
     }
 }
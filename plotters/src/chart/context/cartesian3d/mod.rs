@@ -1,9 +1,14 @@
-use crate::chart::{axes3d::Axes3dStyle, ChartContext};
+use std::borrow::Borrow;
+
+use crate::chart::{axes3d::Axes3dStyle, ChartContext, SeriesAnno};
 use crate::coord::{
     cartesian::Cartesian3d,
     ranged1d::{Ranged, ValueFormatter},
     ranged3d::{ProjectionMatrix, ProjectionMatrixBuilder},
 };
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{CoordMapper, Drawable, PointCollection};
+use crate::style::Color;
 use plotters_backend::DrawingBackend;
 
 mod draw_impl;
@@ -95,8 +100,52 @@ where
     [`ChartContext::configure_mesh()`], a similar function for 2D plots
     */
     pub fn configure_axes(&mut self) -> Axes3dStyle<'a, '_, X, Y, Z, DB> {
+        self.point_formatter = Some(Self::format_point);
         Axes3dStyle::new(self)
     }
+
+    /// Format a guest coordinate into its x, y and z labels.
+    pub(crate) fn format_point(coord: &Cartesian3d<X, Y, Z>, point: &(XT, YT, ZT)) -> Vec<String> {
+        vec![
+            X::format_ext(&coord.logic_x, &point.0),
+            Y::format_ext(&coord.logic_y, &point.1),
+            Z::format_ext(&coord.logic_z, &point.2),
+        ]
+    }
+
+    /// Draw a series while emitting semantic contexts for every element.
+    ///
+    /// This is the 3D counterpart of the 2D `draw_series_with_tooltips`: each element must yield
+    /// guest coordinates `(XT, YT, ZT)`, and the data point and data line contexts carry the
+    /// formatted z label next to the x and y labels. The positions in the contexts are the
+    /// projected pixel coordinates, so tooltips follow the current projection.
+    ///
+    /// `series_color` and `series_label` describe the series metadata forwarded to the backend.
+    pub fn draw_series_with_tooltips<B, E, R, S, C>(
+        &mut self,
+        series: S,
+        series_color: &C,
+        series_label: &str,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, (XT, YT, ZT), B>,
+        E: Drawable<DB, B>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+        C: Color,
+    {
+        self.point_formatter = Some(Self::format_point);
+        let series_id = self.alloc_series_id();
+        self.draw_data_series(
+            series,
+            series_id,
+            series_color.to_backend_color(),
+            series_label.to_string(),
+            None,
+        )?;
+        Ok(self.alloc_series_anno(series_id))
+    }
 }
 
 impl<'a, DB, X: Ranged, Y: Ranged, Z: Ranged> ChartContext<'a, DB, Cartesian3d<X, Y, Z>>
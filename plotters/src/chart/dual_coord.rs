@@ -3,6 +3,7 @@ use std::borrow::{Borrow, BorrowMut};
 use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
 
+use super::context::UNKNOWN_COLOR;
 use super::mesh::SecondaryMeshStyle;
 use super::{ChartContext, ChartState, SeriesAnno};
 
@@ -122,6 +123,8 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
                 drawing_area: secondary_drawing_area,
                 series_anno: vec![],
                 next_series_id: 0,
+                point_formatter: None,
+                continuous_mapping: None,
                 drawing_area_pos: (0, 0),
             },
         }
@@ -184,6 +187,8 @@ where
 {
     /// Start configure the style for the secondary axes
     pub fn configure_secondary_axes<'b>(&'b mut self) -> SecondaryMeshStyle<'a, 'b, SX, SY, DB> {
+        self.secondary.point_formatter =
+            Some(ChartContext::<DB, Cartesian2d<SX, SY>>::format_point);
         SecondaryMeshStyle::new(&mut self.secondary)
     }
 }
@@ -204,8 +209,11 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged, SX: Ranged, SY: Ranged>
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
-        self.secondary.draw_series_impl(series)?;
-        Ok(self.primary.alloc_series_anno())
+        // Series ids are shared by both coordinate systems, since they end up on the same backend
+        let series_id = self.primary.alloc_series_id();
+        self.secondary
+            .draw_data_series(series, series_id, UNKNOWN_COLOR, String::new(), None)?;
+        Ok(self.primary.alloc_series_anno(series_id))
     }
 }
 
@@ -1,10 +1,11 @@
+use super::context::UNKNOWN_COLOR;
 use super::ChartContext;
-use crate::coord::CoordTranslate;
-use crate::drawing::DrawingAreaErrorKind;
+use crate::coord::{CoordTranslate, Shift};
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
 use crate::element::{DynElement, EmptyElement, IntoDynElement, MultiLineText, Rectangle};
 use crate::style::{IntoFont, IntoTextStyle, ShapeStyle, SizeDesc, TextStyle, TRANSPARENT};
 
-use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind, ElementContext};
 
 type SeriesAnnoDrawFn<'a, DB> = dyn Fn(BackendCoord) -> DynElement<'a, DB, BackendCoord> + 'a;
 
@@ -14,6 +15,9 @@ type SeriesAnnoDrawFn<'a, DB> = dyn Fn(BackendCoord) -> DynElement<'a, DB, Backe
 pub struct SeriesAnno<'a, DB: DrawingBackend> {
     label: Option<String>,
     draw_func: Option<Box<SeriesAnnoDrawFn<'a, DB>>>,
+    /// The id of the `DataSeries` context emitted for this series, along with the area used to
+    /// reach the backend when the label is assigned afterwards.
+    series: Option<(usize, DrawingArea<DB, Shift>)>,
 }
 
 impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
@@ -27,10 +31,15 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
         self.draw_func.as_ref().map(|x| x.as_ref())
     }
 
-    pub(crate) fn new() -> Self {
+    pub(crate) fn get_series_id(&self) -> Option<usize> {
+        self.series.as_ref().map(|(id, _)| *id)
+    }
+
+    pub(crate) fn new(series: Option<(usize, DrawingArea<DB, Shift>)>) -> Self {
         Self {
             label: None,
             draw_func: None,
+            series,
         }
     }
 
@@ -40,7 +49,20 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
     See [`ChartContext::configure_series_labels()`] for more information and examples.
     */
     pub fn label<L: Into<String>>(&mut self, label: L) -> &mut Self {
-        self.label = Some(label.into());
+        let label = label.into();
+        if let Some((id, area)) = self.series.as_ref() {
+            // The series has already been drawn at this point, thus we ask the backend to patch
+            // the label into the context it has recorded. This is best-effort: the label is still
+            // drawn in the legend if the backend fails to record it.
+            if area.is_context_aware() {
+                let _ = area.update_context(ElementContext::data_series(
+                    *id,
+                    UNKNOWN_COLOR,
+                    label.clone(),
+                ));
+            }
+        }
+        self.label = Some(label);
         self
     }
 
@@ -238,6 +260,7 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
 
         let mut label_element = MultiLineText::<_, &str>::new((0, 0), &font);
         let mut funcs = vec![];
+        let mut entries = vec![];
 
         for anno in self.target.series_anno.iter() {
             let label_text = anno.get_label();
@@ -248,6 +271,7 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             }
 
             funcs.push(draw_func.unwrap_or(&|p: BackendCoord| EmptyElement::at(p).into_dyn()));
+            entries.push((anno.get_series_id(), label_text));
             label_element.push_line(label_text);
         }
 
@@ -279,16 +303,31 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         ))?;
         drawing_area.draw(&label_element)?;
 
-        for (((_, y0), (_, y1)), make_elem) in label_element
+        for ((((_, y0), (_, y1)), make_elem), (series_id, label)) in label_element
             .compute_line_layout()
             .map_err(|e| {
                 DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)))
             })?
             .into_iter()
-            .zip(funcs.into_iter())
+            .zip(funcs)
+            .zip(entries)
         {
+            // Link the row to its series, so interactive backends can toggle the series
+            if let Some(series_id) = series_id {
+                drawing_area.begin_context(ElementContext::LegendEntry {
+                    series_id,
+                    label: label.to_string(),
+                    area: (
+                        drawing_area.map_coordinate(&(label_x, y0)),
+                        drawing_area.map_coordinate(&(label_x + w, y1)),
+                    ),
+                })?;
+            }
             let legend_element = make_elem((label_x + margin, (y0 + y1) / 2));
             drawing_area.draw(&legend_element)?;
+            if series_id.is_some() {
+                drawing_area.end_context()?;
+            }
         }
 
         Ok(())
@@ -107,6 +107,8 @@ impl<CT: CoordTranslate> ChartState<CT> {
             drawing_area: area.apply_coord_spec(self.coord),
             series_anno: vec![],
             next_series_id: 0,
+            point_formatter: None,
+            continuous_mapping: None,
             drawing_area_pos: self.drawing_area_pos,
         }
     }
@@ -64,6 +64,10 @@ where
         self.inner.map(value, limit)
     }
 
+    fn map_f64(&self, value: &Self::ValueType, limit: (i32, i32)) -> f64 {
+        self.inner.map_f64(value, limit)
+    }
+
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
         if hint.weight().allow_light_points() {
             self.light_points.clone()
@@ -75,6 +79,10 @@ where
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
         self.inner.axis_pixel_range(limit)
     }
+
+    fn linear_value_range(&self) -> Option<(f64, f64)> {
+        self.inner.linear_value_range()
+    }
 }
 
 impl<R: DiscreteRanged> DiscreteRanged for WithKeyPoints<R>
@@ -189,6 +197,10 @@ impl<R: Ranged> Ranged for WithKeyPointMethod<R> {
         self.inner.map(value, limit)
     }
 
+    fn map_f64(&self, value: &Self::ValueType, limit: (i32, i32)) -> f64 {
+        self.inner.map_f64(value, limit)
+    }
+
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
         if hint.weight().allow_light_points() {
             (self.light_func)(hint.max_num_points())
@@ -200,6 +212,10 @@ impl<R: Ranged> Ranged for WithKeyPointMethod<R> {
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
         self.inner.axis_pixel_range(limit)
     }
+
+    fn linear_value_range(&self) -> Option<(f64, f64)> {
+        self.inner.linear_value_range()
+    }
 }
 
 impl<R: DiscreteRanged> DiscreteRanged for WithKeyPointMethod<R> {
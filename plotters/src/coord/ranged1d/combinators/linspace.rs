@@ -287,6 +287,10 @@ where
         self.inner.map(value, limit)
     }
 
+    fn map_f64(&self, value: &T::ValueType, limit: (i32, i32)) -> f64 {
+        self.inner.map_f64(value, limit)
+    }
+
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<T::ValueType> {
         if self.grid_value.is_empty() {
             return vec![];
@@ -207,6 +207,11 @@ impl<V: LogScalable> Ranged for LogCoord<V> {
         self.linear.map(&value_ln, limit)
     }
 
+    fn map_f64(&self, value: &V, limit: (i32, i32)) -> f64 {
+        let value_ln = self.value_to_f64(value).ln();
+        self.linear.map_f64(&value_ln, limit)
+    }
+
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
         let max_points = hint.max_num_points();
 
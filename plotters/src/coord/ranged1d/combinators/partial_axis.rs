@@ -36,6 +36,10 @@ where
         self.0.map(value, limit)
     }
 
+    fn map_f64(&self, value: &Self::ValueType, limit: (i32, i32)) -> f64 {
+        self.0.map_f64(value, limit)
+    }
+
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
         self.0.key_points(hint)
     }
@@ -50,6 +54,10 @@ where
 
         left.min(right)..left.max(right)
     }
+
+    fn linear_value_range(&self) -> Option<(f64, f64)> {
+        self.0.linear_value_range()
+    }
 }
 
 impl<R: DiscreteRanged> DiscreteRanged for PartialAxis<R>
@@ -201,6 +201,12 @@ pub trait Ranged {
     /// This function maps the value to i32, which is the drawing coordinate
     fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32;
 
+    /// This function maps the value to the drawing coordinate, keeping the sub-pixel part of the
+    /// position. The default implementation returns the result of [`map`](Self::map).
+    fn map_f64(&self, value: &Self::ValueType, limit: (i32, i32)) -> f64 {
+        f64::from(self.map(value, limit))
+    }
+
     /// This function gives the key points that we can draw a grid based on this
     fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType>;
 
@@ -216,6 +222,13 @@ pub trait Ranged {
             limit.1..limit.0
         }
     }
+
+    /// If the values are mapped linearly to pixels, the range of this value as floating-point
+    /// numbers. This allows backends to compute the value at any pixel position of the axis,
+    /// e.g. for interactive readouts. The default implementation returns `None`.
+    fn linear_value_range(&self) -> Option<(f64, f64)> {
+        None
+    }
 }
 
 /// The trait indicates the ranged value can be map reversely, which means
@@ -103,12 +103,40 @@ macro_rules! make_numeric_coord {
                     return limit.0 + (actual_length as f64 * logic_length - 1e-3).ceil() as i32;
                 }
             }
+            #[allow(clippy::float_cmp)]
+            fn map_f64(&self, v: &$type, limit: (i32, i32)) -> f64 {
+                // The same corner cases as `map`, without snapping the result to a pixel
+                if self.1 == self.0 {
+                    return f64::from((limit.1 - limit.0) / 2);
+                }
+
+                let logic_length = (*v as f64 - self.0 as f64) / (self.1 as f64 - self.0 as f64);
+
+                let actual_length = limit.1 - limit.0;
+
+                if actual_length == 0 {
+                    return f64::from(limit.1);
+                }
+
+                if logic_length.is_infinite() {
+                    if logic_length.is_sign_positive() {
+                        return f64::from(limit.1);
+                    } else {
+                        return f64::from(limit.0);
+                    }
+                }
+
+                f64::from(limit.0) + f64::from(actual_length) * logic_length
+            }
             fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<$type> {
                 $key_points((self.0, self.1), hint.max_num_points())
             }
             fn range(&self) -> Range<$type> {
                 return self.0..self.1;
             }
+            fn linear_value_range(&self) -> Option<(f64, f64)> {
+                Some((self.0 as f64, self.1 as f64))
+            }
         }
     };
     ($type:ty, $name:ident, $key_points:ident, $doc: expr) => {
@@ -381,6 +409,7 @@ impl_ranged_type_trait!(usize, RangedCoordusize);
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::coord::combinators::{BindKeyPoints, IntoLogRange, LogCoord};
     #[test]
     fn test_key_points() {
         let kp = compute_i32_key_points((0, 999), 28);
@@ -481,4 +510,25 @@ mod test {
         let p = coord.key_points(10);
         assert!(!p.is_empty() && p.len() <= 10);
     }
+
+    #[test]
+    fn test_linear_value_range() {
+        let coord: RangedCoordi32 = (-5..10).into();
+        assert_eq!(coord.linear_value_range(), Some((-5.0, 10.0)));
+        let coord = (0.5..1.5).with_key_points(vec![1.0]);
+        assert_eq!(coord.linear_value_range(), Some((0.5, 1.5)));
+        let coord: LogCoord<f64> = (0.5..1.5).log_scale().into();
+        assert_eq!(coord.linear_value_range(), None);
+    }
+    #[test]
+    fn test_coord_map_f64() {
+        let coord: RangedCoordf64 = (0.0..3.0).into();
+        assert_eq!(coord.map(&1.0, (0, 100)), 33);
+        assert!((coord.map_f64(&1.0, (0, 100)) - 100.0 / 3.0).abs() < 1e-9);
+        assert_eq!(coord.map_f64(&1.5, (100, 0)), 50.0);
+        let coord: RangedCoordi32 = (5..5).into();
+        assert_eq!(coord.map_f64(&5, (0, 100)), 50.0);
+        let coord = (0.0..3.0).with_key_points(vec![1.0]);
+        assert_eq!(coord.map_f64(&0.75, (0, 10)), 2.5);
+    }
 }
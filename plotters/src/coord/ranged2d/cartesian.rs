@@ -11,7 +11,7 @@ use crate::coord::ranged1d::{KeyPointHint, Ranged, ReversibleRanged};
 use crate::coord::{CoordTranslate, ReverseCoordTranslate};
 
 use crate::style::ShapeStyle;
-use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use plotters_backend::{BackendCoord, BackendFloatCoord, DrawingBackend, DrawingErrorKind};
 
 use std::ops::Range;
 
@@ -20,8 +20,8 @@ use std::ops::Range;
 pub struct Cartesian2d<X: Ranged, Y: Ranged> {
     logic_x: X,
     logic_y: Y,
-    back_x: (i32, i32),
-    back_y: (i32, i32),
+    pub(crate) back_x: (i32, i32),
+    pub(crate) back_y: (i32, i32),
 }
 
 impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
@@ -119,6 +119,13 @@ impl<X: Ranged, Y: Ranged> CoordTranslate for Cartesian2d<X, Y> {
             self.logic_y.map(&from.1, self.back_y),
         )
     }
+
+    fn translate_f64(&self, from: &Self::From) -> BackendFloatCoord {
+        (
+            self.logic_x.map_f64(&from.0, self.back_x),
+            self.logic_y.map_f64(&from.1, self.back_y),
+        )
+    }
 }
 
 impl<X: ReversibleRanged, Y: ReversibleRanged> ReverseCoordTranslate for Cartesian2d<X, Y> {
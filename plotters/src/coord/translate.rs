@@ -1,4 +1,4 @@
-use plotters_backend::BackendCoord;
+use plotters_backend::{BackendCoord, BackendFloatCoord};
 use std::ops::Deref;
 
 /// The trait that translates some customized object to the backend coordinate
@@ -9,6 +9,13 @@ pub trait CoordTranslate {
     /// Translate the guest coordinate to the guest coordinate
     fn translate(&self, from: &Self::From) -> BackendCoord;
 
+    /// Translate the guest coordinate to the backend coordinate, keeping the sub-pixel part of
+    /// the position. The default implementation returns the result of `translate`.
+    fn translate_f64(&self, from: &Self::From) -> BackendFloatCoord {
+        let (x, y) = self.translate(from);
+        (f64::from(x), f64::from(y))
+    }
+
     /// Get the Z-value of current coordinate
     fn depth(&self, _from: &Self::From) -> i32 {
         0
@@ -24,6 +31,9 @@ where
     fn translate(&self, from: &Self::From) -> BackendCoord {
         self.deref().translate(from)
     }
+    fn translate_f64(&self, from: &Self::From) -> BackendFloatCoord {
+        self.deref().translate_f64(from)
+    }
 }
 
 /// The trait indicates that the coordinate system supports reverse transform
@@ -6,7 +6,9 @@ use crate::style::text_anchor::{HPos, Pos, VPos};
 use crate::style::{Color, SizeDesc, TextStyle};
 
 /// The abstraction of a drawing area
-use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind, ElementContext};
+use plotters_backend::{
+    BackendCoord, BackendFloatCoord, DrawingBackend, DrawingErrorKind, ElementContext,
+};
 
 use std::borrow::Borrow;
 use std::cell::RefCell;
@@ -109,6 +111,14 @@ impl Rect {
     pub fn truncate(&self, p: (i32, i32)) -> (i32, i32) {
         (p.0.min(self.x1).max(self.x0), p.1.min(self.y1).max(self.y0))
     }
+
+    /// Make the sub-pixel coordinate in the range of the rectangle
+    pub(crate) fn truncate_f64(&self, p: BackendFloatCoord) -> BackendFloatCoord {
+        (
+            p.0.min(f64::from(self.x1)).max(f64::from(self.x0)),
+            p.1.min(f64::from(self.y1)).max(f64::from(self.y0)),
+        )
+    }
 }
 
 /// The abstraction of a drawing area. Plotters uses drawing area as the fundamental abstraction for the
@@ -331,8 +341,94 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         })
     }
 
+    /// Update the metadata of a semantic context that has already been closed.
+    pub fn update_context(&self, ctx: ElementContext) -> Result<(), DrawingAreaError<DB>> {
+        self.backend_ops(|b| {
+            b.update_context(ctx);
+            Ok(())
+        })
+    }
+
+    /// Check if the underlying backend makes use of the semantic contexts.
+    pub fn is_context_aware(&self) -> bool {
+        if let Ok(db) = self.backend.try_borrow() {
+            db.is_context_aware()
+        } else {
+            false
+        }
+    }
+
+    /// Check if the underlying backend makes use of the sub-pixel coordinates.
+    pub fn is_subpixel_aware(&self) -> bool {
+        if let Ok(db) = self.backend.try_borrow() {
+            db.is_subpixel_aware()
+        } else {
+            false
+        }
+    }
+
+    /// Check if the underlying backend clips the drawing itself.
+    pub fn is_clip_aware(&self) -> bool {
+        if let Ok(db) = self.backend.try_borrow() {
+            db.is_clip_aware()
+        } else {
+            false
+        }
+    }
+
+    /// Make the backend clip the drawing to this area, until [`pop_clip`](Self::pop_clip).
+    /// Returns `false`, without clipping, if the backend doesn't support it.
+    pub(crate) fn push_clip(&self) -> Result<bool, DrawingAreaError<DB>> {
+        if !self.is_clip_aware() {
+            return Ok(false);
+        }
+        // The bottom right corner of the area is exclusive, while it's included by the backend
+        let rect = &self.rect;
+        self.backend_ops(|b| b.push_clip_rect((rect.x0, rect.y0), (rect.x1 - 1, rect.y1 - 1)))?;
+        Ok(true)
+    }
+
+    /// Remove the clip region pushed by [`push_clip`](Self::push_clip).
+    pub(crate) fn pop_clip(&self) -> Result<(), DrawingAreaError<DB>> {
+        self.backend_ops(|b| b.pop_clip())
+    }
+
     /// Draw an high-level element
     pub fn draw<'a, E, B>(&self, element: &'a E) -> Result<(), DrawingAreaError<DB>>
+    where
+        B: CoordMapper,
+        &'a E: PointCollection<'a, CT::From, B>,
+        E: Drawable<DB, B>,
+    {
+        self.draw_within(element, &self.rect)
+    }
+
+    /// Draw an high-level element which the backend clips to this area, see
+    /// [`push_clip`](Self::push_clip).
+    pub(crate) fn draw_clipped<'a, E, B>(&self, element: &'a E) -> Result<(), DrawingAreaError<DB>>
+    where
+        B: CoordMapper,
+        &'a E: PointCollection<'a, CT::From, B>,
+        E: Drawable<DB, B>,
+    {
+        // The backend cuts the shapes itself, thus the coordinates are only limited to keep its
+        // arithmetic from overflowing, and the lines leaving the area keep their slopes
+        const LIMIT: i32 = 1 << 28;
+        let bounds = Rect {
+            x0: -LIMIT,
+            y0: -LIMIT,
+            x1: LIMIT,
+            y1: LIMIT,
+        };
+        self.draw_within(element, &bounds)
+    }
+
+    /// Draw an high-level element, with the coordinates truncated to the bounds
+    fn draw_within<'a, E, B>(
+        &self,
+        element: &'a E,
+        bounds: &Rect,
+    ) -> Result<(), DrawingAreaError<DB>>
     where
         B: CoordMapper,
         &'a E: PointCollection<'a, CT::From, B>,
@@ -340,8 +436,17 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
     {
         let backend_coords = element.point_iter().into_iter().map(|p| {
             let b = p.borrow();
-            B::map(&self.coord, b, &self.rect)
+            B::map(&self.coord, b, bounds)
         });
+        if self.is_subpixel_aware() {
+            let exact_coords = element.point_iter().into_iter().map(|p| {
+                let b = p.borrow();
+                bounds.truncate_f64(self.coord.translate_f64(b))
+            });
+            return self.backend_ops(move |b| {
+                element.draw_subpixel(backend_coords, exact_coords, b, self.dim_in_pixel())
+            });
+        }
         self.backend_ops(move |b| element.draw(backend_coords, b, self.dim_in_pixel()))
     }
 
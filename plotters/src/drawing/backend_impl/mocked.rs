@@ -4,6 +4,7 @@ use crate::drawing::DrawingArea;
 use crate::style::RGBAColor;
 use plotters_backend::{
     BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+    ElementContext,
 };
 
 use std::collections::VecDeque;
@@ -27,6 +28,12 @@ pub struct MockedBackend {
     pub num_draw_text_call: u32,
     pub num_draw_path_call: u32,
     pub num_fill_polygon_call: u32,
+    /// If the mocked backend claims to be context aware
+    pub context_aware: bool,
+    /// All the contexts passed to `begin_context`, in order
+    pub contexts: Vec<ElementContext>,
+    /// All the contexts passed to `update_context`, in order
+    pub updated_contexts: Vec<ElementContext>,
     check_draw_pixel: VecDeque<Box<dyn FnMut(RGBAColor, BackendCoord)>>,
     check_draw_line: VecDeque<Box<dyn FnMut(RGBAColor, u32, BackendCoord, BackendCoord)>>,
     check_draw_rect: VecDeque<Box<dyn FnMut(RGBAColor, u32, bool, BackendCoord, BackendCoord)>>,
@@ -66,6 +73,9 @@ impl MockedBackend {
             num_draw_text_call: 0,
             num_draw_path_call: 0,
             num_fill_polygon_call: 0,
+            context_aware: false,
+            contexts: vec![],
+            updated_contexts: vec![],
             check_draw_pixel: vec![].into(),
             check_draw_line: vec![].into(),
             check_draw_rect: vec![].into(),
@@ -128,6 +138,18 @@ impl DrawingBackend for MockedBackend {
         Ok(())
     }
 
+    fn is_context_aware(&self) -> bool {
+        self.context_aware
+    }
+
+    fn begin_context(&mut self, ctx: ElementContext) {
+        self.contexts.push(ctx);
+    }
+
+    fn update_context(&mut self, ctx: ElementContext) {
+        self.updated_contexts.push(ctx);
+    }
+
     fn draw_pixel(
         &mut self,
         point: BackendCoord,
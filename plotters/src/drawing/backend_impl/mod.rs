@@ -3,6 +3,12 @@ mod mocked;
 #[cfg(test)]
 pub use mocked::{check_color, create_mocked_drawing_area, MockedBackend};
 
+mod recording;
+pub use recording::{
+    AxisDescription, ChartDescription, LegendDescription, LineDescription, PixelBounds,
+    PointDescription, RecordingBackend, SeriesDescription, TextDescription, TickDescription,
+};
+
 /// This is the dummy backend placeholder for the backend that never fails
 #[derive(Debug)]
 pub struct DummyBackendError;
@@ -0,0 +1,890 @@
+use crate::style::RGBAColor;
+use plotters_backend::{
+    text_anchor::{HPos, VPos},
+    BackendColor, BackendCoord, BackendFloatCoord, BackendStyle, BackendTextStyle, DrawingBackend,
+    DrawingErrorKind, ElementContext, Interpolation, MarkerShape,
+};
+
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+/// A rectangle in backend pixels, given by its upper-left and bottom-right corners
+pub type PixelBounds = (BackendCoord, BackendCoord);
+
+/// The structured description of a rendered chart, recorded by a [RecordingBackend]
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub struct ChartDescription {
+    /// The size of the drawing backend in pixels
+    pub size: (u32, u32),
+    /// The axes, in drawing order
+    pub axes: Vec<AxisDescription>,
+    /// The data series, in drawing order. Series ids restart with every chart, so a document
+    /// with several charts may list the same id more than once.
+    pub series: Vec<SeriesDescription>,
+    /// The rows of the series legends
+    pub legend: Vec<LegendDescription>,
+    /// The texts drawn outside of the axes, such as captions and legend labels
+    pub labels: Vec<TextDescription>,
+}
+
+/// An axis of a chart
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub struct AxisDescription {
+    /// The axis id, e.g. `"x"` or `"y"`
+    pub id: String,
+    /// The tick labels and their pixel position along the axis direction
+    pub ticks: Vec<TickDescription>,
+    /// The pixel range of a continuous axis, mapped to `value_range`
+    pub pixel_range: Option<(i32, i32)>,
+    /// The value range of a continuous axis
+    pub value_range: Option<(f64, f64)>,
+    /// The units of the axis values, empty if unknown
+    pub units: String,
+    /// The texts drawn with the axis: the tick labels and the axis description
+    pub texts: Vec<TextDescription>,
+    /// The area covered by the draw calls of the axis
+    pub bounds: Option<PixelBounds>,
+}
+
+/// A tick of an axis
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub struct TickDescription {
+    /// The pixel position of the tick along the axis direction
+    pub position: i32,
+    /// The formatted value of the tick
+    pub label: String,
+}
+
+/// A text drawn on the chart
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub struct TextDescription {
+    /// The text content
+    pub text: String,
+    /// The anchor point of the text
+    pub pos: BackendCoord,
+    /// Whether the text is a caption, i.e. drawn inside a label context
+    pub is_caption: bool,
+}
+
+/// A data series of a chart
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub struct SeriesDescription {
+    /// The series id, unique within one chart
+    pub id: usize,
+    /// The series label, empty if the series has none
+    pub label: String,
+    /// The series colour, or the colour of the first primitive drawn if the chart didn't report
+    /// one
+    pub color: Option<RGBAColor>,
+    /// The single-point elements of the series
+    pub points: Vec<PointDescription>,
+    /// The multi-point elements of the series, such as lines and areas
+    pub lines: Vec<LineDescription>,
+    /// The area covered by the draw calls of the series
+    pub bounds: Option<PixelBounds>,
+}
+
+/// A data point, in pixel and in logical space
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub struct PointDescription {
+    /// The pixel coordinate of the point
+    pub pixel: BackendCoord,
+    /// The formatted x value
+    pub x: String,
+    /// The formatted y value
+    pub y: String,
+    /// The formatted z value, only present for 3D charts
+    pub z: Option<String>,
+    /// The numeric x and y values, only known when both axes are mapped continuously
+    pub value: Option<(f64, f64)>,
+    /// The user supplied key/value pairs describing the point
+    pub metadata: Vec<(String, String)>,
+    /// The area covered by the draw calls of the element, if any
+    pub bounds: Option<PixelBounds>,
+}
+
+/// A line or another multi-point element of a series
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub struct LineDescription {
+    /// The vertices of the line, their `bounds` and `metadata` are empty
+    pub vertices: Vec<PointDescription>,
+    /// The area covered by the draw calls of the element
+    pub bounds: Option<PixelBounds>,
+}
+
+/// A row of a series legend
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub struct LegendDescription {
+    /// The series the row describes
+    pub series_id: usize,
+    /// The label shown in the row
+    pub label: String,
+    /// The area of the whole row
+    pub area: PixelBounds,
+}
+
+/// A context which is currently open, with what has been drawn inside of it so far
+struct OpenContext {
+    ctx: ElementContext,
+    /// The index of the axis or the series in the description, for axis and series contexts
+    index: Option<usize>,
+    bounds: Option<PixelBounds>,
+    color: Option<BackendColor>,
+    /// The vertices of the first path or polygon drawn
+    shape: Vec<BackendCoord>,
+}
+
+/// A drawing backend adapter which records what's drawn into a [ChartDescription]
+///
+/// All the calls are forwarded to the wrapped backend, so the chart is rendered as usual. The
+/// element contexts reported by the chart and the draw calls inside of them are turned into a
+/// structured description of the axes, the series with their data points and the texts, which is
+/// serializable with the `serialization` feature. This is useful for snapshot tests, hit-testing
+/// or describing a chart without parsing the rendered image.
+///
+/// ```
+/// use plotters::prelude::*;
+/// use plotters::drawing::{ChartDescription, RecordingBackend};
+///
+/// let mut svg = String::new();
+/// let mut description = ChartDescription::default();
+/// {
+///     let backend = RecordingBackend::new(SVGBackend::with_string(&mut svg, (300, 200)), &mut description);
+///     let root = backend.into_drawing_area();
+///     let mut chart = ChartBuilder::on(&root)
+///         .x_label_area_size(20)
+///         .build_cartesian_2d(0..10, 0..10)
+///         .unwrap();
+///     chart.configure_mesh().draw().unwrap();
+///     chart
+///         .draw_series(LineSeries::new((0..10).map(|x| (x, x)), &RED))
+///         .unwrap()
+///         .label("y = x");
+/// }
+/// assert_eq!(description.series[0].label, "y = x");
+/// ```
+pub struct RecordingBackend<'a, DB: DrawingBackend> {
+    inner: DB,
+    description: &'a mut ChartDescription,
+    stack: Vec<OpenContext>,
+    /// The clip regions pushed to the inner backend, each intersected with the previous one
+    clips: Vec<PixelBounds>,
+}
+
+impl<'a, DB: DrawingBackend> RecordingBackend<'a, DB> {
+    /// Wrap a backend, the description is reset and filled while the chart is drawn
+    ///
+    /// - `inner`: The backend which renders the chart
+    /// - `description`: The description to record into
+    pub fn new(inner: DB, description: &'a mut ChartDescription) -> Self {
+        *description = ChartDescription {
+            size: inner.get_size(),
+            ..Default::default()
+        };
+        Self {
+            inner,
+            description,
+            stack: vec![],
+            clips: vec![],
+        }
+    }
+
+    /// Get the wrapped backend
+    pub fn inner(&self) -> &DB {
+        &self.inner
+    }
+
+    fn track(&mut self, a: BackendCoord, b: BackendCoord, color: BackendColor) {
+        let (mut lo, mut hi) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
+        // Only the visible part of a clipped shape is tracked
+        if let Some((clip_lo, clip_hi)) = self.clips.last() {
+            lo = (lo.0.max(clip_lo.0), lo.1.max(clip_lo.1));
+            hi = (hi.0.min(clip_hi.0), hi.1.min(clip_hi.1));
+            if lo.0 > hi.0 || lo.1 > hi.1 {
+                return;
+            }
+        }
+        for open in self.stack.iter_mut() {
+            open.bounds = Some(match open.bounds {
+                Some((l, h)) => (
+                    (l.0.min(lo.0), l.1.min(lo.1)),
+                    (h.0.max(hi.0), h.1.max(hi.1)),
+                ),
+                None => (lo, hi),
+            });
+            if open.color.is_none() && color.alpha > 0.0 {
+                open.color = Some(color);
+            }
+        }
+    }
+
+    fn track_shape(&mut self, points: &[BackendCoord], color: BackendColor) {
+        for point in points {
+            self.track(*point, *point, color);
+        }
+        if let Some(open) = self.stack.last_mut() {
+            if open.shape.is_empty() {
+                open.shape.extend_from_slice(points);
+            }
+        }
+    }
+
+    /// Track the area of a text, laid out like the default text rendering does, and record it
+    /// as an axis text or a label
+    fn track_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) {
+        let (w, h) = self
+            .inner
+            .estimate_text_size(text, style)
+            .map_or((0, 0), |(w, h)| (w as i32, h as i32));
+        let dx = match style.anchor().h_pos {
+            HPos::Left => 0,
+            HPos::Right => -w,
+            HPos::Center => -w / 2,
+        };
+        let dy = match style.anchor().v_pos {
+            VPos::Top => 0,
+            VPos::Center => -h / 2,
+            VPos::Bottom => -h,
+        };
+        let trans = style.transform();
+        let (x0, y0) = trans.transform(dx, dy);
+        let (x1, y1) = trans.transform(dx + w, dy + h);
+        self.track(
+            (pos.0 + x0, pos.1 + y0),
+            (pos.0 + x1, pos.1 + y1),
+            style.color(),
+        );
+        let is_caption = self
+            .stack
+            .iter()
+            .any(|open| matches!(open.ctx, ElementContext::Label { .. }));
+        let axis = self.stack.iter().rev().find_map(|open| match open.ctx {
+            ElementContext::Axis { .. } => open.index,
+            _ => None,
+        });
+        let text_desc = TextDescription {
+            text: text.to_string(),
+            pos,
+            is_caption,
+        };
+        match axis {
+            Some(idx) => self.description.axes[idx].texts.push(text_desc),
+            None => self.description.labels.push(text_desc),
+        }
+    }
+
+    /// The series the element of the series id belongs to, which is the open series with the id
+    /// or, if there's none, a new series
+    fn series_index(&mut self, series_id: usize) -> usize {
+        let open = self.stack.iter().rev().find_map(|open| match open.ctx {
+            ElementContext::DataSeries { id, .. } if id == series_id => open.index,
+            _ => None,
+        });
+        open.unwrap_or_else(|| {
+            self.description.series.push(SeriesDescription {
+                id: series_id,
+                ..Default::default()
+            });
+            self.description.series.len() - 1
+        })
+    }
+
+    fn finish_context(&mut self, open: OpenContext) {
+        match open.ctx {
+            ElementContext::DataSeries { color, .. } => {
+                if let Some(series) = open.index.map(|idx| &mut self.description.series[idx]) {
+                    let color = Some(color).filter(|c| c.alpha > 0.0);
+                    series.color = color.or(open.color).map(to_color);
+                    series.bounds = open.bounds;
+                }
+            }
+            ElementContext::DataPoint {
+                coord,
+                x_label,
+                y_label,
+                z_label,
+                metadata,
+                series_id,
+                ..
+            } => {
+                let idx = self.series_index(series_id);
+                self.description.series[idx].points.push(PointDescription {
+                    pixel: coord,
+                    x: x_label,
+                    y: y_label,
+                    z: z_label,
+                    value: None,
+                    metadata,
+                    bounds: open.bounds,
+                });
+            }
+            ElementContext::DataLine {
+                x_interpolation,
+                y_interpolation,
+                z_interpolation,
+                continuous_interpolation,
+                series_id,
+                ..
+            } => {
+                let vertices = line_vertices(
+                    &x_interpolation,
+                    &y_interpolation,
+                    z_interpolation.as_ref(),
+                    continuous_interpolation.as_ref(),
+                    &open.shape,
+                );
+                let idx = self.series_index(series_id);
+                self.description.series[idx].lines.push(LineDescription {
+                    vertices,
+                    bounds: open.bounds,
+                });
+            }
+            ElementContext::Tick {
+                axis_id,
+                position,
+                label,
+            } => {
+                let axis = self
+                    .description
+                    .axes
+                    .iter_mut()
+                    .rev()
+                    .find(|axis| axis.id == axis_id);
+                if let Some(axis) = axis {
+                    axis.ticks.push(TickDescription { position, label });
+                }
+            }
+            ElementContext::LegendEntry {
+                series_id,
+                label,
+                area,
+            } => {
+                self.description.legend.push(LegendDescription {
+                    series_id,
+                    label,
+                    area,
+                });
+            }
+            ElementContext::Axis { .. } => {
+                if let Some(idx) = open.index {
+                    self.finish_axis(idx, open.bounds);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Axes are reported once per pass of the mesh drawing, and for empty label areas as well.
+    /// Empty axes are dropped, an axis adjacent to an earlier one with the same id and range is
+    /// merged into it. The top and bottom (or left and right) axes of a chart are never adjacent,
+    /// since the plotting area is between them.
+    fn finish_axis(&mut self, idx: usize, bounds: Option<PixelBounds>) {
+        /// The largest gap in pixels between the parts of an axis drawn by different passes
+        const MAX_GAP: i32 = 4;
+
+        let axes = &mut self.description.axes;
+        let Some(((x0, y0), (x1, y1))) = bounds else {
+            axes.remove(idx);
+            return;
+        };
+        axes[idx].bounds = bounds;
+        let current = &axes[idx];
+        let target = axes[..idx].iter().rposition(|axis| {
+            let same_range = match (axis.pixel_range, current.pixel_range) {
+                (Some(a), Some(b)) => a == b && axis.value_range == current.value_range,
+                _ => true,
+            };
+            axis.id == current.id
+                && same_range
+                && axis.bounds.is_some_and(|((ax0, ay0), (ax1, ay1))| {
+                    ax0 <= x1 + MAX_GAP
+                        && x0 <= ax1 + MAX_GAP
+                        && ay0 <= y1 + MAX_GAP
+                        && y0 <= ay1 + MAX_GAP
+                })
+        });
+        if let Some(target) = target {
+            let axis = axes.remove(idx);
+            let merged = &mut axes[target];
+            merged.ticks.extend(axis.ticks);
+            merged.texts.extend(axis.texts);
+            merged.pixel_range = merged.pixel_range.or(axis.pixel_range);
+            merged.value_range = merged.value_range.or(axis.value_range);
+            if merged.units.is_empty() {
+                merged.units = axis.units;
+            }
+            merged.bounds = Some(union(merged.bounds, ((x0, y0), (x1, y1))));
+        }
+    }
+}
+
+fn union(bounds: Option<PixelBounds>, (lo, hi): PixelBounds) -> PixelBounds {
+    match bounds {
+        Some((l, h)) => (
+            (l.0.min(lo.0), l.1.min(lo.1)),
+            (h.0.max(hi.0), h.1.max(hi.1)),
+        ),
+        None => (lo, hi),
+    }
+}
+
+fn round_coord((x, y): BackendFloatCoord) -> BackendCoord {
+    (x.round() as i32, y.round() as i32)
+}
+
+fn to_color(color: BackendColor) -> RGBAColor {
+    RGBAColor(color.rgb.0, color.rgb.1, color.rgb.2, color.alpha)
+}
+
+/// The vertices of a line, taken from its discrete interpolations or, with continuous ones, from
+/// the drawn shape. The numeric values of the vertices are evaluated from `continuous`, if the
+/// axes are mapped linearly.
+fn line_vertices(
+    x: &Interpolation,
+    y: &Interpolation,
+    z: Option<&Interpolation>,
+    continuous: Option<&(Interpolation, Interpolation)>,
+    shape: &[BackendCoord],
+) -> Vec<PointDescription> {
+    match (x, y) {
+        (Interpolation::Discrete { points: xpts }, Interpolation::Discrete { points: ypts }) => {
+            let zpts = match z {
+                Some(Interpolation::Discrete { points }) if points.len() == xpts.len() => {
+                    &points[..]
+                }
+                _ => &[],
+            };
+            xpts.iter()
+                .zip(ypts.iter())
+                .enumerate()
+                .map(|(idx, ((xp, xl), (yp, yl)))| PointDescription {
+                    pixel: (*xp, *yp),
+                    x: xl.clone(),
+                    y: yl.clone(),
+                    z: zpts.get(idx).map(|(_, zl)| zl.clone()),
+                    value: continuous
+                        .and_then(|(cx, cy)| Some((cx.value_at(*xp)?, cy.value_at(*yp)?))),
+                    ..Default::default()
+                })
+                .collect()
+        }
+        _ => shape
+            .iter()
+            .filter_map(|&(px, py)| {
+                Some(PointDescription {
+                    pixel: (px, py),
+                    x: x.label_at(px)?,
+                    y: y.label_at(py)?,
+                    value: Some((x.value_at(px)?, y.value_at(py)?)),
+                    ..Default::default()
+                })
+            })
+            .collect(),
+    }
+}
+
+impl<'a, DB: DrawingBackend> DrawingBackend for RecordingBackend<'a, DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn begin_context(&mut self, ctx: ElementContext) {
+        let index = match &ctx {
+            ElementContext::Axis {
+                axis_id,
+                interpolation,
+            } => {
+                let mut axis = AxisDescription {
+                    id: axis_id.clone(),
+                    ..Default::default()
+                };
+                match interpolation {
+                    Some(Interpolation::Continuous {
+                        backend_range,
+                        value_range,
+                        units,
+                    }) => {
+                        axis.pixel_range = Some(*backend_range);
+                        axis.value_range = Some(*value_range);
+                        axis.units = units.clone();
+                    }
+                    Some(Interpolation::Discrete { points }) => {
+                        axis.ticks = points
+                            .iter()
+                            .map(|(position, label)| TickDescription {
+                                position: *position,
+                                label: label.clone(),
+                            })
+                            .collect();
+                    }
+                    None => {}
+                }
+                self.description.axes.push(axis);
+                Some(self.description.axes.len() - 1)
+            }
+            ElementContext::DataSeries { id, label, .. } => {
+                self.description.series.push(SeriesDescription {
+                    id: *id,
+                    label: label.clone(),
+                    ..Default::default()
+                });
+                Some(self.description.series.len() - 1)
+            }
+            _ => None,
+        };
+        self.inner.begin_context(ctx.clone());
+        self.stack.push(OpenContext {
+            ctx,
+            index,
+            bounds: None,
+            color: None,
+            shape: vec![],
+        });
+    }
+
+    fn end_context(&mut self) {
+        self.inner.end_context();
+        if let Some(open) = self.stack.pop() {
+            self.finish_context(open);
+        }
+    }
+
+    fn update_context(&mut self, ctx: ElementContext) {
+        if let ElementContext::DataSeries {
+            id, color, label, ..
+        } = &ctx
+        {
+            let series = self
+                .description
+                .series
+                .iter_mut()
+                .rev()
+                .find(|s| s.id == *id);
+            if let Some(series) = series {
+                series.label = label.clone();
+                if color.alpha > 0.0 {
+                    series.color = Some(to_color(*color));
+                }
+            }
+        }
+        self.inner.update_context(ctx);
+    }
+
+    fn is_context_aware(&self) -> bool {
+        true
+    }
+
+    fn is_subpixel_aware(&self) -> bool {
+        self.inner.is_subpixel_aware()
+    }
+
+    fn is_anti_aliased(&self) -> bool {
+        self.inner.is_anti_aliased()
+    }
+
+    fn is_clip_aware(&self) -> bool {
+        self.inner.is_clip_aware()
+    }
+
+    fn push_clip_rect(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (mut lo, mut hi) = (upper_left, bottom_right);
+        if let Some((clip_lo, clip_hi)) = self.clips.last() {
+            lo = (lo.0.max(clip_lo.0), lo.1.max(clip_lo.1));
+            hi = (hi.0.min(clip_hi.0), hi.1.min(clip_hi.1));
+        }
+        self.clips.push((lo, hi));
+        self.inner.push_clip_rect(upper_left, bottom_right)
+    }
+
+    fn pop_clip(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.clips.pop();
+        self.inner.pop_clip()
+    }
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.present()
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.track(point, point, color);
+        self.inner.draw_pixel(point, color)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.track(from, to, style.color());
+        self.inner.draw_line(from, to, style)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.track(upper_left, bottom_right, style.color());
+        self.inner.draw_rect(upper_left, bottom_right, style, fill)
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let path: Vec<_> = path.into_iter().collect();
+        self.track_shape(&path, style.color());
+        self.inner.draw_path(path, style)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let r = radius as i32;
+        self.track(
+            (center.0 - r, center.1 - r),
+            (center.0 + r, center.1 + r),
+            style.color(),
+        );
+        self.inner.draw_circle(center, radius, style, fill)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let vert: Vec<_> = vert.into_iter().collect();
+        self.track_shape(&vert, style.color());
+        self.inner.fill_polygon(vert, style)
+    }
+
+    fn draw_marker<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        shape: MarkerShape,
+        size: i32,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let r = size.abs();
+        self.track(
+            (center.0 - r, center.1 - r),
+            (center.0 + r, center.1 + r),
+            style.color(),
+        );
+        self.inner.draw_marker(center, shape, size, style)
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.track_text(text, style, pos);
+        self.inner.draw_text(text, style, pos)
+    }
+
+    fn draw_line_f64<S: BackendStyle>(
+        &mut self,
+        from: BackendFloatCoord,
+        to: BackendFloatCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.track(round_coord(from), round_coord(to), style.color());
+        self.inner.draw_line_f64(from, to, style)
+    }
+
+    fn draw_path_f64<S: BackendStyle, I: IntoIterator<Item = BackendFloatCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let path: Vec<_> = path.into_iter().collect();
+        let shape: Vec<_> = path.iter().copied().map(round_coord).collect();
+        self.track_shape(&shape, style.color());
+        self.inner.draw_path_f64(path, style)
+    }
+
+    fn draw_circle_f64<S: BackendStyle>(
+        &mut self,
+        center: BackendFloatCoord,
+        radius: f64,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.track(
+            round_coord((center.0 - radius, center.1 - radius)),
+            round_coord((center.0 + radius, center.1 + radius)),
+            style.color(),
+        );
+        self.inner.draw_circle_f64(center, radius, style, fill)
+    }
+
+    fn fill_polygon_f64<S: BackendStyle, I: IntoIterator<Item = BackendFloatCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let vert: Vec<_> = vert.into_iter().collect();
+        let shape: Vec<_> = vert.iter().copied().map(round_coord).collect();
+        self.track_shape(&shape, style.color());
+        self.inner.fill_polygon_f64(vert, style)
+    }
+
+    fn draw_text_f64<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendFloatCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.track_text(text, style, round_coord(pos));
+        self.inner.draw_text_f64(text, style, pos)
+    }
+
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.estimate_text_size(text, style)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (iw, ih): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let end = (pos.0 + iw as i32, pos.1 + ih as i32);
+        self.track(
+            pos,
+            end,
+            BackendColor {
+                alpha: 0.0,
+                rgb: (0, 0, 0),
+            },
+        );
+        self.inner.blit_bitmap(pos, (iw, ih), src)
+    }
+
+    fn blit_bitmap_rgba(
+        &mut self,
+        pos: BackendCoord,
+        (iw, ih): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let end = (pos.0 + iw as i32, pos.1 + ih as i32);
+        self.track(
+            pos,
+            end,
+            BackendColor {
+                alpha: 0.0,
+                rgb: (0, 0, 0),
+            },
+        );
+        self.inner.blit_bitmap_rgba(pos, (iw, ih), src)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::{IntoDrawingArea, MockedBackend};
+    use crate::prelude::*;
+
+    #[test]
+    fn test_record_chart() {
+        let mut description = ChartDescription::default();
+        {
+            let backend = RecordingBackend::new(MockedBackend::new(400, 300), &mut description);
+            let root = backend.into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .caption("Chart", ("sans-serif", 20))
+                .x_label_area_size(30)
+                .y_label_area_size(30)
+                .build_cartesian_2d(0..10, 0.0..1.0)
+                .unwrap();
+            chart.configure_mesh().x_desc("Time").draw().unwrap();
+            chart
+                .draw_series(LineSeries::new(vec![(0, 0.0), (10, 1.0)], &RED))
+                .unwrap()
+                .label("Line");
+            chart
+                .draw_series(std::iter::once(Circle::new((5, 0.5), 3, BLUE.filled())))
+                .unwrap();
+        }
+
+        assert_eq!(description.size, (400, 300));
+        assert!(description
+            .labels
+            .iter()
+            .any(|label| label.text == "Chart" && label.is_caption));
+
+        assert_eq!(description.axes.len(), 2);
+        let x_axis = description.axes.iter().find(|a| a.id == "x").unwrap();
+        assert_eq!(x_axis.value_range, Some((0.0, 10.0)));
+        assert!(x_axis.texts.iter().any(|t| t.text == "Time"));
+        assert!(x_axis.texts.iter().any(|t| t.text == "5"));
+
+        assert_eq!(description.series.len(), 2);
+        let line = &description.series[0];
+        assert_eq!(line.label, "Line");
+        assert_eq!(line.color, Some(RGBAColor(255, 0, 0, 1.0)));
+        let vertices = &line.lines[0].vertices;
+        assert_eq!(vertices.len(), 2);
+        assert_eq!(
+            (vertices[0].x.as_str(), vertices[0].y.as_str()),
+            ("0", "0.0")
+        );
+        assert_eq!(vertices[1].value.map(|v| v.0), Some(10.0));
+
+        let point = &description.series[1].points[0];
+        assert_eq!((point.x.as_str(), point.y.as_str()), ("5", "0.5"));
+        assert_eq!(description.series[1].color, Some(RGBAColor(0, 0, 255, 1.0)));
+        let (lo, hi) = point.bounds.unwrap();
+        assert_eq!((hi.0 - lo.0, hi.1 - lo.1), (6, 6));
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn test_description_is_serializable() {
+        fn check<T: Serialize + for<'de> Deserialize<'de>>() {}
+        check::<ChartDescription>();
+    }
+}
@@ -1,6 +1,8 @@
 use super::{Drawable, PointCollection};
-use crate::style::{Color, ShapeStyle, SizeDesc};
-use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::style::{Color, ExtendedStyle, ShapeStyle, SizeDesc};
+use plotters_backend::{
+    BackendCoord, BackendFloatCoord, DrawingBackend, DrawingErrorKind, MarkerShape,
+};
 
 #[inline]
 fn to_i((x, y): (f32, f32)) -> (i32, i32) {
@@ -85,14 +87,14 @@ pub type Path<Coord> = PathElement<Coord>;
 /// An element of a series of connected lines
 pub struct PathElement<Coord> {
     points: Vec<Coord>,
-    style: ShapeStyle,
+    style: ExtendedStyle,
 }
 impl<Coord> PathElement<Coord> {
     /// Create a new path
     /// - `points`: The iterator of the points
     /// - `style`: The shape style
     /// - returns the created element
-    pub fn new<P: Into<Vec<Coord>>, S: Into<ShapeStyle>>(points: P, style: S) -> Self {
+    pub fn new<P: Into<Vec<Coord>>, S: Into<ExtendedStyle>>(points: P, style: S) -> Self {
         Self {
             points: points.into(),
             style: style.into(),
@@ -117,6 +119,16 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for PathElement<Coord> {
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         backend.draw_path(points, &self.style)
     }
+
+    fn draw_subpixel<I: Iterator<Item = BackendCoord>, F: Iterator<Item = BackendFloatCoord>>(
+        &self,
+        _: I,
+        exact: F,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        backend.draw_path_f64(exact, &self.style)
+    }
 }
 
 #[cfg(test)]
@@ -392,7 +404,7 @@ fn test_dotted_path_element() {
 /// A rectangle element
 pub struct Rectangle<Coord> {
     points: [Coord; 2],
-    style: ShapeStyle,
+    style: ExtendedStyle,
     margin: (u32, u32, u32, u32),
 }
 
@@ -401,7 +413,7 @@ impl<Coord> Rectangle<Coord> {
     /// - `points`: The left upper and right lower corner of the rectangle
     /// - `style`: The shape style
     /// - returns the created element
-    pub fn new<S: Into<ShapeStyle>>(points: [Coord; 2], style: S) -> Self {
+    pub fn new<S: Into<ExtendedStyle>>(points: [Coord; 2], style: S) -> Self {
         Self {
             points,
             style: style.into(),
@@ -428,7 +440,7 @@ impl<Coord> Rectangle<Coord> {
     /// Set the style of the rectangle
     /// - `style`: The shape style
     /// - returns a mut reference to the rectangle
-    pub fn set_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+    pub fn set_style<S: Into<ExtendedStyle>>(&mut self, style: S) -> &mut Self {
         self.style = style.into();
         self
     }
@@ -456,7 +468,7 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for Rectangle<Coord> {
                 b.1 -= self.margin.1 as i32;
                 a.0 += self.margin.2 as i32;
                 b.0 -= self.margin.3 as i32;
-                backend.draw_rect(a, b, &self.style, self.style.filled)
+                backend.draw_rect(a, b, &self.style, self.style.shape.filled)
             }
             _ => Ok(()),
         }
@@ -508,7 +520,7 @@ fn test_rect_element() {
 pub struct Circle<Coord, Size: SizeDesc> {
     center: Coord,
     size: Size,
-    style: ShapeStyle,
+    style: ExtendedStyle,
 }
 
 impl<Coord, Size: SizeDesc> Circle<Coord, Size> {
@@ -517,7 +529,7 @@ impl<Coord, Size: SizeDesc> Circle<Coord, Size> {
     /// - `size` The radius of the circle
     /// - `style` The style of the circle
     /// - Return: The newly created circle element
-    pub fn new<S: Into<ShapeStyle>>(coord: Coord, size: Size, style: S) -> Self {
+    pub fn new<S: Into<ExtendedStyle>>(coord: Coord, size: Size, style: S) -> Self {
         Self {
             center: coord,
             size,
@@ -542,8 +554,30 @@ impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Circle<Coord, S
         ps: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some((x, y)) = points.next() {
-            let size = self.size.in_pixels(&ps).max(0) as u32;
-            return backend.draw_circle((x, y), size, &self.style, self.style.filled);
+            let size = self.size.in_pixels(&ps).max(0);
+            let shape = MarkerShape::Circle {
+                filled: self.style.shape.filled,
+            };
+            return backend.draw_marker((x, y), shape, size, &self.style);
+        }
+        Ok(())
+    }
+
+    fn draw_subpixel<I: Iterator<Item = BackendCoord>, F: Iterator<Item = BackendFloatCoord>>(
+        &self,
+        _: I,
+        mut exact: F,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some(center) = exact.next() {
+            let size = self.size.in_pixels(&ps).max(0);
+            return backend.draw_circle_f64(
+                center,
+                f64::from(size),
+                &self.style,
+                self.style.shape.filled,
+            );
         }
         Ok(())
     }
@@ -572,14 +606,14 @@ fn test_circle_element() {
 /// An element of a filled polygon
 pub struct Polygon<Coord> {
     points: Vec<Coord>,
-    style: ShapeStyle,
+    style: ExtendedStyle,
 }
 impl<Coord> Polygon<Coord> {
     /// Create a new polygon
     /// - `points`: The iterator of the points
     /// - `style`: The shape style
     /// - returns the created element
-    pub fn new<P: Into<Vec<Coord>>, S: Into<ShapeStyle>>(points: P, style: S) -> Self {
+    pub fn new<P: Into<Vec<Coord>>, S: Into<ExtendedStyle>>(points: P, style: S) -> Self {
         Self {
             points: points.into(),
             style: style.into(),
@@ -602,7 +636,17 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for Polygon<Coord> {
         backend: &mut DB,
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        backend.fill_polygon(points, &self.style.color.to_backend_color())
+        backend.fill_polygon(points, &self.style)
+    }
+
+    fn draw_subpixel<I: Iterator<Item = BackendCoord>, F: Iterator<Item = BackendFloatCoord>>(
+        &self,
+        _: I,
+        exact: F,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        backend.fill_polygon_f64(exact, &self.style)
     }
 }
 
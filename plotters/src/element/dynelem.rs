@@ -1,5 +1,5 @@
 use super::{Drawable, PointCollection};
-use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use plotters_backend::{BackendCoord, BackendFloatCoord, DrawingBackend, DrawingErrorKind};
 
 use std::borrow::Borrow;
 
@@ -10,6 +10,14 @@ trait DynDrawable<DB: DrawingBackend> {
         backend: &mut DB,
         parent_dim: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>>;
+
+    fn draw_subpixel_dyn(
+        &self,
+        points: &mut dyn Iterator<Item = BackendCoord>,
+        exact: &mut dyn Iterator<Item = BackendFloatCoord>,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>>;
 }
 
 impl<DB: DrawingBackend, T: Drawable<DB>> DynDrawable<DB> for T {
@@ -21,6 +29,16 @@ impl<DB: DrawingBackend, T: Drawable<DB>> DynDrawable<DB> for T {
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         T::draw(self, points, backend, parent_dim)
     }
+
+    fn draw_subpixel_dyn(
+        &self,
+        points: &mut dyn Iterator<Item = BackendCoord>,
+        exact: &mut dyn Iterator<Item = BackendFloatCoord>,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        T::draw_subpixel(self, points, exact, backend, parent_dim)
+    }
 }
 
 /// The container for a dynamically dispatched element
@@ -52,6 +70,17 @@ impl<'a, DB: DrawingBackend, Coord: Clone> Drawable<DB> for DynElement<'a, DB, C
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         self.drawable.draw_dyn(&mut pos, backend, parent_dim)
     }
+
+    fn draw_subpixel<I: Iterator<Item = BackendCoord>, F: Iterator<Item = BackendFloatCoord>>(
+        &self,
+        mut pos: I,
+        mut exact: F,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.drawable
+            .draw_subpixel_dyn(&mut pos, &mut exact, backend, parent_dim)
+    }
 }
 
 /// The trait that makes the conversion from the statically dispatched element
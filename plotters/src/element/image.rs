@@ -7,7 +7,7 @@ use image::{DynamicImage, GenericImageView};
 use super::{Drawable, PointCollection};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
-use plotters_bitmap::bitmap_pixel::{PixelFormat, RGBPixel};
+use plotters_bitmap::bitmap_pixel::{PixelFormat, RGBAPixel, RGBPixel};
 
 #[cfg(all(
     not(all(target_arch = "wasm32", not(target_os = "wasi"))),
@@ -204,7 +204,24 @@ impl<'a, Coord> From<(Coord, DynamicImage)> for BitMapElement<'a, Coord, BGRXPix
     }
 }
 
-impl<'a, 'b, Coord> PointCollection<'a, Coord> for &'a BitMapElement<'b, Coord> {
+#[cfg(all(
+    not(all(target_arch = "wasm32", not(target_os = "wasi"))),
+    feature = "image"
+))]
+impl<'a, Coord> From<(Coord, DynamicImage)> for BitMapElement<'a, Coord, RGBAPixel> {
+    fn from((pos, image): (Coord, DynamicImage)) -> Self {
+        let (w, h) = image.dimensions();
+        let rgba_image = image.to_rgba8().into_raw();
+        Self {
+            pos,
+            image: Buffer::Owned(rgba_image),
+            size: (w, h),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, 'b, Coord, P: PixelFormat> PointCollection<'a, Coord> for &'a BitMapElement<'b, Coord, P> {
     type Point = &'a Coord;
     type IntoIter = std::iter::Once<&'a Coord>;
     fn point_iter(self) -> Self::IntoIter {
@@ -226,3 +243,17 @@ impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for BitMapElement<'a, Coord> {
         Ok(())
     }
 }
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for BitMapElement<'a, Coord, RGBAPixel> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x, y)) = points.next() {
+            return backend.blit_bitmap_rgba((x, y), self.size, self.image.as_ref());
+        }
+        Ok(())
+    }
+}
@@ -154,7 +154,7 @@
     ```
     ![](https://plotters-rs.github.io/plotters-doc-data/element-3.png)
 */
-use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use plotters_backend::{BackendCoord, BackendFloatCoord, DrawingBackend, DrawingErrorKind};
 use std::borrow::Borrow;
 
 mod basic_shapes;
@@ -253,6 +253,21 @@ pub trait Drawable<DB: DrawingBackend, CM: CoordMapper = BackendCoordOnly> {
         backend: &mut DB,
         parent_dim: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>>;
+
+    /// Draws the element on a backend which is sub-pixel aware, see
+    /// [DrawingBackend::is_subpixel_aware]. Besides the key points of `draw`, `exact` gives the
+    /// key points with their fractional position in the image coordinate.
+    ///
+    /// The default implementation ignores `exact` and calls `draw`.
+    fn draw_subpixel<I: Iterator<Item = CM::Output>, F: Iterator<Item = BackendFloatCoord>>(
+        &self,
+        pos: I,
+        _exact: F,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.draw(pos, backend, parent_dim)
+    }
 }
 
 /// Useful to translate from guest coordinates to backend coordinates
@@ -1,7 +1,7 @@
 use super::*;
 use super::{Drawable, PointCollection};
-use crate::style::{Color, ShapeStyle, SizeDesc};
-use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::style::{ShapeStyle, SizeDesc};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind, MarkerShape};
 
 /**
 A common trait for elements that can be interpreted as points: A cross, a circle, a triangle marker...
@@ -62,10 +62,7 @@ impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Cross<Coord, Si
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some((x, y)) = points.next() {
             let size = self.size.in_pixels(&ps);
-            let (x0, y0) = (x - size, y - size);
-            let (x1, y1) = (x + size, y + size);
-            backend.draw_line((x0, y0), (x1, y1), &self.style)?;
-            backend.draw_line((x0, y1), (x1, y0), &self.style)?;
+            backend.draw_marker((x, y), MarkerShape::Cross, size, &self.style)?;
         }
         Ok(())
     }
@@ -114,16 +111,7 @@ impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for TriangleMarker<
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some((x, y)) = points.next() {
             let size = self.size.in_pixels(&ps);
-            let points = [-90, -210, -330]
-                .iter()
-                .map(|deg| f64::from(*deg) * std::f64::consts::PI / 180.0)
-                .map(|rad| {
-                    (
-                        (rad.cos() * f64::from(size) + f64::from(x)).ceil() as i32,
-                        (rad.sin() * f64::from(size) + f64::from(y)).ceil() as i32,
-                    )
-                });
-            backend.fill_polygon(points, &self.style.color.to_backend_color())?;
+            backend.draw_marker((x, y), MarkerShape::Triangle, size, &self.style)?;
         }
         Ok(())
     }
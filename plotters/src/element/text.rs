@@ -2,7 +2,7 @@ use std::borrow::Borrow;
 
 use super::{Drawable, PointCollection};
 use crate::style::{FontDesc, FontResult, LayoutBox, TextStyle};
-use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use plotters_backend::{BackendCoord, BackendFloatCoord, DrawingBackend, DrawingErrorKind};
 
 /// A single line text element. This can be owned or borrowed string, dependents on
 /// `String` or `str` moved into.
@@ -47,6 +47,19 @@ impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB> for Text<'a
         }
         Ok(())
     }
+
+    fn draw_subpixel<I: Iterator<Item = BackendCoord>, F: Iterator<Item = BackendFloatCoord>>(
+        &self,
+        _: I,
+        mut exact: F,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some(a) = exact.next() {
+            return backend.draw_text_f64(self.text.borrow(), &self.style, a);
+        }
+        Ok(())
+    }
 }
 
 /// An multi-line text element. The `Text` element allows only single line text
@@ -866,9 +866,9 @@ pub mod prelude {
     pub use crate::style::colors::colormaps::*;
 
     pub use crate::style::{
-        AsRelative, Color, FontDesc, FontFamily, FontStyle, FontTransform, HSLColor, IntoFont,
-        IntoTextStyle, Palette, Palette100, Palette99, Palette9999, PaletteColor, RGBAColor,
-        RGBColor, ShapeStyle, TextStyle,
+        AsRelative, BackendPaint, Color, ExtendedStyle, FontDesc, FontFamily, FontStyle,
+        FontTransform, HSLColor, IntoFont, IntoTextStyle, LineCap, LineJoin, Palette, Palette100,
+        Palette99, Palette9999, PaletteColor, RGBAColor, RGBColor, ShapeStyle, TextStyle,
     };
 
     // Elements
@@ -927,12 +927,12 @@ pub mod backend {
     #[cfg(feature = "bitmap_backend")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "bitmap_backend")))]
     pub use plotters_bitmap::{
-        bitmap_pixel::{BGRXPixel, PixelFormat, RGBPixel},
-        BitMapBackend,
+        bitmap_pixel::{BGRXPixel, PixelFormat, RGBAPixel, RGBPixel},
+        BitMapBackend, Hit, HitRegionFormat, HitTestIndex,
     };
     #[cfg(feature = "svg_backend")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "svg_backend")))]
-    pub use plotters_svg::SVGBackend;
+    pub use plotters_svg::{tooltip_script, tooltip_stylesheet, SVGBackend, TooltipMode};
 }
 
 #[cfg(test)]
@@ -1,6 +1,6 @@
 use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
 use crate::style::colors::TRANSPARENT;
-use crate::style::ShapeStyle;
+use crate::style::ExtendedStyle;
 use plotters_backend::DrawingBackend;
 
 /**
@@ -29,8 +29,8 @@ The result is a chart with three line series; one of them has a highlighted blue
 ![](https://cdn.jsdelivr.net/gh/facorread/plotters-doc-data@b6703f7/apidoc/area_series.svg)
 */
 pub struct AreaSeries<DB: DrawingBackend, X: Clone, Y: Clone> {
-    area_style: ShapeStyle,
-    border_style: ShapeStyle,
+    area_style: ExtendedStyle,
+    border_style: ExtendedStyle,
     baseline: Y,
     data: Vec<(X, Y)>,
     state: u32,
@@ -43,7 +43,7 @@ impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y> {
 
     See [`AreaSeries`] for more information and examples.
     */
-    pub fn new<S: Into<ShapeStyle>, I: IntoIterator<Item = (X, Y)>>(
+    pub fn new<S: Into<ExtendedStyle>, I: IntoIterator<Item = (X, Y)>>(
         iter: I,
         baseline: Y,
         area_style: S,
@@ -63,7 +63,7 @@ impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y> {
 
     See [`AreaSeries`] for more information and examples.
     */
-    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+    pub fn border_style<S: Into<ExtendedStyle>>(mut self, style: S) -> Self {
         self.border_style = style.into();
         self
     }
@@ -82,13 +82,13 @@ impl<DB: DrawingBackend, X: Clone + 'static, Y: Clone + 'static> Iterator for Ar
 
             self.state = 1;
 
-            Some(Polygon::new(data, self.area_style).into_dyn())
+            Some(Polygon::new(data, self.area_style.clone()).into_dyn())
         } else if self.state == 1 {
             let data: Vec<_> = self.data.clone();
 
             self.state = 2;
 
-            Some(PathElement::new(data, self.border_style).into_dyn())
+            Some(PathElement::new(data, self.border_style.clone()).into_dyn())
         } else {
             None
         }
@@ -6,7 +6,7 @@ use crate::chart::ChartContext;
 use crate::coord::cartesian::Cartesian2d;
 use crate::coord::ranged1d::{DiscreteRanged, Ranged};
 use crate::element::Rectangle;
-use crate::style::{Color, ShapeStyle, GREEN};
+use crate::style::{Color, ExtendedStyle, GREEN};
 use plotters_backend::DrawingBackend;
 
 pub trait HistogramType {}
@@ -83,7 +83,7 @@ where
     A: AddAssign<A> + Default,
     Tag: HistogramType,
 {
-    style: Box<dyn Fn(&BR::ValueType, &A) -> ShapeStyle + 'a>,
+    style: Box<dyn Fn(&BR::ValueType, &A) -> ExtendedStyle + 'a>,
     margin: u32,
     iter: HashMapIter<usize, A>,
     baseline: Box<dyn Fn(&BR::ValueType) -> A + 'a>,
@@ -99,7 +99,7 @@ where
 {
     fn empty(br: &BR) -> Self {
         Self {
-            style: Box::new(|_, _| GREEN.filled()),
+            style: Box::new(|_, _| GREEN.filled().into()),
             margin: 5,
             iter: HashMap::new().into_iter(),
             baseline: Box::new(|_| A::default()),
@@ -112,9 +112,9 @@ where
 
     See [`Histogram`] for more information and examples.
     */
-    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+    pub fn style<S: Into<ExtendedStyle>>(mut self, style: S) -> Self {
         let style = style.into();
-        self.style = Box::new(move |_, _| style);
+        self.style = Box::new(move |_, _| style.clone());
         self
     }
 
@@ -125,11 +125,11 @@ where
     The argument may need some processing if the data range has been transformed by
     [`crate::coord::ranged1d::IntoSegmentedCoord::into_segmented()`] as shown in the [`Histogram`] example.
     */
-    pub fn style_func(
+    pub fn style_func<S: Into<ExtendedStyle>>(
         mut self,
-        style_func: impl Fn(&BR::ValueType, &A) -> ShapeStyle + 'a,
+        style_func: impl Fn(&BR::ValueType, &A) -> S + 'a,
     ) -> Self {
-        self.style = Box::new(style_func);
+        self.style = Box::new(move |value, height| style_func(value, height).into());
         self
     }
 
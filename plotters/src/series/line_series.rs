@@ -1,7 +1,7 @@
 use crate::element::{
     Circle, DashedPathElement, DottedPathElement, DynElement, IntoDynElement, PathElement,
 };
-use crate::style::{ShapeStyle, SizeDesc};
+use crate::style::{ExtendedStyle, ShapeStyle, SizeDesc};
 use plotters_backend::{BackendCoord, DrawingBackend};
 use std::marker::PhantomData;
 
@@ -32,7 +32,7 @@ The result is a chart with three line series; two of them have their data points
 ![](https://cdn.jsdelivr.net/gh/facorread/plotters-doc-data@64e0a28/apidoc/line_series_point_size.svg)
 */
 pub struct LineSeries<DB: DrawingBackend, Coord> {
-    style: ShapeStyle,
+    style: ExtendedStyle,
     data: Vec<Coord>,
     point_idx: usize,
     point_size: u32,
@@ -47,12 +47,13 @@ impl<DB: DrawingBackend, Coord: Clone + 'static> Iterator for LineSeries<DB, Coo
                 let idx = self.point_idx;
                 self.point_idx += 1;
                 return Some(
-                    Circle::new(self.data[idx].clone(), self.point_size, self.style).into_dyn(),
+                    Circle::new(self.data[idx].clone(), self.point_size, self.style.clone())
+                        .into_dyn(),
                 );
             }
             let mut data = vec![];
             std::mem::swap(&mut self.data, &mut data);
-            Some(PathElement::new(data, self.style).into_dyn())
+            Some(PathElement::new(data, self.style.clone()).into_dyn())
         } else {
             None
         }
@@ -65,7 +66,7 @@ impl<DB: DrawingBackend, Coord> LineSeries<DB, Coord> {
 
     See [`LineSeries`] for more information and examples.
     */
-    pub fn new<I: IntoIterator<Item = Coord>, S: Into<ShapeStyle>>(iter: I, style: S) -> Self {
+    pub fn new<I: IntoIterator<Item = Coord>, S: Into<ExtendedStyle>>(iter: I, style: S) -> Self {
         Self {
             style: style.into(),
             data: iter.into_iter().collect(),
@@ -4,6 +4,7 @@ use crate::style::{Color, TextStyle};
 
 use std::convert::From;
 
+use plotters_backend::OutlineCommand;
 pub use plotters_backend::{FontFamily, FontStyle, FontTransform};
 
 /// The error type for the font implementation
@@ -169,6 +170,20 @@ impl<'a> FontDesc<'a> {
             Err(e) => Err(e.clone()),
         }
     }
+
+    /// Trace the glyph outlines of the text, placed as [draw](FontDesc::draw) places the glyphs.
+    /// Returns false if the font implementation can't provide outlines.
+    pub fn outline<F: FnMut(OutlineCommand)>(
+        &self,
+        text: &str,
+        (x, y): (i32, i32),
+        sink: F,
+    ) -> FontResult<bool> {
+        match &self.data {
+            Ok(ref font) => font.outline((x, y), self.size, text, sink),
+            Err(e) => Err(e.clone()),
+        }
+    }
 }
 
 impl<'a> From<&'a str> for FontDesc<'a> {
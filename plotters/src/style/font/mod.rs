@@ -58,6 +58,8 @@ use web::FontDataInternal;
 mod font_desc;
 pub use font_desc::*;
 
+use plotters_backend::OutlineCommand;
+
 /// Represents a box where a text label can be fit
 pub type LayoutBox = ((i32, i32), (i32, i32));
 
@@ -74,4 +76,15 @@ pub trait FontData: Clone {
     ) -> Result<Result<(), E>, Self::ErrorType> {
         panic!("The font implementation is unable to draw text");
     }
+    /// Trace the glyph outlines of the text, placed as `draw` places the glyphs. Returns false
+    /// if the font implementation can't provide outlines.
+    fn outline<F: FnMut(OutlineCommand)>(
+        &self,
+        _pos: (i32, i32),
+        _size: f64,
+        _text: &str,
+        _sink: F,
+    ) -> Result<bool, Self::ErrorType> {
+        Ok(false)
+    }
 }
@@ -12,15 +12,19 @@ use font_kit::{
     font::Font,
     handle::Handle,
     hinting::HintingOptions,
+    outline::OutlineSink,
     properties::{Properties, Style, Weight},
     source::SystemSource,
 };
 
 use ttf_parser::{Face, GlyphId};
 
+use pathfinder_geometry::line_segment::LineSegment2F;
 use pathfinder_geometry::transform2d::Transform2F;
 use pathfinder_geometry::vector::{Vector2F, Vector2I};
 
+use plotters_backend::OutlineCommand;
+
 use super::{FontData, FontFamily, FontStyle, LayoutBox};
 
 type FontResult<T> = Result<T, FontError>;
@@ -201,6 +205,51 @@ fn load_font_from_handle(handle: Handle) -> FontResult<FontExt> {
 #[derive(Clone)]
 pub struct FontDataInternal(FontExt);
 
+/// Maps the glyph outlines from font units to pixels
+struct GlyphOutlineSink<F> {
+    sink: F,
+    /// The origin of the current glyph, on the baseline
+    origin: (f32, f32),
+    /// The size of a font unit in pixels
+    scale: f32,
+}
+
+impl<F: FnMut(OutlineCommand)> GlyphOutlineSink<F> {
+    fn map(&self, v: Vector2F) -> (f64, f64) {
+        (
+            (self.origin.0 + v.x() * self.scale) as f64,
+            (self.origin.1 - v.y() * self.scale) as f64,
+        )
+    }
+}
+
+impl<F: FnMut(OutlineCommand)> OutlineSink for GlyphOutlineSink<F> {
+    fn move_to(&mut self, to: Vector2F) {
+        let (x, y) = self.map(to);
+        (self.sink)(OutlineCommand::MoveTo(x, y));
+    }
+
+    fn line_to(&mut self, to: Vector2F) {
+        let (x, y) = self.map(to);
+        (self.sink)(OutlineCommand::LineTo(x, y));
+    }
+
+    fn quadratic_curve_to(&mut self, ctrl: Vector2F, to: Vector2F) {
+        let command = OutlineCommand::QuadTo(self.map(ctrl), self.map(to));
+        (self.sink)(command);
+    }
+
+    fn cubic_curve_to(&mut self, ctrl: LineSegment2F, to: Vector2F) {
+        let command =
+            OutlineCommand::CubicTo(self.map(ctrl.from()), self.map(ctrl.to()), self.map(to));
+        (self.sink)(command);
+    }
+
+    fn close(&mut self) {
+        (self.sink)(OutlineCommand::Close);
+    }
+}
+
 impl FontData for FontDataInternal {
     type ErrorType = FontError;
 
@@ -299,6 +348,43 @@ impl FontData for FontDataInternal {
         result?;
         Ok(Ok(()))
     }
+
+    fn outline<F: FnMut(OutlineCommand)>(
+        &self,
+        (base_x, base_y): (i32, i32),
+        size: f64,
+        text: &str,
+        sink: F,
+    ) -> Result<bool, Self::ErrorType> {
+        let em = (size / 1.24) as f32;
+        let font = &self.0;
+        let metrics = font.metrics();
+        let scale = em / metrics.units_per_em as f32;
+
+        // The baseline of the glyphs rasterized by `draw`
+        let baseline = (base_y - (0.24 * em) as i32) as f32 + em;
+        let mut sink = GlyphOutlineSink {
+            sink,
+            origin: (base_x as f32, baseline),
+            scale,
+        };
+
+        let mut prev = None;
+        let place_holder = font.glyph_for_char(PLACEHOLDER_CHAR);
+
+        for c in text.chars() {
+            if let Some(glyph_id) = font.glyph_for_char(c).or(place_holder) {
+                if let Some(pc) = prev {
+                    sink.origin.0 += font.query_kerning_table(pc, glyph_id) * scale;
+                }
+                font.outline(glyph_id, HintingOptions::None, &mut sink)
+                    .map_err(|e| FontError::GlyphError(Arc::new(e)))?;
+                sink.origin.0 += font.advance(glyph_id).map(|size| size.x()).unwrap_or(0.0) * scale;
+                prev = Some(glyph_id);
+            }
+        }
+        Ok(true)
+    }
 }
 
 #[cfg(test)]
@@ -321,4 +407,35 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_outline_matches_raster() -> FontResult<()> {
+        let font = FontDataInternal::new(FontFamily::SansSerif, FontStyle::Normal)?;
+        let grow = |b: &mut [f64; 4], x: f64, y: f64| {
+            *b = [b[0].min(x), b[1].min(y), b[2].max(x), b[3].max(y)];
+        };
+        let empty = [f64::MAX, f64::MAX, f64::MIN, f64::MIN];
+
+        let mut raster = empty;
+        font.draw((10, 20), 40.0, "HIH", |x, y, alpha| {
+            if alpha > 0.5 {
+                grow(&mut raster, x as f64 + 0.5, y as f64 + 0.5);
+            }
+            Ok::<(), ()>(())
+        })?
+        .unwrap();
+
+        let mut outline = empty;
+        assert!(font.outline((10, 20), 40.0, "HIH", |cmd| match cmd {
+            OutlineCommand::MoveTo(x, y) | OutlineCommand::LineTo(x, y) => {
+                grow(&mut outline, x, y)
+            }
+            _ => {}
+        })?);
+
+        for (a, b) in raster.iter().zip(outline.iter()) {
+            assert!((a - b).abs() <= 1.5, "{:?} vs {:?}", raster, outline);
+        }
+        Ok(())
+    }
 }
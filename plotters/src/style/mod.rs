@@ -24,7 +24,8 @@ pub use font::{
     FontDesc, FontError, FontFamily, FontResult, FontStyle, FontTransform, IntoFont, LayoutBox,
 };
 
-pub use shape::ShapeStyle;
+pub use plotters_backend::{BackendPaint, GradientStop, GradientStops, LineCap, LineJoin};
+pub use shape::{ExtendedStyle, ShapeStyle};
 pub use size::{AsRelative, RelativeSize, SizeDesc};
 pub use text::text_anchor;
 pub use text::{IntoTextStyle, TextStyle};
@@ -1,5 +1,8 @@
 use super::color::{Color, RGBAColor};
-use plotters_backend::{BackendColor, BackendStyle};
+use plotters_backend::{
+    BackendColor, BackendPaint, BackendStyle, GradientStop, GradientStops, LineCap, LineJoin,
+};
+use std::sync::Arc;
 
 /// Style for any shape
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -74,6 +77,97 @@ impl ShapeStyle {
             stroke_width: width,
         }
     }
+
+    /**
+    Returns an extended style with the same color and the specified dash pattern.
+
+    - `lengths`: The lengths in pixels of the dashes and the gaps between them, alternately, see
+      [BackendStyle::dash_array]. An empty pattern makes the stroke solid.
+
+    # Example
+
+    ```
+    use plotters::prelude::*;
+    let dashed_style = BLUE.stroke_width(2).dash(&[10.0, 5.0]);
+    let drawing_area = SVGBackend::new("shape_style_dash.svg", (400, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    drawing_area.draw(&PathElement::new(vec![(50, 100), (350, 100)], dashed_style));
+    ```
+    */
+    pub fn dash(&self, lengths: &[f64]) -> ExtendedStyle {
+        ExtendedStyle::from(*self).dash(lengths)
+    }
+
+    /// Returns an extended style whose dash pattern starts at the specified distance, see
+    /// [ExtendedStyle::dash_offset].
+    pub fn dash_offset(&self, offset: f64) -> ExtendedStyle {
+        ExtendedStyle::from(*self).dash_offset(offset)
+    }
+
+    /// Returns an extended style with the specified shape at the ends of the open strokes.
+    pub fn line_cap(&self, cap: LineCap) -> ExtendedStyle {
+        ExtendedStyle::from(*self).line_cap(cap)
+    }
+
+    /// Returns an extended style with the specified shape at the corners of the strokes.
+    pub fn line_join(&self, join: LineJoin) -> ExtendedStyle {
+        ExtendedStyle::from(*self).line_join(join)
+    }
+
+    /// Returns an extended style which fills the shapes with the specified paint.
+    pub fn paint(&self, paint: BackendPaint) -> ExtendedStyle {
+        ExtendedStyle::from(*self).paint(paint)
+    }
+
+    /**
+    Returns an extended style which fills the shapes with a linear gradient.
+
+    - `start`, `end`: The positions of the first and the last offsets, relative to the bounding
+      box of the shape, where `(0.0, 0.0)` is its upper left corner and `(1.0, 1.0)` its bottom
+      right corner
+    - `stops`: The offsets, from 0 to 1, and the colors of the gradient
+
+    # Example
+
+    ```
+    use plotters::prelude::*;
+    let fade_style = BLUE
+        .filled()
+        .linear_gradient((0.0, 0.0), (0.0, 1.0), &[(0.0, BLUE.to_rgba()), (1.0, BLUE.mix(0.0))]);
+    let drawing_area = SVGBackend::new("shape_style_gradient.svg", (400, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    drawing_area.draw(&Rectangle::new([(50, 20), (350, 180)], fade_style));
+    ```
+    */
+    pub fn linear_gradient<C: Color>(
+        &self,
+        start: (f64, f64),
+        end: (f64, f64),
+        stops: &[(f64, C)],
+    ) -> ExtendedStyle {
+        ExtendedStyle::from(*self).linear_gradient(start, end, stops)
+    }
+
+    /// Returns an extended style which fills the shapes with a radial gradient, the `center` and
+    /// the `radius` are relative to the bounding box of the shape as with
+    /// [linear_gradient](ShapeStyle::linear_gradient).
+    pub fn radial_gradient<C: Color>(
+        &self,
+        center: (f64, f64),
+        radius: f64,
+        stops: &[(f64, C)],
+    ) -> ExtendedStyle {
+        ExtendedStyle::from(*self).radial_gradient(center, radius, stops)
+    }
+
+    /// Returns an extended style which fills the shapes with parallel lines of its color.
+    ///
+    /// - `angle`: The angle of the lines in degrees, clockwise from horizontal
+    /// - `spacing`: The distance in pixels from a line to the next one
+    /// - `width`: The width of the lines in pixels
+    pub fn hatch(&self, angle: f64, spacing: f64, width: f64) -> ExtendedStyle {
+        ExtendedStyle::from(*self).hatch(angle, spacing, width)
+    }
 }
 
 impl<T: Color> From<T> for ShapeStyle {
@@ -96,3 +190,196 @@ impl BackendStyle for ShapeStyle {
         self.stroke_width
     }
 }
+
+/// The stroke and fill settings of an [ExtendedStyle], stored out of line to keep the elements
+/// holding a style small
+#[derive(Clone, Debug, PartialEq, Default)]
+struct StyleExtension {
+    /// The lengths of the dashes and the gaps between them, empty for a solid stroke
+    dash: Vec<f64>,
+    /// The distance into the dash pattern at which the stroke starts
+    dash_offset: f64,
+    line_cap: LineCap,
+    line_join: LineJoin,
+    /// The paint of the fills, `None` to fill with the color. The strokes, and the fills of the
+    /// backends which can't draw the paint, use its mean color.
+    paint: Option<BackendPaint>,
+}
+
+/**
+A [ShapeStyle] with a dash pattern, line caps, line joins or a paint.
+
+It's created by the builder methods of [ShapeStyle], e.g. `BLUE.stroke_width(2).dash(&[4.0, 2.0])`,
+and accepted by the elements and the series which draw strokes and fills, such as
+[PathElement](crate::element::PathElement) or [AreaSeries](crate::series::AreaSeries). Any
+[ShapeStyle] or color converts into an extended style with a solid stroke.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExtendedStyle {
+    /// The color, the fill and the stroke width.
+    pub shape: ShapeStyle,
+    /// `None` for a solid stroke with the default caps and joins, which fills with the color
+    extension: Option<Arc<StyleExtension>>,
+}
+
+impl ExtendedStyle {
+    /// Returns a filled style with the same color, stroke width and extended settings.
+    pub fn filled(&self) -> Self {
+        Self {
+            shape: self.shape.filled(),
+            extension: self.extension.clone(),
+        }
+    }
+
+    /// Returns a new style with the specified stroke width.
+    pub fn stroke_width(&self, width: u32) -> Self {
+        Self {
+            shape: self.shape.stroke_width(width),
+            extension: self.extension.clone(),
+        }
+    }
+
+    /// Returns a new style with the specified dash pattern, see [ShapeStyle::dash].
+    pub fn dash(&self, lengths: &[f64]) -> Self {
+        self.extend(|extension| extension.dash = lengths.to_vec())
+    }
+
+    /// Returns a new style with the same dash pattern, starting at the specified distance into
+    /// the pattern.
+    pub fn dash_offset(&self, offset: f64) -> Self {
+        self.extend(|extension| extension.dash_offset = offset)
+    }
+
+    /// Returns a new style with the specified shape at the ends of the open strokes.
+    pub fn line_cap(&self, cap: LineCap) -> Self {
+        self.extend(|extension| extension.line_cap = cap)
+    }
+
+    /// Returns a new style with the specified shape at the corners of the strokes.
+    pub fn line_join(&self, join: LineJoin) -> Self {
+        self.extend(|extension| extension.line_join = join)
+    }
+
+    /// Returns a new style which fills the shapes with the specified paint.
+    pub fn paint(&self, paint: BackendPaint) -> Self {
+        self.extend(|extension| extension.paint = Some(paint))
+    }
+
+    /// Returns a new style which fills the shapes with a linear gradient, see
+    /// [ShapeStyle::linear_gradient].
+    pub fn linear_gradient<C: Color>(
+        &self,
+        start: (f64, f64),
+        end: (f64, f64),
+        stops: &[(f64, C)],
+    ) -> Self {
+        self.paint(BackendPaint::LinearGradient {
+            start,
+            end,
+            stops: gradient_stops(stops),
+        })
+    }
+
+    /// Returns a new style which fills the shapes with a radial gradient, see
+    /// [ShapeStyle::radial_gradient].
+    pub fn radial_gradient<C: Color>(
+        &self,
+        center: (f64, f64),
+        radius: f64,
+        stops: &[(f64, C)],
+    ) -> Self {
+        self.paint(BackendPaint::RadialGradient {
+            center,
+            radius,
+            stops: gradient_stops(stops),
+        })
+    }
+
+    /// Returns a new style which fills the shapes with parallel lines of its color, see
+    /// [ShapeStyle::hatch].
+    pub fn hatch(&self, angle: f64, spacing: f64, width: f64) -> Self {
+        let color = self.shape.color.to_backend_color();
+        self.paint(BackendPaint::Hatch {
+            color,
+            background: color.mix(0.0),
+            angle,
+            spacing,
+            width,
+        })
+    }
+
+    fn extend<F: FnOnce(&mut StyleExtension)>(&self, update: F) -> Self {
+        let mut style = self.clone();
+        update(Arc::make_mut(
+            style.extension.get_or_insert_with(Default::default),
+        ));
+        style
+    }
+}
+
+fn gradient_stops<C: Color>(stops: &[(f64, C)]) -> GradientStops {
+    let stops: Vec<_> = stops
+        .iter()
+        .map(|(offset, color)| GradientStop {
+            offset: *offset,
+            color: color.to_backend_color(),
+        })
+        .collect();
+    GradientStops::new(&stops)
+}
+
+impl From<ShapeStyle> for ExtendedStyle {
+    fn from(shape: ShapeStyle) -> Self {
+        Self {
+            shape,
+            extension: None,
+        }
+    }
+}
+
+impl<T: Color> From<T> for ExtendedStyle {
+    fn from(f: T) -> Self {
+        ShapeStyle::from(f).into()
+    }
+}
+
+impl BackendStyle for ExtendedStyle {
+    /// Returns the color as interpreted by the backend.
+    fn color(&self) -> BackendColor {
+        match self.extension.as_ref().and_then(|e| e.paint) {
+            Some(paint) => paint.mean_color(),
+            None => self.shape.color.to_backend_color(),
+        }
+    }
+    /// Returns the stroke width.
+    fn stroke_width(&self) -> u32 {
+        self.shape.stroke_width
+    }
+    /// Returns the dash pattern.
+    fn dash_array(&self) -> &[f64] {
+        self.extension.as_ref().map_or(&[], |e| &e.dash[..])
+    }
+    /// Returns the distance into the dash pattern at which the stroke starts.
+    fn dash_offset(&self) -> f64 {
+        self.extension.as_ref().map_or(0.0, |e| e.dash_offset)
+    }
+    /// Returns the shape at the ends of the open strokes.
+    fn line_cap(&self) -> LineCap {
+        self.extension
+            .as_ref()
+            .map_or_else(LineCap::default, |e| e.line_cap)
+    }
+    /// Returns the shape at the corners of the strokes.
+    fn line_join(&self) -> LineJoin {
+        self.extension
+            .as_ref()
+            .map_or_else(LineJoin::default, |e| e.line_join)
+    }
+    /// Returns the paint of the fills.
+    fn paint(&self) -> BackendPaint {
+        self.extension
+            .as_ref()
+            .and_then(|e| e.paint)
+            .unwrap_or_else(|| BackendPaint::Solid(self.shape.color.to_backend_color()))
+    }
+}
@@ -3,7 +3,9 @@ use super::font::{FontDesc, FontError, FontFamily, FontStyle, FontTransform};
 use super::size::{HasDimension, SizeDesc};
 use super::BLACK;
 pub use plotters_backend::text_anchor;
-use plotters_backend::{BackendColor, BackendCoord, BackendStyle, BackendTextStyle};
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, OutlineCommand,
+};
 
 /// Style of a text
 #[derive(Clone)]
@@ -324,4 +326,13 @@ impl<'a> BackendTextStyle for TextStyle<'a> {
             draw(x, y, mix_color)
         })
     }
+
+    fn outline<F: FnMut(OutlineCommand)>(
+        &self,
+        text: &str,
+        pos: BackendCoord,
+        sink: F,
+    ) -> Result<bool, Self::FontError> {
+        self.font.outline(text, pos, sink)
+    }
 }